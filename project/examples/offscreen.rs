@@ -26,7 +26,7 @@ impl SimpleAcetate {
             id: id.to_string(),
             name: name.to_string(),
             z_index,
-            area: design.area.clone(),
+            area: design.area,
             design,
         }
     }
@@ -46,7 +46,17 @@ impl Acetate for SimpleAcetate {
     }
 
     fn area(&self) -> Rect {
-        self.area.clone()
+        self.area
+    }
+
+    fn relocate(&self, area: Rect) -> Box<dyn Acetate> {
+        let mut design = self.design.clone();
+        design.area = area;
+        Box::new(Self {
+            area,
+            design,
+            ..self.clone()
+        })
     }
 
     fn subscriptions(&self) -> Vec<EventKind> {
@@ -66,6 +76,8 @@ impl Acetate for SimpleAcetate {
             content: None,
             focus: false,
             status: AcetateStatus::Ready,
+            clipboard: None,
+            action: None,
         }
     }
 
@@ -100,11 +112,12 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         "Background",
         0,
         AcetateDesign {
-            area: Rect { x: 0, y: 0, width, height },
+            area: Rect::new(0.0, 0.0, width as f32, height as f32),
             background: Color { r: 22.0 / 255.0, g: 25.0 / 255.0, b: 35.0 / 255.0, a: 1.0 },
             border: Color { r: 0.0, g: 0.0, b: 0.0, a: 0.0 },
             border_thickness: 0.0,
             text: None,
+            ..AcetateDesign::default()
         },
     );
 
@@ -114,11 +127,12 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         "Card",
         10,
         AcetateDesign {
-            area: Rect { x: 40, y: 40, width: 160, height: 100 },
+            area: Rect::new(40.0, 40.0, 160.0, 100.0),
             background: Color { r: 0.10, g: 0.60, b: 0.90, a: 1.0 },
             border: Color { r: 0.95, g: 0.95, b: 0.95, a: 1.0 },
             border_thickness: 2.0,
             text: None,
+            ..AcetateDesign::default()
         },
     );
 
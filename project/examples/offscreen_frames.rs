@@ -28,7 +28,7 @@ impl SimpleAcetate {
             id: id.to_string(),
             name: name.to_string(),
             z_index,
-            area: design.area.clone(),
+            area: design.area,
             design,
         }
     }
@@ -48,7 +48,17 @@ impl Acetate for SimpleAcetate {
     }
 
     fn area(&self) -> Rect {
-        self.area.clone()
+        self.area
+    }
+
+    fn relocate(&self, area: Rect) -> Box<dyn Acetate> {
+        let mut design = self.design.clone();
+        design.area = area;
+        Box::new(Self {
+            area,
+            design,
+            ..self.clone()
+        })
     }
 
     fn subscriptions(&self) -> Vec<EventKind> {
@@ -68,6 +78,8 @@ impl Acetate for SimpleAcetate {
             content: None,
             focus: false,
             status: AcetateStatus::Ready,
+            clipboard: None,
+            action: None,
         }
     }
 
@@ -99,18 +111,19 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     for frame in 0..frames {
         let tick = ticker.tick();
-        let x = 20 + ((tick.number as i32 * 5) % 600);
+        let x = 20.0 + (tick.number as f32 * 5.0) % 600.0;
 
         let background = SimpleAcetate::new(
             "bg",
             "Background",
             0,
             AcetateDesign {
-                area: Rect { x: 0, y: 0, width, height },
+                area: Rect::new(0.0, 0.0, width as f32, height as f32),
                 background: Color { r: 0.08, g: 0.10, b: 0.14, a: 1.0 },
                 border: Color { r: 0.0, g: 0.0, b: 0.0, a: 0.0 },
                 border_thickness: 0.0,
                 text: None,
+                ..AcetateDesign::default()
             },
         );
 
@@ -119,11 +132,12 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             "Mover",
             10,
             AcetateDesign {
-                area: Rect { x, y: 140, width: 160, height: 90 },
+                area: Rect::new(x, 140.0, 160.0, 90.0),
                 background: Color { r: 0.20, g: 0.70, b: 0.35, a: 1.0 },
                 border: Color { r: 0.95, g: 0.95, b: 0.95, a: 1.0 },
                 border_thickness: 2.0,
                 text: None,
+                ..AcetateDesign::default()
             },
         );
 
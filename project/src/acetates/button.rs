@@ -0,0 +1,293 @@
+//! Botón: hover, presionado, foco y deshabilitado, con un estilo por estado.
+
+use super::press::PressState;
+use crate::contracts::event::{Action, Event, EventKind};
+use crate::contracts::scene::{
+    Acetate, AcetateDesign, AcetateIO, AcetateStatus, EmittedAction, Rect, SceneInfo, TextAlign,
+};
+use crate::core::Color;
+
+/// Colores de un estado concreto del botón.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ButtonStateStyle {
+    pub fill: Color,
+    pub border: Color,
+    pub text_color: Color,
+}
+
+/// Estilo completo del botón. Prioridad al pintar:
+/// deshabilitado > presionado > hover > foco > normal.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ButtonStyle {
+    pub normal: ButtonStateStyle,
+    pub hover: ButtonStateStyle,
+    pub pressed: ButtonStateStyle,
+    pub focused: ButtonStateStyle,
+    pub disabled: ButtonStateStyle,
+    pub border_thickness: f32,
+    pub font_size: f32,
+}
+
+impl ButtonStyle {
+    /// Deriva los estados a partir del estilo normal (aclarar, oscurecer, atenuar).
+    pub fn from_normal(normal: ButtonStateStyle) -> Self {
+        let white = Color::rgba(1.0, 1.0, 1.0, 1.0);
+        let black = Color::rgba(0.0, 0.0, 0.0, 1.0);
+        Self {
+            hover: ButtonStateStyle {
+                fill: normal.fill.mix(white, 0.12),
+                ..normal.clone()
+            },
+            pressed: ButtonStateStyle {
+                fill: normal.fill.mix(black, 0.18),
+                ..normal.clone()
+            },
+            focused: ButtonStateStyle {
+                border: Color::rgba(0.35, 0.55, 0.95, 1.0),
+                ..normal.clone()
+            },
+            disabled: ButtonStateStyle {
+                fill: normal.fill.with_alpha(normal.fill.a * 0.45),
+                border: normal.border.with_alpha(normal.border.a * 0.45),
+                text_color: normal.text_color.with_alpha(normal.text_color.a * 0.45),
+            },
+            normal,
+            border_thickness: 1.0,
+            font_size: 14.0,
+        }
+    }
+}
+
+impl Default for ButtonStyle {
+    fn default() -> Self {
+        Self::from_normal(ButtonStateStyle {
+            fill: Color::rgba(0.22, 0.36, 0.78, 1.0),
+            border: Color::rgba(0.16, 0.26, 0.60, 1.0),
+            text_color: Color::rgba(1.0, 1.0, 1.0, 1.0),
+        })
+    }
+}
+
+/// Botón reutilizable. Emite `Action::Click` al soltar dentro tras presionar dentro,
+/// o con Enter/Espacio si tiene el foco.
+#[derive(Debug, Clone)]
+pub struct Button {
+    id: String,
+    name: String,
+    z_index: i32,
+    area: Rect,
+    label: Option<String>,
+    tooltip: Option<String>,
+    style: ButtonStyle,
+    status: AcetateStatus,
+    press: PressState,
+    action: Option<EmittedAction>,
+}
+
+impl Button {
+    pub fn new(id: &str, area: Rect) -> Self {
+        Self {
+            id: id.to_string(),
+            name: id.to_string(),
+            z_index: 0,
+            area,
+            label: None,
+            tooltip: None,
+            style: ButtonStyle::default(),
+            status: AcetateStatus::Ready,
+            press: PressState::default(),
+            action: None,
+        }
+    }
+
+    pub fn with_name(mut self, name: &str) -> Self {
+        self.name = name.to_string();
+        self
+    }
+
+    pub fn with_z_index(mut self, z_index: i32) -> Self {
+        self.z_index = z_index;
+        self
+    }
+
+    pub fn with_label(mut self, label: &str) -> Self {
+        self.label = Some(label.to_string());
+        self
+    }
+
+    /// Ayuda que se muestra al dejar el puntero encima (útil en botones de icono).
+    pub fn with_tooltip(mut self, tooltip: &str) -> Self {
+        self.tooltip = Some(tooltip.to_string());
+        self
+    }
+
+    pub fn with_style(mut self, style: ButtonStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    pub fn with_status(mut self, status: AcetateStatus) -> Self {
+        self.status = status;
+        self
+    }
+
+    pub fn is_hovered(&self) -> bool {
+        self.press.hovered
+    }
+
+    pub fn is_pressed(&self) -> bool {
+        self.press.pressed
+    }
+
+    fn is_disabled(&self) -> bool {
+        !matches!(self.status, AcetateStatus::Ready)
+    }
+
+    fn current_style(&self) -> &ButtonStateStyle {
+        if self.is_disabled() {
+            &self.style.disabled
+        } else if self.press.pressed && self.press.hovered {
+            &self.style.pressed
+        } else if self.press.hovered {
+            &self.style.hover
+        } else if self.press.focused {
+            &self.style.focused
+        } else {
+            &self.style.normal
+        }
+    }
+
+    fn clicked(mut self) -> Self {
+        self.action = Some(EmittedAction::next(self.action.as_ref(), Action::Click));
+        self
+    }
+}
+
+impl Acetate for Button {
+    fn id(&self) -> String {
+        self.id.clone()
+    }
+
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn z_index(&self) -> i32 {
+        self.z_index
+    }
+
+    fn area(&self) -> Rect {
+        self.area
+    }
+
+    fn relocate(&self, area: Rect) -> Box<dyn Acetate> {
+        Box::new(Self {
+            area,
+            ..self.clone()
+        })
+    }
+
+    fn subscriptions(&self) -> Vec<EventKind> {
+        vec![
+            EventKind::MouseMoved,
+            EventKind::MouseDown,
+            EventKind::MouseUp,
+            EventKind::KeyPressed,
+        ]
+    }
+
+    fn react(&self, event: &Event, _scene: &SceneInfo) -> Option<Box<dyn Acetate>> {
+        if self.is_disabled() {
+            return None;
+        }
+
+        let (press, activated) = self.press.after(event, &self.area);
+        if press == self.press && !activated {
+            return None;
+        }
+
+        let mut next = self.clone();
+        next.press = press;
+        if activated {
+            next = next.clicked();
+        }
+        Some(Box::new(next))
+    }
+
+    fn perceive(&self, scene: &SceneInfo) -> SceneInfo {
+        scene.clone()
+    }
+
+    fn output(&self) -> AcetateIO {
+        AcetateIO {
+            content: self.label.clone(),
+            focus: self.press.focused,
+            status: self.status.clone(),
+            clipboard: None,
+            action: self.action.clone(),
+        }
+    }
+
+    fn design(&self) -> AcetateDesign {
+        let style = self.current_style();
+        AcetateDesign {
+            area: self.area,
+            background: style.fill,
+            border: style.border,
+            border_thickness: self.style.border_thickness,
+            text: self.label.clone(),
+            text_color: style.text_color,
+            font_size: self.style.font_size,
+            text_align: TextAlign::Center,
+            shapes: Vec::new(),
+            commands: Vec::new(),
+            tooltip: self.tooltip.clone(),
+            ..AcetateDesign::default()
+        }
+    }
+
+    fn clone_box(&self) -> Box<dyn Acetate> {
+        Box::new(self.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::acetates::test_support::drag;
+    use crate::contracts::event::keys;
+    use crate::contracts::scene::Scene;
+    use crate::runtime::ActionCollector;
+
+    fn scene() -> Scene {
+        let button = Button::new("save", Rect::new(10.0, 10.0, 100.0, 30.0)).with_label("Guardar");
+        Scene::from(vec![Box::new(button) as Box<dyn Acetate>])
+    }
+
+    #[test]
+    fn click_inside_emits_once_and_enter_repeats_with_focus() {
+        let mut actions = ActionCollector::new();
+        let scene = scene().react(&drag(20, 20, (30, 25)));
+        let emitted = scene.acetates[0].output().action;
+        assert_eq!(emitted, Some(EmittedAction { serial: 1, action: Action::Click }));
+        let delivered = vec![Event::Action { source: "save".to_string(), action: Action::Click }];
+        assert_eq!(actions.collect(&scene), delivered);
+        // La acción sigue expuesta, pero solo se entrega una vez.
+        assert!(actions.collect(&scene).is_empty());
+
+        // El clic dejó el foco: Enter vuelve a pulsarlo.
+        let scene = scene.react(&[Event::KeyPressed(keys::ENTER.to_string())]);
+        assert_eq!(scene.acetates[0].output().action.map(|a| a.serial), Some(2));
+        assert_eq!(actions.collect(&scene), delivered);
+    }
+
+    #[test]
+    fn press_inside_and_release_outside_does_not_click() {
+        let scene = scene().react(&drag(20, 20, (300, 25)));
+        assert_eq!(scene.acetates[0].output().action, None);
+
+        // Presionar fuera y soltar dentro tampoco cuenta.
+        let scene = scene.react(&drag(300, 25, (20, 20)));
+        assert_eq!(scene.acetates[0].output().action, None);
+    }
+}
@@ -0,0 +1,256 @@
+//! Lienzo de dibujo inmediato: el acetate se describe con `DrawCommand`s.
+
+use std::fmt;
+use std::sync::Arc;
+
+use crate::contracts::event::{AnimationFrame, Event, EventKind};
+use crate::contracts::scene::{
+    Acetate, AcetateDesign, AcetateIO, AcetateStatus, DrawCommand, Rect, SceneInfo,
+};
+use crate::core::Color;
+
+/// Lo que el pintor sabe del lienzo en cada frame.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CanvasContext {
+    pub width: f32,
+    pub height: f32,
+    /// Puntero en coordenadas locales, si está sobre el lienzo.
+    pub pointer: Option<(f32, f32)>,
+    /// Segundos acumulados de `Tick` (solo avanza con `with_animation(true)`).
+    pub time: f32,
+}
+
+type Paint = dyn Fn(&CanvasContext) -> Vec<DrawCommand> + Send + Sync;
+
+/// Función que produce los comandos del lienzo a partir de su contexto.
+#[derive(Clone)]
+pub struct Painter(Arc<Paint>);
+
+impl Painter {
+    pub fn new(paint: impl Fn(&CanvasContext) -> Vec<DrawCommand> + Send + Sync + 'static) -> Self {
+        Self(Arc::new(paint))
+    }
+
+    pub fn paint(&self, context: &CanvasContext) -> Vec<DrawCommand> {
+        (self.0)(context)
+    }
+}
+
+impl fmt::Debug for Painter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Painter")
+    }
+}
+
+/// Vía de escape para visualizaciones a medida: en vez de un rectángulo con formas,
+/// el lienzo entrega una lista de comandos (trazados, relleno, trazo, texto, imágenes
+/// y transformaciones) que viajan en `SnapshotLayer::commands` y que `VelloAdapter`
+/// reproduce recortados al área. Los comandos pueden ser fijos (`with_commands`) o
+/// salir de un `Painter` que se vuelve a llamar cuando cambia el contexto.
+#[derive(Debug, Clone)]
+pub struct Canvas {
+    id: String,
+    name: String,
+    z_index: i32,
+    area: Rect,
+    background: Color,
+    commands: Vec<DrawCommand>,
+    painter: Option<Painter>,
+    animated: bool,
+    status: AcetateStatus,
+    pointer: Option<(f32, f32)>,
+    time: f32,
+}
+
+impl Canvas {
+    pub fn new(id: &str, area: Rect) -> Self {
+        Self {
+            id: id.to_string(),
+            name: id.to_string(),
+            z_index: 0,
+            area,
+            background: Color::TRANSPARENT,
+            commands: Vec::new(),
+            painter: None,
+            animated: false,
+            status: AcetateStatus::Ready,
+            pointer: None,
+            time: 0.0,
+        }
+    }
+
+    pub fn with_name(mut self, name: &str) -> Self {
+        self.name = name.to_string();
+        self
+    }
+
+    pub fn with_z_index(mut self, z_index: i32) -> Self {
+        self.z_index = z_index;
+        self
+    }
+
+    pub fn with_background(mut self, background: Color) -> Self {
+        self.background = background;
+        self
+    }
+
+    /// Comandos fijos; se ignoran si hay pintor.
+    pub fn with_commands(mut self, commands: Vec<DrawCommand>) -> Self {
+        self.commands = commands;
+        self
+    }
+
+    pub fn with_painter(
+        mut self,
+        paint: impl Fn(&CanvasContext) -> Vec<DrawCommand> + Send + Sync + 'static,
+    ) -> Self {
+        self.painter = Some(Painter::new(paint));
+        self
+    }
+
+    /// Con `true`, cada `Tick` avanza `CanvasContext::time` y repinta.
+    pub fn with_animation(mut self, animated: bool) -> Self {
+        self.animated = animated;
+        self
+    }
+
+    pub fn with_status(mut self, status: AcetateStatus) -> Self {
+        self.status = status;
+        self
+    }
+
+    pub fn context(&self) -> CanvasContext {
+        CanvasContext {
+            width: self.area.width,
+            height: self.area.height,
+            pointer: self.pointer,
+            time: self.time,
+        }
+    }
+}
+
+impl Acetate for Canvas {
+    fn id(&self) -> String {
+        self.id.clone()
+    }
+
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn z_index(&self) -> i32 {
+        self.z_index
+    }
+
+    fn area(&self) -> Rect {
+        self.area
+    }
+
+    fn relocate(&self, area: Rect) -> Box<dyn Acetate> {
+        Box::new(Self {
+            area,
+            ..self.clone()
+        })
+    }
+
+    fn subscriptions(&self) -> Vec<EventKind> {
+        if self.painter.is_some() {
+            vec![EventKind::MouseMoved]
+        } else {
+            Vec::new()
+        }
+    }
+
+    fn react(&self, event: &Event, _scene: &SceneInfo) -> Option<Box<dyn Acetate>> {
+        let Event::MouseMoved(x, y) = event else {
+            return None;
+        };
+        let pointer = self
+            .area
+            .contains(*x, *y)
+            .then_some((*x as f32 - self.area.x, *y as f32 - self.area.y));
+        if pointer == self.pointer {
+            return None;
+        }
+        Some(Box::new(Self {
+            pointer,
+            ..self.clone()
+        }))
+    }
+
+    fn animate(&self, frame: &AnimationFrame) -> Option<Box<dyn Acetate>> {
+        if !self.animated {
+            return None;
+        }
+        Some(Box::new(Self {
+            time: self.time + frame.dt,
+            ..self.clone()
+        }))
+    }
+
+    fn perceive(&self, scene: &SceneInfo) -> SceneInfo {
+        scene.clone()
+    }
+
+    fn output(&self) -> AcetateIO {
+        AcetateIO {
+            content: None,
+            focus: false,
+            status: self.status.clone(),
+            clipboard: None,
+            action: None,
+        }
+    }
+
+    fn design(&self) -> AcetateDesign {
+        let commands = match &self.painter {
+            Some(painter) => painter.paint(&self.context()),
+            None => self.commands.clone(),
+        };
+        AcetateDesign {
+            area: self.area,
+            background: self.background,
+            commands,
+            ..AcetateDesign::default()
+        }
+    }
+
+    fn clone_box(&self) -> Box<dyn Acetate> {
+        Box::new(self.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::actors::snapshot_builder::SnapshotBuilder;
+    use crate::contracts::scene::Scene;
+
+    #[test]
+    fn painter_commands_follow_the_pointer_into_the_snapshot() {
+        let area = Rect::new(100.0, 50.0, 200.0, 100.0);
+        let canvas = Canvas::new("scope", area).with_painter(|context| match context.pointer {
+            Some((x, y)) => vec![
+                DrawCommand::BeginPath,
+                DrawCommand::MoveTo(x, 0.0),
+                DrawCommand::LineTo(x, y),
+                DrawCommand::Stroke {
+                    color: Color::rgba(1.0, 1.0, 1.0, 1.0),
+                    width: 1.0,
+                },
+            ],
+            None => Vec::new(),
+        });
+        let scene = Scene::from(vec![Box::new(canvas) as Box<dyn Acetate>]);
+        assert!(
+            SnapshotBuilder::build(scene.clone()).layers[0]
+                .commands
+                .is_empty()
+        );
+
+        let scene = scene.react(&[Event::MouseMoved(130, 90)]);
+        let layers = SnapshotBuilder::build(scene).layers;
+        assert_eq!(layers[0].commands[1], DrawCommand::MoveTo(30.0, 0.0));
+        assert_eq!(layers[0].commands[2], DrawCommand::LineTo(30.0, 40.0));
+    }
+}
@@ -0,0 +1,705 @@
+//! Gráficos de líneas, barras y dispersión con ejes autoescalados.
+
+use std::sync::Arc;
+
+use super::label::Label;
+use crate::contracts::event::{Data, Event, EventKind, Series};
+use crate::contracts::scene::{
+    Acetate, AcetateDesign, AcetateIO, AcetateStatus, LINE_HEIGHT, Rect, SceneInfo, Shape,
+    ShapeStroke, TextAlign, text_width,
+};
+use crate::core::Color;
+
+/// Divisiones aproximadas de cada eje; el paso real se redondea a 1, 2 o 5 × 10ⁿ.
+const TICKS: f32 = 5.0;
+/// Márgenes del área de trazado: izquierda (etiquetas de y), derecha, arriba y abajo
+/// (etiquetas de x).
+const MARGIN: (f32, f32, f32, f32) = (48.0, 12.0, 8.0, 20.0);
+/// Distancia máxima, en píxeles, para que el puntero señale un punto.
+const HOVER_RADIUS: f32 = 12.0;
+/// Fracción de cada categoría que ocupan sus barras (el resto es hueco).
+const BAR_GROUP: f32 = 0.8;
+
+/// Cómo se dibujan las series.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ChartKind {
+    #[default]
+    Line,
+    /// Barras agrupadas por índice de punto; las `x` solo rotulan las categorías.
+    Bar,
+    Scatter,
+}
+
+impl ChartKind {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim() {
+            "line" => Some(ChartKind::Line),
+            "bar" => Some(ChartKind::Bar),
+            "scatter" => Some(ChartKind::Scatter),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChartStyle {
+    pub background: Color,
+    pub border: Color,
+    pub axis: Color,
+    pub grid: Color,
+    pub text_color: Color,
+    pub font_size: f32,
+    pub readout_background: Color,
+    /// Colores de las series, en orden; se repiten si hay más series que colores.
+    pub palette: Vec<Color>,
+}
+
+impl Default for ChartStyle {
+    fn default() -> Self {
+        Self {
+            background: Color::rgba(0.11, 0.12, 0.15, 1.0),
+            border: Color::TRANSPARENT,
+            axis: Color::rgba(0.45, 0.47, 0.55, 1.0),
+            grid: Color::rgba(1.0, 1.0, 1.0, 0.06),
+            text_color: Color::rgba(0.70, 0.72, 0.78, 1.0),
+            font_size: 11.0,
+            readout_background: Color::rgba(0.08, 0.09, 0.11, 0.95),
+            palette: vec![
+                Color::rgba(0.35, 0.55, 0.95, 1.0),
+                Color::rgba(0.95, 0.60, 0.25, 1.0),
+                Color::rgba(0.35, 0.80, 0.55, 1.0),
+                Color::rgba(0.85, 0.35, 0.45, 1.0),
+                Color::rgba(0.65, 0.50, 0.90, 1.0),
+            ],
+        }
+    }
+}
+
+/// Eje lineal con extremos y paso "redondos".
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Scale {
+    min: f32,
+    max: f32,
+    step: f32,
+}
+
+impl Scale {
+    fn nice(min: f32, max: f32) -> Self {
+        let (min, max) = if max > min {
+            (min, max)
+        } else {
+            (min - 1.0, max + 1.0)
+        };
+        let raw = (max - min) / TICKS;
+        let magnitude = 10f32.powf(raw.log10().floor());
+        let step = [1.0, 2.0, 5.0, 10.0]
+            .into_iter()
+            .map(|m| m * magnitude)
+            .find(|step| *step >= raw)
+            .unwrap_or(raw);
+        Self {
+            min: (min / step).floor() * step,
+            max: (max / step).ceil() * step,
+            step,
+        }
+    }
+
+    fn ticks(&self) -> Vec<f32> {
+        let count = ((self.max - self.min) / self.step).round() as usize;
+        (0..=count)
+            .map(|i| self.min + i as f32 * self.step)
+            .collect()
+    }
+
+    /// Fracción 0..1 de `value` dentro del eje.
+    fn unit(&self, value: f32) -> f32 {
+        (value - self.min) / (self.max - self.min)
+    }
+
+    /// Texto de `value` con los decimales que pide el paso.
+    fn format(&self, value: f32) -> String {
+        let decimals = (-self.step.log10().floor()).max(0.0) as usize;
+        format!("{:.*}", decimals, value)
+    }
+}
+
+/// Geometría derivada de los datos: área de trazado y escalas.
+struct Layout {
+    plot: Rect,
+    x: Scale,
+    y: Scale,
+    /// Número de categorías (solo barras).
+    categories: usize,
+}
+
+impl Layout {
+    fn px(&self, (x, y): (f32, f32)) -> (f32, f32) {
+        let plot = &self.plot;
+        (
+            plot.x + self.x.unit(x) * plot.width,
+            plot.y + (1.0 - self.y.unit(y)) * plot.height,
+        )
+    }
+
+    fn y_px(&self, y: f32) -> f32 {
+        self.px((self.x.min, y)).1
+    }
+
+    fn band(&self) -> f32 {
+        self.plot.width / self.categories.max(1) as f32
+    }
+}
+
+/// Gráfico de series. Calcula escalas "redondas" a partir de los datos, dibuja
+/// rejilla, ejes, rótulos y las series con la paleta del estilo, y al pasar el
+/// puntero cerca de un punto (o sobre una barra) muestra su lectura. Los datos
+/// llegan con `with_series` o `Data::Series`; `output().content` es la lectura
+/// señalada.
+#[derive(Debug, Clone)]
+pub struct Chart {
+    id: String,
+    name: String,
+    z_index: i32,
+    area: Rect,
+    kind: ChartKind,
+    series: Arc<Vec<Series>>,
+    title: Option<String>,
+    style: ChartStyle,
+    status: AcetateStatus,
+    /// Punto señalado: (serie, índice).
+    hovered: Option<(usize, usize)>,
+}
+
+impl Chart {
+    pub fn new(id: &str, area: Rect) -> Self {
+        Self {
+            id: id.to_string(),
+            name: id.to_string(),
+            z_index: 0,
+            area,
+            kind: ChartKind::Line,
+            series: Arc::new(Vec::new()),
+            title: None,
+            style: ChartStyle::default(),
+            status: AcetateStatus::Ready,
+            hovered: None,
+        }
+    }
+
+    pub fn with_name(mut self, name: &str) -> Self {
+        self.name = name.to_string();
+        self
+    }
+
+    pub fn with_z_index(mut self, z_index: i32) -> Self {
+        self.z_index = z_index;
+        self
+    }
+
+    pub fn with_kind(mut self, kind: ChartKind) -> Self {
+        self.kind = kind;
+        self
+    }
+
+    pub fn with_series(mut self, series: impl IntoIterator<Item = Series>) -> Self {
+        self.series = Arc::new(series.into_iter().collect());
+        self.hovered = None;
+        self
+    }
+
+    pub fn with_title(mut self, title: &str) -> Self {
+        self.title = Some(title.to_string());
+        self
+    }
+
+    pub fn with_style(mut self, style: ChartStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    pub fn with_status(mut self, status: AcetateStatus) -> Self {
+        self.status = status;
+        self
+    }
+
+    pub fn series(&self) -> &[Series] {
+        &self.series
+    }
+
+    fn color(&self, series: usize) -> Color {
+        match self.style.palette.len() {
+            0 => self.style.axis,
+            len => self.style.palette[series % len],
+        }
+    }
+
+    fn title_height(&self) -> f32 {
+        match self.title {
+            Some(_) => (self.style.font_size * LINE_HEIGHT).ceil() + 4.0,
+            None => 0.0,
+        }
+    }
+
+    fn layout(&self) -> Layout {
+        let (left, right, top, bottom) = MARGIN;
+        let area = &self.area;
+        let top = top + self.title_height();
+        let plot = Rect {
+            x: area.x + left,
+            y: area.y + top,
+            width: (area.width - left - right).max(1.0),
+            height: (area.height - top - bottom).max(1.0),
+        };
+
+        let points = || self.series.iter().flat_map(|s| s.points.iter());
+        let bounds = |values: &mut dyn Iterator<Item = f32>| {
+            values.fold(None, |acc: Option<(f32, f32)>, v| match acc {
+                Some((lo, hi)) => Some((lo.min(v), hi.max(v))),
+                None => Some((v, v)),
+            })
+        };
+        let (x_min, x_max) = bounds(&mut points().map(|p| p.0)).unwrap_or((0.0, 1.0));
+        let (mut y_min, mut y_max) = bounds(&mut points().map(|p| p.1)).unwrap_or((0.0, 1.0));
+        let categories = self
+            .series
+            .iter()
+            .map(|s| s.points.len())
+            .max()
+            .unwrap_or(0);
+        if self.kind == ChartKind::Bar {
+            // Las barras nacen del cero: el eje siempre lo incluye.
+            y_min = y_min.min(0.0);
+            y_max = y_max.max(0.0);
+        }
+
+        Layout {
+            plot,
+            x: Scale::nice(x_min, x_max),
+            y: Scale::nice(y_min, y_max),
+            categories,
+        }
+    }
+
+    fn bar_rect(&self, layout: &Layout, series: usize, index: usize, value: f32) -> Rect {
+        let band = layout.band();
+        let width = band * BAR_GROUP / self.series.len().max(1) as f32;
+        let x = layout.plot.x + band * index as f32 + band * (1.0 - BAR_GROUP) / 2.0;
+        let x = x + width * series as f32;
+        let top = layout.y_px(value.max(0.0));
+        let bottom = layout.y_px(value.min(0.0));
+        Rect::new(x, top, width.max(1.0), (bottom - top).max(1.0))
+    }
+
+    /// Punto bajo el puntero: la barra que lo contiene o el punto más cercano dentro
+    /// de `HOVER_RADIUS`.
+    fn hit(&self, x: i32, y: i32) -> Option<(usize, usize)> {
+        let layout = self.layout();
+        if !self.area.contains(x, y) {
+            return None;
+        }
+        if self.kind == ChartKind::Bar {
+            return self.series.iter().enumerate().find_map(|(s, series)| {
+                series.points.iter().enumerate().find_map(|(i, point)| {
+                    self.bar_rect(&layout, s, i, point.1)
+                        .contains(x, y)
+                        .then_some((s, i))
+                })
+            });
+        }
+        let mut best = None;
+        let mut best_distance = HOVER_RADIUS;
+        for (s, series) in self.series.iter().enumerate() {
+            for (i, point) in series.points.iter().enumerate() {
+                let (px, py) = layout.px(*point);
+                let distance = (px - x as f32).hypot(py - y as f32);
+                if distance <= best_distance {
+                    best_distance = distance;
+                    best = Some((s, i));
+                }
+            }
+        }
+        best
+    }
+
+    fn readout(&self) -> Option<String> {
+        let (s, i) = self.hovered?;
+        let series = self.series.get(s)?;
+        let point = series.points.get(i)?;
+        let layout = self.layout();
+        Some(format!(
+            "{}: {}, {}",
+            series.name,
+            layout.x.format(point.0),
+            layout.y.format(point.1)
+        ))
+    }
+
+    fn label(&self, id: &str, area: Rect, text: String) -> Label {
+        Label::new(&format!("{}/{}", self.id, id), area, &text)
+            .with_text_color(self.style.text_color)
+            .with_font_size(self.style.font_size)
+    }
+}
+
+impl Acetate for Chart {
+    fn id(&self) -> String {
+        self.id.clone()
+    }
+
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn z_index(&self) -> i32 {
+        self.z_index
+    }
+
+    fn area(&self) -> Rect {
+        self.area
+    }
+
+    fn relocate(&self, area: Rect) -> Box<dyn Acetate> {
+        Box::new(Self {
+            area,
+            ..self.clone()
+        })
+    }
+
+    fn restyle(
+        &self,
+        _border_thickness: Option<f32>,
+        font_size: Option<f32>,
+    ) -> Box<dyn Acetate> {
+        let mut style = self.style.clone();
+        style.font_size = font_size.unwrap_or(style.font_size);
+        Box::new(self.clone().with_style(style))
+    }
+
+    fn subscriptions(&self) -> Vec<EventKind> {
+        vec![EventKind::MouseMoved, EventKind::Data]
+    }
+
+    fn react(&self, event: &Event, _scene: &SceneInfo) -> Option<Box<dyn Acetate>> {
+        let next = match event {
+            Event::Data {
+                target,
+                data: Data::Series(series),
+            } if *target == self.id => Self {
+                series: series.clone(),
+                hovered: None,
+                ..self.clone()
+            },
+            Event::MouseMoved(x, y) => {
+                let hovered = self.hit(*x, *y);
+                if hovered == self.hovered {
+                    return None;
+                }
+                Self {
+                    hovered,
+                    ..self.clone()
+                }
+            }
+            _ => return None,
+        };
+        Some(Box::new(next))
+    }
+
+    fn perceive(&self, scene: &SceneInfo) -> SceneInfo {
+        scene.clone()
+    }
+
+    fn output(&self) -> AcetateIO {
+        AcetateIO {
+            content: self.readout(),
+            focus: false,
+            status: self.status.clone(),
+            clipboard: None,
+            action: None,
+        }
+    }
+
+    fn design(&self) -> AcetateDesign {
+        let layout = self.layout();
+        let plot = &layout.plot;
+        let (left, top) = (plot.x, plot.y);
+        let (right, bottom) = (left + plot.width, top + plot.height);
+        let line = |points: Vec<(f32, f32)>, color: Color, width: f32| Shape::Polyline {
+            points,
+            stroke: ShapeStroke { color, width },
+        };
+
+        let mut shapes = Vec::new();
+        for y in layout.y.ticks() {
+            let py = layout.y_px(y);
+            shapes.push(line(vec![(left, py), (right, py)], self.style.grid, 1.0));
+        }
+        if self.kind != ChartKind::Bar {
+            for x in layout.x.ticks() {
+                let px = layout.px((x, layout.y.min)).0;
+                shapes.push(line(vec![(px, top), (px, bottom)], self.style.grid, 1.0));
+            }
+        }
+        shapes.push(line(
+            vec![(left, top), (left, bottom), (right, bottom)],
+            self.style.axis,
+            1.0,
+        ));
+
+        for (s, series) in self.series.iter().enumerate() {
+            let color = self.color(s);
+            match self.kind {
+                ChartKind::Line => {
+                    let points = series.points.iter().map(|p| layout.px(*p)).collect();
+                    shapes.push(line(points, color, 2.0));
+                }
+                ChartKind::Scatter => {
+                    shapes.extend(series.points.iter().map(|p| Shape::Circle {
+                        center: layout.px(*p),
+                        radius: 3.0,
+                        fill: color,
+                        stroke: None,
+                    }));
+                }
+                ChartKind::Bar => {
+                    shapes.extend(series.points.iter().enumerate().map(|(i, p)| Shape::Rect {
+                        area: self.bar_rect(&layout, s, i, p.1),
+                        fill: color,
+                    }));
+                }
+            }
+        }
+
+        // Marca del punto señalado (en barras, la lectura basta).
+        if let Some((s, i)) = self.hovered.filter(|_| self.kind != ChartKind::Bar)
+            && let Some(point) = self.series.get(s).and_then(|series| series.points.get(i))
+        {
+            shapes.push(Shape::Circle {
+                center: layout.px(*point),
+                radius: 5.0,
+                fill: self.color(s),
+                stroke: Some(ShapeStroke {
+                    color: self.style.text_color,
+                    width: 1.5,
+                }),
+            });
+        }
+
+        AcetateDesign {
+            area: self.area,
+            background: self.style.background,
+            border: self.style.border,
+            border_thickness: if self.style.border.a > 0.0 { 1.0 } else { 0.0 },
+            shapes,
+            ..AcetateDesign::default()
+        }
+    }
+
+    /// Rótulos de los ejes, el título y la lectura del punto señalado.
+    fn children(&self) -> Vec<Box<dyn Acetate>> {
+        let layout = self.layout();
+        let plot = &layout.plot;
+        let line_height = (self.style.font_size * LINE_HEIGHT).ceil();
+        let mut children: Vec<Box<dyn Acetate>> = Vec::new();
+
+        if let Some(title) = &self.title {
+            let area = Rect {
+                x: plot.x,
+                y: self.area.y + MARGIN.2,
+                width: plot.width,
+                height: line_height,
+            };
+            children.push(Box::new(self.label("title", area, title.clone())));
+        }
+
+        for (i, y) in layout.y.ticks().into_iter().enumerate() {
+            let area = Rect {
+                x: self.area.x,
+                y: layout.y_px(y) - line_height / 2.0,
+                width: MARGIN.0 - 6.0,
+                height: line_height,
+            };
+            let label = self
+                .label(&format!("y{i}"), area, layout.y.format(y))
+                .with_align(TextAlign::End);
+            children.push(Box::new(label));
+        }
+
+        let x_labels: Vec<(f32, String)> = match self.kind {
+            ChartKind::Bar => {
+                let band = layout.band();
+                let first = self.series.iter().find(|s| !s.points.is_empty());
+                first
+                    .map(|s| {
+                        s.points
+                            .iter()
+                            .enumerate()
+                            .map(|(i, p)| {
+                                let center = plot.x + band * (i as f32 + 0.5);
+                                (center, layout.x.format(p.0))
+                            })
+                            .collect()
+                    })
+                    .unwrap_or_default()
+            }
+            _ => layout
+                .x
+                .ticks()
+                .into_iter()
+                .map(|x| (layout.px((x, layout.y.min)).0, layout.x.format(x)))
+                .collect(),
+        };
+        let label_width = 48.0;
+        for (i, (center, text)) in x_labels.into_iter().enumerate() {
+            let area = Rect::new(
+                center - label_width / 2.0,
+                plot.bottom() + 4.0,
+                label_width,
+                line_height,
+            );
+            let label = self
+                .label(&format!("x{i}"), area, text)
+                .with_align(TextAlign::Center);
+            children.push(Box::new(label));
+        }
+
+        if let (Some(text), Some((s, i))) = (self.readout(), self.hovered) {
+            let point = self.series[s].points[i];
+            let (px, py) = match self.kind {
+                ChartKind::Bar => {
+                    let bar = self.bar_rect(&layout, s, i, point.1);
+                    (bar.x + bar.width, bar.y)
+                }
+                _ => layout.px(point),
+            };
+            let width = (text_width(&text, self.style.font_size) + 12.0).floor();
+            let height = line_height + 8.0;
+            let max_x = plot.right() - width;
+            let area = Rect::new(
+                (px + 8.0).min(max_x).max(plot.x),
+                (py - height - 4.0).max(plot.y),
+                width,
+                height,
+            );
+            let readout = self
+                .label("readout", area, text)
+                .with_align(TextAlign::Center)
+                .with_background(self.style.readout_background)
+                .with_border(self.color(s));
+            children.push(Box::new(readout));
+        }
+
+        children
+    }
+
+    fn clone_box(&self) -> Box<dyn Acetate> {
+        Box::new(self.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::actors::snapshot_builder::SnapshotBuilder;
+    use crate::actors::vello_adapter::VelloAdapter;
+    use crate::contracts::scene::Scene;
+
+    #[test]
+    fn axes_autoscale_and_hover_reads_nearest_point() {
+        let area = Rect::new(0.0, 0.0, 260.0, 128.0);
+        let chart = Chart::new("cpu_chart", area);
+        let info = Scene::from(Vec::new()).info();
+        let plot = Event::Data {
+            target: "cpu_chart".to_string(),
+            data: Data::Series(Arc::new(vec![Series::new("cpu", [(0.0, 3.0), (10.0, 95.0)])])),
+        };
+        let chart = chart.react(&plot, &info).unwrap();
+
+        // 3..95 se redondea a 0..100 con paso 20: seis rótulos en y.
+        assert_eq!(y_labels(chart.as_ref()), ["0", "20", "40", "60", "80", "100"]);
+
+        // Área de trazado: x 48..248, y 8..108. (10, 95) cae en (248, 13).
+        let chart = chart.react(&Event::MouseMoved(244, 16), &info).unwrap();
+        assert_eq!(chart.output().content.as_deref(), Some("cpu: 10, 95"));
+        assert!(chart.react(&Event::MouseMoved(150, 60), &info).is_some());
+    }
+
+    #[test]
+    fn tick_labels_title_and_readout_reach_the_renderer() {
+        let area = Rect::new(0.0, 0.0, 260.0, 128.0);
+        let info = Scene::from(Vec::new()).info();
+        let chart = Chart::new("cpu_chart", area)
+            .with_title("CPU")
+            .with_series([Series::new("cpu", [(0.0, 3.0), (10.0, 95.0)])]);
+        let chart = chart.react(&Event::MouseMoved(244, 30), &info).unwrap();
+        let snapshot = SnapshotBuilder::build(Scene::from(vec![chart]));
+
+        let texts: Vec<(&str, TextAlign)> = snapshot
+            .layers
+            .iter()
+            .filter_map(|layer| Some((layer.style.text.as_deref()?, layer.style.text_align)))
+            .collect();
+        for expected in [
+            ("CPU", TextAlign::Start),
+            ("100", TextAlign::End),
+            ("10", TextAlign::Center),
+            ("cpu: 10, 95", TextAlign::Center),
+        ] {
+            assert!(texts.contains(&expected), "{expected:?} en {texts:?}");
+        }
+
+        // Cada carácter visible de los rótulos llega al adaptador como un glifo.
+        let visible: usize = texts
+            .iter()
+            .map(|(text, _)| text.chars().filter(|c| !c.is_whitespace()).count())
+            .sum();
+        let vello = VelloAdapter::adapt(snapshot);
+        assert_eq!(vello.encoding().resources.glyphs.len(), visible);
+    }
+
+    fn y_labels(chart: &dyn Acetate) -> Vec<String> {
+        let prefix = format!("{}/y", chart.id());
+        chart
+            .children()
+            .iter()
+            .filter(|c| c.id().starts_with(&prefix))
+            .filter_map(|c| c.output().content)
+            .collect()
+    }
+
+    #[test]
+    fn empty_series_draw_a_default_axis_without_readout() {
+        let area = Rect::new(0.0, 0.0, 260.0, 128.0);
+        let info = Scene::from(Vec::new()).info();
+        for chart in [
+            Chart::new("empty", area),
+            Chart::new("empty", area).with_series([Series::new("cpu", [])]),
+        ] {
+            assert_eq!(y_labels(&chart), ["0.0", "0.2", "0.4", "0.6", "0.8", "1.0"]);
+            assert!(chart.react(&Event::MouseMoved(150, 60), &info).is_none());
+            assert!(chart.output().content.is_none());
+            SnapshotBuilder::build(Scene::from(vec![chart.clone_box()]));
+        }
+    }
+
+    #[test]
+    fn axes_round_outwards_and_bars_start_at_zero() {
+        let area = Rect::new(0.0, 0.0, 260.0, 128.0);
+        let line = Chart::new("c", area)
+            .with_series([Series::new("mem", [(0.0, 1200.0), (1.0, 5600.0)])]);
+        assert_eq!(y_labels(&line), ["1000", "2000", "3000", "4000", "5000", "6000"]);
+
+        // Un valor constante se abre una unidad por cada lado.
+        let flat = Chart::new("c", area)
+            .with_series([Series::new("mem", [(0.0, 5.0), (1.0, 5.0)])]);
+        assert_eq!(y_labels(&flat), ["4.0", "4.5", "5.0", "5.5", "6.0"]);
+
+        let bars = Chart::new("c", area)
+            .with_kind(ChartKind::Bar)
+            .with_series([Series::new("ventas", [(0.0, 20.0), (1.0, 80.0)])]);
+        assert_eq!(y_labels(&bars), ["0", "20", "40", "60", "80"]);
+
+        let scale = Scale::nice(-30.0, 70.0);
+        assert_eq!((scale.min, scale.max, scale.step), (-40.0, 80.0, 20.0));
+        assert_eq!(scale.unit(20.0), 0.5);
+    }
+}
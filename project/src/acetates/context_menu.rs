@@ -0,0 +1,261 @@
+//! Menú contextual: clic derecho sobre una región abre un menú en el puntero.
+
+use super::popup::{MenuStyle, PopupMenu};
+use crate::contracts::event::{Action, Event, EventKind, MouseButton, keys};
+use crate::contracts::scene::{
+    Acetate, AcetateDesign, AcetateIO, AcetateStatus, EmittedAction, Overlay, Rect, SceneInfo,
+};
+
+/// Menú contextual sobre `area` (p. ej. una fila de tabla). No se pinta hasta
+/// abrirse con clic derecho; el menú se desplaza para caber en la ventana.
+/// Elegir una entrada emite `Action::Selected(etiqueta)`; Escape o un clic
+/// fuera lo cierran, y ese clic no llega a los acetatos de debajo (ver
+/// `Scene::react`). `output().content` es la última entrada elegida.
+#[derive(Debug, Clone)]
+pub struct ContextMenu {
+    id: String,
+    name: String,
+    z_index: i32,
+    area: Rect,
+    items: Vec<String>,
+    width: u32,
+    style: MenuStyle,
+    status: AcetateStatus,
+    menu: Option<PopupMenu>,
+    chosen: Option<usize>,
+    action: Option<EmittedAction>,
+}
+
+impl ContextMenu {
+    pub fn new(id: &str, area: Rect) -> Self {
+        Self {
+            id: id.to_string(),
+            name: id.to_string(),
+            z_index: 0,
+            area,
+            items: Vec::new(),
+            width: 160,
+            style: MenuStyle::default(),
+            status: AcetateStatus::Ready,
+            menu: None,
+            chosen: None,
+            action: None,
+        }
+    }
+
+    pub fn with_name(mut self, name: &str) -> Self {
+        self.name = name.to_string();
+        self
+    }
+
+    pub fn with_z_index(mut self, z_index: i32) -> Self {
+        self.z_index = z_index;
+        self
+    }
+
+    pub fn with_items<S: Into<String>>(mut self, items: impl IntoIterator<Item = S>) -> Self {
+        self.items = items.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Ancho del menú emergente.
+    pub fn with_width(mut self, width: u32) -> Self {
+        self.width = width;
+        self
+    }
+
+    pub fn with_style(mut self, style: MenuStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    pub fn with_status(mut self, status: AcetateStatus) -> Self {
+        self.status = status;
+        self
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.menu.is_some()
+    }
+
+    fn choose(&mut self, row: Option<usize>) {
+        self.menu = None;
+        if let Some(row) = row {
+            self.chosen = Some(row);
+            let action = Action::Selected(self.items[row].clone());
+            self.action = Some(EmittedAction::next(self.action.as_ref(), action));
+        }
+    }
+}
+
+impl Acetate for ContextMenu {
+    fn id(&self) -> String {
+        self.id.clone()
+    }
+
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn z_index(&self) -> i32 {
+        self.z_index
+    }
+
+    fn area(&self) -> Rect {
+        self.area
+    }
+
+    fn relocate(&self, area: Rect) -> Box<dyn Acetate> {
+        Box::new(Self {
+            area,
+            menu: None,
+            ..self.clone()
+        })
+    }
+
+    fn restyle(
+        &self,
+        _border_thickness: Option<f32>,
+        font_size: Option<f32>,
+    ) -> Box<dyn Acetate> {
+        let mut style = self.style.clone();
+        style.font_size = font_size.unwrap_or(style.font_size);
+        Box::new(self.clone().with_style(style))
+    }
+
+    fn subscriptions(&self) -> Vec<EventKind> {
+        vec![
+            EventKind::MouseMoved,
+            EventKind::MouseDown,
+            EventKind::MouseUp,
+            EventKind::KeyPressed,
+        ]
+    }
+
+    fn react(&self, event: &Event, scene: &SceneInfo) -> Option<Box<dyn Acetate>> {
+        if !matches!(self.status, AcetateStatus::Ready) {
+            return None;
+        }
+
+        let mut next = self.clone();
+        match (&self.menu, event) {
+            (
+                _,
+                Event::MouseDown {
+                    button: MouseButton::Right,
+                    x,
+                    y,
+                },
+            ) => {
+                next.menu = (self.area.contains(*x, *y) && !self.items.is_empty()).then(|| {
+                    PopupMenu::open(
+                        (*x as f32, *y as f32),
+                        *y as f32,
+                        self.width as f32,
+                        self.items.len(),
+                        self.style.row_height,
+                        (scene.width, scene.height),
+                    )
+                });
+            }
+            (Some(menu), Event::MouseMoved(x, y)) => {
+                let row = menu.row_at(*x, *y)?;
+                next.menu = Some(menu.clone().with_highlighted(Some(row)));
+            }
+            (
+                Some(menu),
+                Event::MouseDown {
+                    button: MouseButton::Left,
+                    x,
+                    y,
+                },
+            ) if menu.row_at(*x, *y).is_none() => next.menu = None,
+            (
+                Some(menu),
+                Event::MouseUp {
+                    button: MouseButton::Left,
+                    x,
+                    y,
+                },
+            ) => next.choose(Some(menu.row_at(*x, *y)?)),
+            (Some(menu), Event::KeyPressed(key)) => match key.as_str() {
+                keys::ESCAPE | keys::TAB => next.menu = None,
+                keys::ENTER | keys::SPACE => next.choose(menu.highlighted),
+                _ => next.menu = Some(menu.clone().with_highlighted(Some(menu.navigate(key)?))),
+            },
+            _ => return None,
+        }
+
+        if next.menu == self.menu && next.chosen == self.chosen && next.action == self.action {
+            return None;
+        }
+        Some(Box::new(next))
+    }
+
+    fn perceive(&self, scene: &SceneInfo) -> SceneInfo {
+        scene.clone()
+    }
+
+    fn output(&self) -> AcetateIO {
+        AcetateIO {
+            content: self.chosen.map(|i| self.items[i].clone()),
+            focus: self.is_open(),
+            status: self.status.clone(),
+            clipboard: None,
+            action: self.action.clone(),
+        }
+    }
+
+    fn design(&self) -> AcetateDesign {
+        AcetateDesign {
+            area: self.area,
+            ..AcetateDesign::default()
+        }
+    }
+
+    fn overlays(&self) -> Vec<Overlay> {
+        self.menu
+            .iter()
+            .map(|menu| menu.overlay(self.z_index, &self.items, &self.style))
+            .collect()
+    }
+
+    fn clone_box(&self) -> Box<dyn Acetate> {
+        Box::new(self.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::acetates::Button;
+    use crate::acetates::test_support::press;
+    use crate::contracts::scene::Scene;
+
+    #[test]
+    fn outside_click_closes_the_menu_without_reaching_the_acetate_below() {
+        let menu = ContextMenu::new("row", Rect::new(0.0, 0.0, 200.0, 24.0))
+            .with_items(["Copiar", "Borrar"]);
+        let button = Button::new("save", Rect::new(0.0, 150.0, 100.0, 24.0));
+        let scene = Scene {
+            width: 400,
+            height: 300,
+            ..Scene::from(vec![
+                Box::new(menu) as Box<dyn Acetate>,
+                Box::new(button) as Box<dyn Acetate>,
+            ])
+        };
+
+        let scene = scene.react(&press(MouseButton::Right, 10, 10));
+        assert_eq!(scene.acetates[0].overlays().len(), 1);
+
+        // El clic cae sobre el botón, fuera del menú: solo cierra el menú.
+        let scene = scene.react(&press(MouseButton::Left, 10, 160));
+        assert!(scene.acetates[0].overlays().is_empty());
+        assert!(scene.acetates[1].output().action.is_none());
+
+        // Cerrado, el mismo clic ya llega al botón.
+        let scene = scene.react(&press(MouseButton::Left, 10, 160));
+        assert!(scene.acetates[1].output().action.is_some());
+    }
+}
@@ -0,0 +1,764 @@
+//! Tabla de datos: cabecera con orden y columnas redimensionables sobre una `VirtualList`.
+
+use std::cmp::Ordering;
+use std::sync::Arc;
+
+use super::label::Label;
+use super::virtual_list::{ListRow, ListStyle, SelectionMode, VirtualList};
+use crate::contracts::event::keys::is_char_key;
+use crate::contracts::event::{Data, Event, EventKind, MouseButton};
+use crate::contracts::scene::{
+    Acetate, AcetateDesign, AcetateIO, AcetateStatus, ClipboardRequest, Rect, SceneInfo, Shape,
+    ShapeStroke,
+};
+use crate::core::Color;
+
+/// Margen horizontal del texto de cada celda.
+const CELL_PADDING: f32 = 6.0;
+/// Distancia al borde derecho de una columna a la que se agarra para redimensionar.
+const RESIZE_GRIP: f32 = 4.0;
+const MIN_COLUMN_WIDTH: u32 = 24;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Column {
+    pub title: String,
+    pub width: u32,
+    pub sortable: bool,
+}
+
+impl Column {
+    pub fn new(title: &str, width: u32) -> Self {
+        Self {
+            title: title.to_string(),
+            width: width.max(MIN_COLUMN_WIDTH),
+            sortable: true,
+        }
+    }
+
+    pub fn with_sortable(mut self, sortable: bool) -> Self {
+        self.sortable = sortable;
+        self
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortOrder {
+    Ascending,
+    Descending,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TableStyle {
+    pub header_background: Color,
+    pub header_text: Color,
+    /// Separadores de la cabecera.
+    pub divider: Color,
+    /// Cuerpo de la tabla (fondo, selección, cebra...).
+    pub body: ListStyle,
+}
+
+impl Default for TableStyle {
+    fn default() -> Self {
+        Self {
+            header_background: Color::rgba(0.15, 0.16, 0.20, 1.0),
+            header_text: Color::rgba(0.80, 0.82, 0.88, 1.0),
+            divider: Color::rgba(0.30, 0.32, 0.40, 1.0),
+            body: ListStyle {
+                stripe: Some(Color::rgba(1.0, 1.0, 1.0, 0.03)),
+                ..ListStyle::default()
+            },
+        }
+    }
+}
+
+/// Arrastre del borde derecho de `column`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Resize {
+    column: usize,
+    origin_x: i32,
+    origin_width: u32,
+}
+
+/// Tabla con fila de cabecera. Los datos llegan de la app (`with_rows` o
+/// `Data::Rows`) y solo las filas visibles se materializan. Clic en una cabecera
+/// ordena por esa columna (otro clic invierte el orden; los números se comparan
+/// como números); arrastrar el borde derecho de una cabecera cambia su ancho.
+/// La selección (simple o múltiple con Shift/Ctrl) se informa en
+/// `output().content` como índices de `rows` separados por comas; con foco, Ctrl+C la
+/// copia al portapapeles como TSV.
+#[derive(Debug, Clone)]
+pub struct DataTable {
+    id: String,
+    name: String,
+    z_index: i32,
+    area: Rect,
+    columns: Vec<Column>,
+    rows: Arc<Vec<Vec<String>>>,
+    /// Posición en pantalla -> índice en `rows`.
+    order: Arc<Vec<usize>>,
+    sort: Option<(usize, SortOrder)>,
+    header_height: u32,
+    style: TableStyle,
+    status: AcetateStatus,
+    body: VirtualList,
+    pressed_column: Option<usize>,
+    resize: Option<Resize>,
+    clipboard: Option<ClipboardRequest>,
+}
+
+impl DataTable {
+    pub fn new(id: &str, area: Rect) -> Self {
+        let style = TableStyle::default();
+        let header_height = 24;
+        let body = VirtualList::new(&format!("{id}/body"), body_area(&area, header_height))
+            .with_style(style.body.clone());
+        let mut table = Self {
+            id: id.to_string(),
+            name: id.to_string(),
+            z_index: 0,
+            area,
+            columns: Vec::new(),
+            rows: Arc::default(),
+            order: Arc::default(),
+            sort: None,
+            header_height,
+            style,
+            status: AcetateStatus::Ready,
+            body,
+            pressed_column: None,
+            resize: None,
+            clipboard: None,
+        };
+        table.refresh();
+        table
+    }
+
+    pub fn with_name(mut self, name: &str) -> Self {
+        self.name = name.to_string();
+        self
+    }
+
+    pub fn with_z_index(mut self, z_index: i32) -> Self {
+        self.z_index = z_index;
+        self.body = self.body.with_z_index(z_index);
+        self
+    }
+
+    pub fn with_columns(mut self, columns: impl IntoIterator<Item = Column>) -> Self {
+        self.columns = columns.into_iter().collect();
+        self.sort = self.sort.filter(|(column, _)| *column < self.columns.len());
+        self.sort_rows();
+        self.refresh();
+        self
+    }
+
+    pub fn with_rows(mut self, rows: Vec<Vec<String>>) -> Self {
+        self.set_rows(Arc::new(rows));
+        self
+    }
+
+    pub fn with_sort(mut self, column: usize, order: SortOrder) -> Self {
+        self.sort = (column < self.columns.len()).then_some((column, order));
+        self.sort_rows();
+        self.refresh();
+        self
+    }
+
+    pub fn with_row_height(mut self, row_height: u32) -> Self {
+        self.body = self.body.with_row_height(row_height);
+        self
+    }
+
+    pub fn with_header_height(mut self, header_height: u32) -> Self {
+        self.header_height = header_height.min(self.area.height as u32);
+        self.body = self
+            .body
+            .with_area(body_area(&self.area, self.header_height));
+        self
+    }
+
+    pub fn with_selection_mode(mut self, mode: SelectionMode) -> Self {
+        self.body = self.body.with_selection_mode(mode);
+        self
+    }
+
+    pub fn with_style(mut self, style: TableStyle) -> Self {
+        self.body = self.body.with_style(style.body.clone());
+        self.style = style;
+        self.refresh();
+        self
+    }
+
+    pub fn with_status(mut self, status: AcetateStatus) -> Self {
+        self.body = self.body.with_status(status.clone());
+        self.status = status;
+        self
+    }
+
+    pub fn columns(&self) -> &[Column] {
+        &self.columns
+    }
+
+    pub fn sort(&self) -> Option<(usize, SortOrder)> {
+        self.sort
+    }
+
+    /// Índices en `rows` de las filas seleccionadas, en orden de pantalla.
+    pub fn selected_rows(&self) -> Vec<usize> {
+        self.body
+            .selection()
+            .iter()
+            .map(|position| self.order[*position])
+            .collect()
+    }
+
+    fn is_disabled(&self) -> bool {
+        !matches!(self.status, AcetateStatus::Ready)
+    }
+
+    /// Filas seleccionadas como TSV: celdas separadas por tabuladores y filas por saltos
+    /// de línea. Los tabuladores y saltos dentro de una celda pasan a espacios.
+    fn selection_tsv(&self) -> Option<String> {
+        let rows = self.selected_rows();
+        if rows.is_empty() {
+            return None;
+        }
+        let tsv = rows
+            .iter()
+            .map(|row| {
+                self.rows[*row]
+                    .iter()
+                    .map(|cell| cell.replace(['\t', '\n'], " "))
+                    .collect::<Vec<_>>()
+                    .join("\t")
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        Some(tsv)
+    }
+
+    fn set_rows(&mut self, rows: Arc<Vec<Vec<String>>>) {
+        self.rows = rows;
+        self.order = Arc::new((0..self.rows.len()).collect());
+        self.body = self.body.clone().with_selection([], None);
+        self.sort_rows();
+        self.refresh();
+    }
+
+    /// Reordena las filas y conserva la selección sobre los mismos datos.
+    fn sort_rows(&mut self) {
+        let previous = self.order.clone();
+        let mut order: Vec<usize> = (0..self.rows.len()).collect();
+        if let Some((column, direction)) = self.sort {
+            let cell = |row: usize| self.rows[row].get(column).map_or("", String::as_str);
+            order.sort_by(|a, b| {
+                let ordering = compare_cells(cell(*a), cell(*b));
+                match direction {
+                    SortOrder::Ascending => ordering,
+                    SortOrder::Descending => ordering.reverse(),
+                }
+            });
+        }
+
+        let mut position = vec![0; order.len()];
+        for (index, row) in order.iter().enumerate() {
+            position[*row] = index;
+        }
+        let remap = |old: usize| previous.get(old).map(|row| position[*row]);
+        let selection: Vec<usize> = self
+            .body
+            .selection()
+            .iter()
+            .filter_map(|old| remap(*old))
+            .collect();
+        let cursor = self.body.selected().and_then(remap);
+        self.order = Arc::new(order);
+        self.body = self.body.clone().with_selection(selection, cursor);
+    }
+
+    /// Reconstruye el cuerpo tras cambiar datos, orden o columnas.
+    fn refresh(&mut self) {
+        let rows = self.rows.clone();
+        let order = self.order.clone();
+        let widths: Vec<u32> = self.columns.iter().map(|c| c.width).collect();
+        let (text_color, font_size) = (self.style.body.text_color, self.style.body.font_size);
+        let prefix = self.id.clone();
+        self.body = self
+            .body
+            .clone()
+            .with_row_count(self.order.len())
+            .with_row_builder(move |row: &ListRow| {
+                let data = order.get(row.index).and_then(|index| rows.get(*index));
+                let mut x = row.area.x;
+                let cells = widths
+                    .iter()
+                    .enumerate()
+                    .map(|(column, width)| {
+                        let area = Rect {
+                            x,
+                            y: row.area.y,
+                            width: *width as f32,
+                            height: row.area.height,
+                        };
+                        x += *width as f32;
+                        let text = data.and_then(|cells| cells.get(column));
+                        let id = format!("{prefix}/{}/{column}", row.index);
+                        let cell = Label::new(&id, area, text.map_or("", String::as_str))
+                            .with_text_color(text_color)
+                            .with_font_size(font_size)
+                            .with_padding(CELL_PADDING);
+                        Box::new(cell) as Box<dyn Acetate>
+                    })
+                    .collect();
+                Box::new(TableRow {
+                    id: format!("{prefix}/{}", row.index),
+                    area: row.area,
+                    cells,
+                })
+            });
+    }
+
+    fn header_rects(&self) -> Vec<Rect> {
+        let mut x = self.area.x;
+        self.columns
+            .iter()
+            .map(|column| {
+                let rect = Rect {
+                    x,
+                    y: self.area.y,
+                    width: column.width as f32,
+                    height: self.header_height as f32,
+                };
+                x += column.width as f32;
+                rect
+            })
+            .collect()
+    }
+
+    fn in_header(&self, x: i32, y: i32) -> bool {
+        self.area.contains(x, y) && (y as f32) < self.area.y + self.header_height as f32
+    }
+
+    /// Columna cuyo borde derecho está bajo el puntero (prioridad sobre el clic de orden).
+    fn border_at(&self, x: i32, y: i32) -> Option<usize> {
+        if !self.in_header(x, y) {
+            return None;
+        }
+        self.header_rects()
+            .iter()
+            .position(|rect| (rect.right() - x as f32).abs() <= RESIZE_GRIP)
+    }
+
+    fn column_at(&self, x: i32, y: i32) -> Option<usize> {
+        if !self.in_header(x, y) {
+            return None;
+        }
+        self.header_rects()
+            .iter()
+            .position(|rect| rect.contains(x, y))
+    }
+
+    fn toggle_sort(&mut self, column: usize) {
+        let direction = match self.sort {
+            Some((current, SortOrder::Ascending)) if current == column => SortOrder::Descending,
+            _ => SortOrder::Ascending,
+        };
+        self.sort = Some((column, direction));
+        self.sort_rows();
+        self.refresh();
+    }
+
+    fn sort_arrow(&self, rect: &Rect, direction: SortOrder) -> Shape {
+        let cx = rect.right() - 10.0;
+        let cy = rect.y + rect.height / 2.0;
+        let dy = match direction {
+            SortOrder::Ascending => 2.0,
+            SortOrder::Descending => -2.0,
+        };
+        Shape::Polyline {
+            points: vec![(cx - 4.0, cy + dy), (cx, cy - dy), (cx + 4.0, cy + dy)],
+            stroke: ShapeStroke {
+                color: self.style.header_text,
+                width: 1.5,
+            },
+        }
+    }
+}
+
+/// Área del cuerpo: la tabla sin la cabecera.
+fn body_area(area: &Rect, header_height: u32) -> Rect {
+    let header_height = (header_height as f32).min(area.height);
+    Rect {
+        y: area.y + header_height,
+        height: area.height - header_height,
+        ..*area
+    }
+}
+
+/// Números como números; el resto, como texto.
+fn compare_cells(a: &str, b: &str) -> Ordering {
+    match (a.trim().parse::<f64>(), b.trim().parse::<f64>()) {
+        (Ok(a), Ok(b)) => a.total_cmp(&b),
+        _ => a.cmp(b),
+    }
+}
+
+impl Acetate for DataTable {
+    fn id(&self) -> String {
+        self.id.clone()
+    }
+
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn z_index(&self) -> i32 {
+        self.z_index
+    }
+
+    fn area(&self) -> Rect {
+        self.area
+    }
+
+    fn relocate(&self, area: Rect) -> Box<dyn Acetate> {
+        let header_height = self.header_height.min(area.height as u32);
+        let body = self.body.clone().with_area(body_area(&area, header_height));
+        Box::new(Self {
+            area,
+            header_height,
+            body,
+            ..self.clone()
+        })
+    }
+
+    fn restyle(
+        &self,
+        _border_thickness: Option<f32>,
+        font_size: Option<f32>,
+    ) -> Box<dyn Acetate> {
+        let mut style = self.style.clone();
+        style.body.font_size = font_size.unwrap_or(style.body.font_size);
+        Box::new(self.clone().with_style(style))
+    }
+
+    fn subscriptions(&self) -> Vec<EventKind> {
+        let mut subscriptions = self.body.subscriptions();
+        subscriptions.push(EventKind::Data);
+        subscriptions
+    }
+
+    fn react(&self, event: &Event, _scene: &SceneInfo) -> Option<Box<dyn Acetate>> {
+        if let Event::Data { target, data } = event {
+            let Data::Rows(rows) = data else {
+                return None;
+            };
+            if *target != self.id {
+                return None;
+            }
+            let mut next = self.clone();
+            next.set_rows(rows.clone());
+            return Some(Box::new(next));
+        }
+        if self.is_disabled() {
+            return None;
+        }
+
+        let mut next = self.clone();
+        match event {
+            Event::KeyPressed(key)
+                if self.body.is_focused()
+                    && self.body.modifiers().control
+                    && is_char_key(key, 'c') =>
+            {
+                let serial = self.clipboard.as_ref().map_or(0, ClipboardRequest::serial) + 1;
+                let text = self.selection_tsv()?;
+                next.clipboard = Some(ClipboardRequest::Copy { serial, text });
+                return Some(Box::new(next));
+            }
+            Event::MouseDown {
+                button: MouseButton::Left,
+                x,
+                y,
+            } if self.in_header(*x, *y) => {
+                next.resize = self.border_at(*x, *y).map(|column| Resize {
+                    column,
+                    origin_x: *x,
+                    origin_width: self.columns[column].width,
+                });
+                if next.resize.is_none() {
+                    next.pressed_column = self.column_at(*x, *y);
+                }
+            }
+            Event::MouseMoved(x, _) if let Some(resize) = self.resize => {
+                let width = resize.origin_width as i32 + (x - resize.origin_x);
+                next.columns[resize.column].width = width.max(MIN_COLUMN_WIDTH as i32) as u32;
+                next.refresh();
+            }
+            Event::MouseUp {
+                button: MouseButton::Left,
+                x,
+                y,
+            } => {
+                next.resize = None;
+                next.pressed_column = None;
+                if let Some(column) = self.pressed_column
+                    && self.column_at(*x, *y) == Some(column)
+                    && self.columns[column].sortable
+                {
+                    next.toggle_sort(column);
+                }
+            }
+            _ => {}
+        }
+
+        // La cabecera no tapa al cuerpo: también ve el evento (p. ej. para perder el foco).
+        if let Some(body) = next.body.reacted(event) {
+            next.body = body;
+        } else if next.columns == self.columns
+            && next.sort == self.sort
+            && next.resize == self.resize
+            && next.pressed_column == self.pressed_column
+        {
+            return None;
+        }
+        Some(Box::new(next))
+    }
+
+    fn perceive(&self, scene: &SceneInfo) -> SceneInfo {
+        scene.clone()
+    }
+
+    fn output(&self) -> AcetateIO {
+        let selected: Vec<String> = self.selected_rows().iter().map(usize::to_string).collect();
+        AcetateIO {
+            content: (!selected.is_empty()).then(|| selected.join(",")),
+            focus: self.body.is_focused(),
+            status: self.status.clone(),
+            clipboard: self.clipboard.clone(),
+            action: self.body.output().action,
+        }
+    }
+
+    fn design(&self) -> AcetateDesign {
+        AcetateDesign {
+            area: self.area,
+            background: self.style.header_background,
+            border: self.style.divider,
+            border_thickness: 1.0,
+            text_color: self.style.header_text,
+            font_size: self.style.body.font_size,
+            ..AcetateDesign::default()
+        }
+    }
+
+    fn children(&self) -> Vec<Box<dyn Acetate>> {
+        let mut children: Vec<Box<dyn Acetate>> = self
+            .header_rects()
+            .into_iter()
+            .zip(&self.columns)
+            .enumerate()
+            .map(|(index, (rect, column))| {
+                let shapes = match self.sort {
+                    Some((sorted, direction)) if sorted == index => {
+                        vec![self.sort_arrow(&rect, direction)]
+                    }
+                    _ => Vec::new(),
+                };
+                let id = format!("{}/header/{index}", self.id);
+                Box::new(
+                    Label::new(&id, rect, &column.title)
+                        .with_text_color(self.style.header_text)
+                        .with_font_size(self.style.body.font_size)
+                        .with_padding(CELL_PADDING)
+                        .with_background(self.style.header_background)
+                        .with_border(self.style.divider)
+                        .with_shapes(shapes),
+                ) as Box<dyn Acetate>
+            })
+            .collect();
+        children.push(Box::new(self.body.clone()));
+        children
+    }
+
+    fn clone_box(&self) -> Box<dyn Acetate> {
+        Box::new(self.clone())
+    }
+}
+
+/// Fila de datos: contenedor transparente de sus celdas.
+#[derive(Debug, Clone)]
+struct TableRow {
+    id: String,
+    area: Rect,
+    cells: Vec<Box<dyn Acetate>>,
+}
+
+impl Acetate for TableRow {
+    fn id(&self) -> String {
+        self.id.clone()
+    }
+
+    fn name(&self) -> String {
+        self.id.clone()
+    }
+
+    fn z_index(&self) -> i32 {
+        0
+    }
+
+    fn area(&self) -> Rect {
+        self.area
+    }
+
+    /// Las celdas se mueven con la fila.
+    fn relocate(&self, area: Rect) -> Box<dyn Acetate> {
+        let (dx, dy) = (area.x - self.area.x, area.y - self.area.y);
+        Box::new(Self {
+            id: self.id.clone(),
+            area,
+            cells: self
+                .cells
+                .iter()
+                .map(|cell| cell.relocate(cell.area().offset(dx, dy)))
+                .collect(),
+        })
+    }
+
+    fn subscriptions(&self) -> Vec<EventKind> {
+        Vec::new()
+    }
+
+    fn react(&self, _event: &Event, _scene: &SceneInfo) -> Option<Box<dyn Acetate>> {
+        None
+    }
+
+    fn perceive(&self, scene: &SceneInfo) -> SceneInfo {
+        scene.clone()
+    }
+
+    fn output(&self) -> AcetateIO {
+        AcetateIO {
+            content: None,
+            focus: false,
+            status: AcetateStatus::Ready,
+            clipboard: None,
+            action: None,
+        }
+    }
+
+    fn design(&self) -> AcetateDesign {
+        AcetateDesign {
+            area: self.area,
+            ..AcetateDesign::default()
+        }
+    }
+
+    fn children(&self) -> Vec<Box<dyn Acetate>> {
+        self.cells.clone()
+    }
+
+    fn clone_box(&self) -> Box<dyn Acetate> {
+        Box::new(self.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::acetates::test_support::click;
+    use crate::contracts::event::keys;
+    use crate::contracts::scene::Scene;
+
+    /// Texto de la primera celda de cada fila visible.
+    fn first_column(scene: &Scene) -> Vec<String> {
+        let body = scene.acetates[0].children().pop().unwrap();
+        body.children()
+            .iter()
+            .map(|row| row.children()[0].output().content.unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn sorts_selects_and_resizes_with_data_from_the_app() {
+        let table = DataTable::new(
+            "users",
+            Rect::new(0.0, 0.0, 300.0, 124.0),
+        )
+        .with_columns([Column::new("Name", 100), Column::new("Age", 60)])
+        .with_row_height(20)
+        .with_selection_mode(SelectionMode::Multi);
+        let scene = Scene::from(vec![Box::new(table) as Box<dyn Acetate>]);
+
+        let rows = [["Ana", "34"], ["Luis", "9"], ["Eva", "120"]]
+            .iter()
+            .map(|row| row.iter().map(|cell| cell.to_string()).collect())
+            .collect();
+        let scene = scene.react(&[Event::Data {
+            target: "users".to_string(),
+            data: Data::Rows(Arc::new(rows)),
+        }]);
+        assert_eq!(first_column(&scene), ["Ana", "Luis", "Eva"]);
+
+        // Edad como número: 9 < 34 < 120.
+        let scene = scene.react(&click(130, 10));
+        assert_eq!(first_column(&scene), ["Luis", "Ana", "Eva"]);
+
+        // Ctrl+clic suma filas; el contenido son índices de los datos originales.
+        let mut events = click(10, 30).to_vec();
+        events.push(Event::KeyPressed(keys::CONTROL.to_string()));
+        events.extend(click(10, 70));
+        let scene = scene.react(&events);
+        assert_eq!(scene.acetates[0].output().content.as_deref(), Some("1,2"));
+
+        // Reordenar conserva la selección sobre los mismos datos.
+        let scene = scene.react(&click(130, 10));
+        assert_eq!(first_column(&scene), ["Eva", "Ana", "Luis"]);
+        assert_eq!(scene.acetates[0].output().content.as_deref(), Some("2,1"));
+
+        let scene = scene.react(&[
+            Event::MouseDown {
+                button: MouseButton::Left,
+                x: 101,
+                y: 10,
+            },
+            Event::MouseMoved(141, 10),
+            Event::MouseUp {
+                button: MouseButton::Left,
+                x: 141,
+                y: 10,
+            },
+        ]);
+        assert_eq!(scene.acetates[0].children()[0].area().width, 140.0);
+        assert_eq!(scene.acetates[0].output().content.as_deref(), Some("2,1"));
+    }
+
+    #[test]
+    fn ctrl_c_copies_selected_rows_as_tsv() {
+        let table = DataTable::new("users", Rect::new(0.0, 0.0, 300.0, 124.0))
+            .with_columns([Column::new("Name", 100), Column::new("Age", 60)])
+            .with_row_height(20)
+            .with_selection_mode(SelectionMode::Multi)
+            .with_rows(vec![
+                vec!["Ana".to_string(), "34".to_string()],
+                vec!["Luis\tG.".to_string(), "9".to_string()],
+            ]);
+        let scene = Scene::from(vec![Box::new(table) as Box<dyn Acetate>]);
+        let copy = Event::KeyPressed("c".to_string());
+
+        let mut events = click(10, 30).to_vec();
+        events.push(Event::KeyPressed(keys::CONTROL.to_string()));
+        events.extend(click(10, 50));
+        events.push(copy.clone());
+        let scene = scene.react(&events);
+        let Some(ClipboardRequest::Copy { serial, text }) = scene.acetates[0].output().clipboard
+        else {
+            panic!("Ctrl+C debe pedir una copia");
+        };
+        assert_eq!(text, "Ana\t34\nLuis G.\t9");
+
+        // Cada copia lleva un serial nuevo, aunque el texto se repita.
+        let scene = scene.react(&[copy]);
+        let again = scene.acetates[0].output().clipboard.unwrap();
+        assert_eq!(again.serial(), serial + 1);
+    }
+}
@@ -0,0 +1,360 @@
+//! Desplegable de selección única con su lista en un overlay.
+
+use super::popup::{MenuStyle, PopupMenu};
+use crate::contracts::event::{Action, Event, EventKind, MouseButton, keys};
+use crate::contracts::scene::{
+    Acetate, AcetateDesign, AcetateIO, AcetateStatus, EmittedAction, Overlay, Rect, SceneInfo,
+    Shape, ShapeStroke,
+};
+
+/// Desplegable (select). Clic o Enter/Espacio/↓ con foco lo abren; la lista se
+/// coloca debajo del campo (o encima si no cabe en la ventana). Se cierra al
+/// elegir, con Escape o con un clic fuera. `output().content` es la opción elegida.
+#[derive(Debug, Clone)]
+pub struct Dropdown {
+    id: String,
+    name: String,
+    z_index: i32,
+    area: Rect,
+    options: Vec<String>,
+    selected: Option<usize>,
+    placeholder: Option<String>,
+    style: MenuStyle,
+    status: AcetateStatus,
+    focused: bool,
+    menu: Option<PopupMenu>,
+    action: Option<EmittedAction>,
+}
+
+impl Dropdown {
+    pub fn new(id: &str, area: Rect) -> Self {
+        Self {
+            id: id.to_string(),
+            name: id.to_string(),
+            z_index: 0,
+            area,
+            options: Vec::new(),
+            selected: None,
+            placeholder: None,
+            style: MenuStyle::default(),
+            status: AcetateStatus::Ready,
+            focused: false,
+            menu: None,
+            action: None,
+        }
+    }
+
+    pub fn with_name(mut self, name: &str) -> Self {
+        self.name = name.to_string();
+        self
+    }
+
+    pub fn with_z_index(mut self, z_index: i32) -> Self {
+        self.z_index = z_index;
+        self
+    }
+
+    pub fn with_options<S: Into<String>>(mut self, options: impl IntoIterator<Item = S>) -> Self {
+        self.options = options.into_iter().map(Into::into).collect();
+        self.selected = self.selected.filter(|i| *i < self.options.len());
+        self
+    }
+
+    pub fn with_selected(mut self, selected: Option<usize>) -> Self {
+        self.selected = selected.filter(|i| *i < self.options.len());
+        self
+    }
+
+    pub fn with_placeholder(mut self, placeholder: &str) -> Self {
+        self.placeholder = Some(placeholder.to_string());
+        self
+    }
+
+    pub fn with_style(mut self, style: MenuStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    pub fn with_status(mut self, status: AcetateStatus) -> Self {
+        self.status = status;
+        self
+    }
+
+    pub fn selected(&self) -> Option<usize> {
+        self.selected
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.menu.is_some()
+    }
+
+    fn is_disabled(&self) -> bool {
+        !matches!(self.status, AcetateStatus::Ready)
+    }
+
+    fn open(&mut self, scene: &SceneInfo) {
+        if self.options.is_empty() {
+            return;
+        }
+        let below = (self.area.x, self.area.bottom());
+        let menu = PopupMenu::open(
+            below,
+            self.area.y,
+            self.area.width,
+            self.options.len(),
+            self.style.row_height,
+            (scene.width, scene.height),
+        );
+        self.menu = Some(menu.with_highlighted(self.selected.or(Some(0))));
+    }
+
+    fn choose(&mut self, row: Option<usize>) {
+        self.menu = None;
+        if let Some(row) = row
+            && self.selected != Some(row)
+        {
+            self.selected = Some(row);
+            self.action = Some(EmittedAction::next(self.action.as_ref(), Action::Changed));
+        }
+    }
+
+    fn react_open(&mut self, menu: &PopupMenu, event: &Event) {
+        match event {
+            Event::MouseMoved(x, y) => {
+                if let Some(row) = menu.row_at(*x, *y) {
+                    self.menu = Some(menu.clone().with_highlighted(Some(row)));
+                }
+            }
+            Event::MouseDown {
+                button: MouseButton::Left,
+                x,
+                y,
+            } if menu.row_at(*x, *y).is_none() => {
+                self.menu = None;
+                self.focused = self.area.contains(*x, *y);
+            }
+            Event::MouseUp {
+                button: MouseButton::Left,
+                x,
+                y,
+            } => {
+                if let Some(row) = menu.row_at(*x, *y) {
+                    self.choose(Some(row));
+                }
+            }
+            Event::KeyPressed(key) => match key.as_str() {
+                keys::ESCAPE | keys::TAB => self.menu = None,
+                keys::ENTER | keys::SPACE => self.choose(menu.highlighted),
+                _ => {
+                    if let Some(row) = menu.navigate(key) {
+                        self.menu = Some(menu.clone().with_highlighted(Some(row)));
+                    }
+                }
+            },
+            _ => {}
+        }
+    }
+
+    fn chevron(&self) -> Shape {
+        let cx = self.area.right() - 14.0;
+        let cy = self.area.center().1;
+        // Apunta hacia abajo cerrado y hacia arriba abierto.
+        let dy = if self.is_open() { -2.0 } else { 2.0 };
+        Shape::Polyline {
+            points: vec![(cx - 4.0, cy - dy), (cx, cy + dy), (cx + 4.0, cy - dy)],
+            stroke: ShapeStroke {
+                color: self.style.text_color,
+                width: 1.5,
+            },
+        }
+    }
+}
+
+impl Acetate for Dropdown {
+    fn id(&self) -> String {
+        self.id.clone()
+    }
+
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn z_index(&self) -> i32 {
+        self.z_index
+    }
+
+    fn area(&self) -> Rect {
+        self.area
+    }
+
+    fn relocate(&self, area: Rect) -> Box<dyn Acetate> {
+        Box::new(Self {
+            area,
+            menu: None,
+            ..self.clone()
+        })
+    }
+
+    fn restyle(
+        &self,
+        _border_thickness: Option<f32>,
+        font_size: Option<f32>,
+    ) -> Box<dyn Acetate> {
+        let mut style = self.style.clone();
+        style.font_size = font_size.unwrap_or(style.font_size);
+        Box::new(self.clone().with_style(style))
+    }
+
+    fn subscriptions(&self) -> Vec<EventKind> {
+        vec![
+            EventKind::MouseMoved,
+            EventKind::MouseDown,
+            EventKind::MouseUp,
+            EventKind::KeyPressed,
+        ]
+    }
+
+    fn react(&self, event: &Event, scene: &SceneInfo) -> Option<Box<dyn Acetate>> {
+        if self.is_disabled() {
+            return None;
+        }
+
+        let mut next = self.clone();
+        match (&self.menu, event) {
+            (Some(menu), _) => next.react_open(menu, event),
+            (
+                None,
+                Event::MouseDown {
+                    button: MouseButton::Left,
+                    x,
+                    y,
+                },
+            ) => {
+                next.focused = self.area.contains(*x, *y);
+                if next.focused {
+                    next.open(scene);
+                }
+            }
+            (None, Event::KeyPressed(key))
+                if self.focused
+                    && matches!(key.as_str(), keys::ENTER | keys::SPACE | keys::ARROW_DOWN) =>
+            {
+                next.open(scene);
+            }
+            _ => return None,
+        }
+
+        let unchanged = next.focused == self.focused
+            && next.menu == self.menu
+            && next.selected == self.selected;
+        if unchanged {
+            return None;
+        }
+        Some(Box::new(next))
+    }
+
+    fn perceive(&self, scene: &SceneInfo) -> SceneInfo {
+        scene.clone()
+    }
+
+    fn output(&self) -> AcetateIO {
+        AcetateIO {
+            content: self.selected.map(|i| self.options[i].clone()),
+            focus: self.focused,
+            status: self.status.clone(),
+            clipboard: None,
+            action: self.action.clone(),
+        }
+    }
+
+    fn design(&self) -> AcetateDesign {
+        let text = match self.selected {
+            Some(i) => Some(self.options[i].clone()),
+            None => self.placeholder.clone(),
+        };
+        let design = AcetateDesign {
+            area: self.area,
+            background: self.style.background,
+            border: if self.focused {
+                self.style.focus_border
+            } else {
+                self.style.border
+            },
+            border_thickness: 1.0,
+            text,
+            text_color: self.style.text_color,
+            font_size: self.style.font_size,
+            // El texto deja sitio al chevrón de la derecha.
+            text_box: Some(Rect {
+                x: self.area.x + 8.0,
+                width: (self.area.width - 36.0).max(0.0),
+                ..self.area
+            }),
+            shapes: vec![self.chevron()],
+            commands: Vec::new(),
+            tooltip: None,
+            ..AcetateDesign::default()
+        };
+        if self.is_disabled() {
+            return AcetateDesign {
+                background: design.background.with_alpha(design.background.a * 0.45),
+                text_color: design.text_color.with_alpha(design.text_color.a * 0.45),
+                ..design
+            };
+        }
+        design
+    }
+
+    fn overlays(&self) -> Vec<Overlay> {
+        self.menu
+            .iter()
+            .map(|menu| menu.overlay(self.z_index, &self.options, &self.style))
+            .collect()
+    }
+
+    fn clone_box(&self) -> Box<dyn Acetate> {
+        Box::new(self.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::acetates::Button;
+    use crate::acetates::test_support::{click, rect};
+    use crate::contracts::scene::Scene;
+
+    #[test]
+    fn popup_flips_above_and_captures_clicks_over_other_acetates() {
+        let dropdown = Dropdown::new("zoom", rect(0, 150, 100, 24))
+            .with_options(["50%", "100%", "200%"])
+            .with_selected(Some(1));
+        let button = Button::new("under", rect(0, 78, 100, 24));
+        let scene = Scene {
+            width: 200,
+            height: 200,
+            ..Scene::from(vec![
+                Box::new(dropdown) as Box<dyn Acetate>,
+                Box::new(button) as Box<dyn Acetate>,
+            ])
+        };
+
+        // No cabe debajo (150 + 24 + 72 > 200): se abre encima, de y = 78 a 150.
+        let scene = scene.react(&click(10, 160));
+        let overlays = scene.acetates[0].overlays();
+        assert_eq!(overlays[0].design.area, rect(0, 78, 100, 72));
+
+        // La primera fila tapa al botón: el clic solo llega al desplegable.
+        let scene = scene.react(&click(10, 85));
+        assert_eq!(scene.acetates[0].output().content.as_deref(), Some("50%"));
+        assert!(scene.acetates[0].overlays().is_empty());
+        assert!(scene.acetates[1].output().action.is_none());
+
+        let scene = scene.react(&[
+            Event::KeyPressed(keys::ENTER.to_string()),
+            Event::KeyPressed(keys::ESCAPE.to_string()),
+        ]);
+        assert!(scene.acetates[0].overlays().is_empty());
+        assert_eq!(scene.acetates[0].output().content.as_deref(), Some("50%"));
+    }
+}
@@ -0,0 +1,148 @@
+//! Texto estático en un rectángulo: celdas, etiquetas de pestañas y ejes.
+
+use crate::contracts::event::{Event, EventKind};
+use crate::contracts::scene::{
+    Acetate, AcetateDesign, AcetateIO, AcetateStatus, Rect, SceneInfo, Shape, TextAlign,
+};
+use crate::core::Color;
+
+/// Etiqueta sin interacción. Se usa sobre todo como hijo (`Acetate::children`) de
+/// acetates compuestos; `output().content` es su texto.
+#[derive(Debug, Clone)]
+pub struct Label {
+    id: String,
+    area: Rect,
+    text: String,
+    text_color: Color,
+    font_size: f32,
+    align: TextAlign,
+    padding: f32,
+    background: Color,
+    border: Color,
+    shapes: Vec<Shape>,
+}
+
+impl Label {
+    pub fn new(id: &str, area: Rect, text: &str) -> Self {
+        Self {
+            id: id.to_string(),
+            area,
+            text: text.to_string(),
+            text_color: Color::rgba(0.92, 0.93, 0.95, 1.0),
+            font_size: 14.0,
+            align: TextAlign::Start,
+            padding: 0.0,
+            background: Color::TRANSPARENT,
+            border: Color::TRANSPARENT,
+            shapes: Vec::new(),
+        }
+    }
+
+    pub fn with_text_color(mut self, text_color: Color) -> Self {
+        self.text_color = text_color;
+        self
+    }
+
+    pub fn with_font_size(mut self, font_size: f32) -> Self {
+        self.font_size = font_size;
+        self
+    }
+
+    pub fn with_align(mut self, align: TextAlign) -> Self {
+        self.align = align;
+        self
+    }
+
+    /// Margen horizontal entre el borde y el texto.
+    pub fn with_padding(mut self, padding: f32) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    pub fn with_background(mut self, background: Color) -> Self {
+        self.background = background;
+        self
+    }
+
+    /// Borde de 1 px; transparente (por defecto) no se pinta.
+    pub fn with_border(mut self, border: Color) -> Self {
+        self.border = border;
+        self
+    }
+
+    pub fn with_shapes(mut self, shapes: Vec<Shape>) -> Self {
+        self.shapes = shapes;
+        self
+    }
+}
+
+impl Acetate for Label {
+    fn id(&self) -> String {
+        self.id.clone()
+    }
+
+    fn name(&self) -> String {
+        self.id.clone()
+    }
+
+    fn z_index(&self) -> i32 {
+        0
+    }
+
+    fn area(&self) -> Rect {
+        self.area
+    }
+
+    fn relocate(&self, area: Rect) -> Box<dyn Acetate> {
+        Box::new(Self {
+            area,
+            ..self.clone()
+        })
+    }
+
+    fn subscriptions(&self) -> Vec<EventKind> {
+        Vec::new()
+    }
+
+    fn react(&self, _event: &Event, _scene: &SceneInfo) -> Option<Box<dyn Acetate>> {
+        None
+    }
+
+    fn perceive(&self, scene: &SceneInfo) -> SceneInfo {
+        scene.clone()
+    }
+
+    fn output(&self) -> AcetateIO {
+        AcetateIO {
+            content: Some(self.text.clone()),
+            focus: false,
+            status: AcetateStatus::Ready,
+            clipboard: None,
+            action: None,
+        }
+    }
+
+    fn design(&self) -> AcetateDesign {
+        AcetateDesign {
+            area: self.area,
+            background: self.background,
+            border: self.border,
+            border_thickness: if self.border.a > 0.0 { 1.0 } else { 0.0 },
+            text: Some(self.text.clone()),
+            text_color: self.text_color,
+            font_size: self.font_size,
+            text_align: self.align,
+            text_box: (self.padding > 0.0).then(|| Rect {
+                x: self.area.x + self.padding,
+                width: (self.area.width - 2.0 * self.padding).max(0.0),
+                ..self.area
+            }),
+            shapes: self.shapes.clone(),
+            ..AcetateDesign::default()
+        }
+    }
+
+    fn clone_box(&self) -> Box<dyn Acetate> {
+        Box::new(self.clone())
+    }
+}
@@ -0,0 +1,42 @@
+//! Acetatos incorporados, listos para usar en una `Scene`.
+
+pub mod button;
+pub mod canvas;
+pub mod chart;
+pub mod context_menu;
+pub mod data_table;
+pub mod dropdown;
+pub mod label;
+pub mod modal;
+pub mod popup;
+pub mod press;
+pub mod progress;
+pub mod radio_group;
+pub mod slider;
+pub mod tabs;
+#[cfg(test)]
+pub(crate) mod test_support;
+pub mod text_edit;
+pub mod text_field;
+pub mod toggle;
+pub mod tooltip;
+pub mod virtual_list;
+
+pub use button::{Button, ButtonStateStyle, ButtonStyle};
+pub use canvas::{Canvas, CanvasContext, Painter};
+pub use chart::{Chart, ChartKind, ChartStyle};
+pub use context_menu::ContextMenu;
+pub use data_table::{Column, DataTable, SortOrder, TableStyle};
+pub use dropdown::Dropdown;
+pub use label::Label;
+pub use modal::{Modal, ModalStyle};
+pub use popup::MenuStyle;
+pub use progress::{ProgressBar, ProgressStyle, Spinner};
+pub use radio_group::RadioGroup;
+pub use slider::{Orientation, Slider, SliderStyle};
+pub use tabs::{Tab, TabStyle, Tabs};
+pub use text_edit::TextEdit;
+pub use text_field::{TextField, TextFieldStyle};
+pub use toggle::{Checkbox, Switch, ToggleStyle};
+pub use tooltip::{Tooltip, TooltipStyle, WithTooltip};
+pub use virtual_list::{ListRow, ListStyle, RowBuilder, SelectionMode, VirtualList};
@@ -0,0 +1,558 @@
+//! Diálogo modal: bloquea la escena por debajo y atrapa el foco en sus botones.
+
+use super::button::{ButtonStateStyle, ButtonStyle};
+use super::label::Label;
+use crate::contracts::event::{Action, Event, EventKind, Modifiers, MouseButton, keys};
+use crate::contracts::scene::{
+    Acetate, AcetateDesign, AcetateIO, AcetateStatus, EmittedAction, LINE_HEIGHT, MODAL_Z_INDEX,
+    Rect, SceneInfo, Shape, ShapeStroke, TextAlign,
+};
+use crate::core::Color;
+
+const PADDING: f32 = 16.0;
+const BUTTON_WIDTH: f32 = 96.0;
+const BUTTON_HEIGHT: f32 = 30.0;
+const BUTTON_GAP: f32 = 8.0;
+const CLOSE_SIZE: f32 = 20.0;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ModalStyle {
+    pub panel: Color,
+    pub border: Color,
+    pub text_color: Color,
+    pub font_size: f32,
+    pub buttons: ButtonStyle,
+}
+
+impl Default for ModalStyle {
+    fn default() -> Self {
+        Self {
+            panel: Color::rgba(0.13, 0.14, 0.18, 1.0),
+            border: Color::rgba(0.30, 0.32, 0.40, 1.0),
+            text_color: Color::rgba(0.92, 0.93, 0.95, 1.0),
+            font_size: 14.0,
+            buttons: ButtonStyle::default(),
+        }
+    }
+}
+
+/// Zona activable dentro del diálogo.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Target {
+    Button(usize),
+    Close,
+}
+
+/// Diálogo modal. Abierto, sube al rango `MODAL_Z_INDEX`, bloquea la entrada de
+/// todo lo que queda debajo y la escena se pinta oscurecida tras él.
+///
+/// Sus botones se recorren con Tab/Shift+Tab o flechas sin salir del diálogo. El
+/// ciclo solo cubre esos botones: los acetatos colocados encima del modal (z mayor)
+/// reciben las mismas teclas y llevan su propio foco.
+/// Pulsar uno emite `Action::Selected(etiqueta)`; Escape o la ✕ emiten
+/// `Action::Dismissed`. En ambos casos el diálogo se cierra. Con `with_trigger`
+/// se vuelve a abrir cuando el acetate indicado emite `Action::Click`.
+#[derive(Debug, Clone)]
+pub struct Modal {
+    id: String,
+    name: String,
+    z_index: i32,
+    area: Rect,
+    title: Option<String>,
+    message: Option<String>,
+    buttons: Vec<String>,
+    trigger: Option<String>,
+    style: ModalStyle,
+    open: bool,
+    hovered: Option<Target>,
+    pressed: Option<Target>,
+    focused: Option<usize>,
+    modifiers: Modifiers,
+    chosen: Option<usize>,
+    action: Option<EmittedAction>,
+}
+
+impl Modal {
+    pub fn new(id: &str, area: Rect) -> Self {
+        Self {
+            id: id.to_string(),
+            name: id.to_string(),
+            z_index: 0,
+            area,
+            title: None,
+            message: None,
+            buttons: Vec::new(),
+            trigger: None,
+            style: ModalStyle::default(),
+            open: true,
+            hovered: None,
+            pressed: None,
+            focused: None,
+            modifiers: Modifiers::default(),
+            chosen: None,
+            action: None,
+        }
+    }
+
+    pub fn with_name(mut self, name: &str) -> Self {
+        self.name = name.to_string();
+        self
+    }
+
+    pub fn with_z_index(mut self, z_index: i32) -> Self {
+        self.z_index = z_index;
+        self
+    }
+
+    pub fn with_title(mut self, title: &str) -> Self {
+        self.title = Some(title.to_string());
+        self
+    }
+
+    pub fn with_message(mut self, message: &str) -> Self {
+        self.message = Some(message.to_string());
+        self
+    }
+
+    /// Botones de izquierda a derecha; el foco empieza en el primero.
+    pub fn with_buttons<S: Into<String>>(mut self, buttons: impl IntoIterator<Item = S>) -> Self {
+        self.buttons = buttons.into_iter().map(Into::into).collect();
+        self.focused = (!self.buttons.is_empty()).then_some(0);
+        self
+    }
+
+    /// Id del acetate cuyo `Action::Click` abre el diálogo.
+    pub fn with_trigger(mut self, source: &str) -> Self {
+        self.trigger = Some(source.to_string());
+        self
+    }
+
+    pub fn with_open(mut self, open: bool) -> Self {
+        self.open = open;
+        self
+    }
+
+    pub fn with_style(mut self, style: ModalStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.open
+    }
+
+    fn button_rect(&self, index: usize) -> Rect {
+        let count = self.buttons.len() as f32;
+        let right = self.area.right() - PADDING;
+        let from_right = count - index as f32;
+        Rect {
+            x: right - from_right * BUTTON_WIDTH - (from_right - 1.0) * BUTTON_GAP,
+            y: self.area.bottom() - PADDING - BUTTON_HEIGHT,
+            width: BUTTON_WIDTH,
+            height: BUTTON_HEIGHT,
+        }
+    }
+
+    fn close_rect(&self) -> Rect {
+        Rect {
+            x: self.area.right() - PADDING / 2.0 - CLOSE_SIZE,
+            y: self.area.y + PADDING / 2.0,
+            width: CLOSE_SIZE,
+            height: CLOSE_SIZE,
+        }
+    }
+
+    fn target_at(&self, x: i32, y: i32) -> Option<Target> {
+        if self.close_rect().contains(x, y) {
+            return Some(Target::Close);
+        }
+        (0..self.buttons.len())
+            .find(|i| self.button_rect(*i).contains(x, y))
+            .map(Target::Button)
+    }
+
+    fn reopened(&self) -> Self {
+        Self {
+            open: true,
+            hovered: None,
+            pressed: None,
+            focused: (!self.buttons.is_empty()).then_some(0),
+            modifiers: Modifiers::default(),
+            ..self.clone()
+        }
+    }
+
+    fn activate(&mut self, target: Target) {
+        let action = match target {
+            Target::Button(index) => {
+                self.chosen = Some(index);
+                Action::Selected(self.buttons[index].clone())
+            }
+            Target::Close => Action::Dismissed,
+        };
+        self.open = false;
+        self.pressed = None;
+        self.action = Some(EmittedAction::next(self.action.as_ref(), action));
+    }
+
+    /// Foco cíclico: nunca sale de los botones del diálogo.
+    fn cycle_focus(&mut self, forward: bool) {
+        let count = self.buttons.len();
+        if count == 0 {
+            return;
+        }
+        let current = self.focused.unwrap_or(0);
+        self.focused = Some(if forward {
+            (current + 1) % count
+        } else {
+            (current + count - 1) % count
+        });
+    }
+
+    fn react_key(&mut self, key: &str) {
+        match key {
+            keys::ESCAPE => self.activate(Target::Close),
+            keys::TAB => self.cycle_focus(!self.modifiers.shift),
+            keys::ARROW_RIGHT => self.cycle_focus(true),
+            keys::ARROW_LEFT => self.cycle_focus(false),
+            keys::ENTER | keys::SPACE => {
+                if let Some(index) = self.focused {
+                    self.activate(Target::Button(index));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn button_style(&self, index: usize) -> &ButtonStateStyle {
+        let target = Some(Target::Button(index));
+        let styles = &self.style.buttons;
+        if self.pressed == target && self.hovered == target {
+            &styles.pressed
+        } else if self.hovered == target {
+            &styles.hover
+        } else if self.focused == Some(index) {
+            &styles.focused
+        } else {
+            &styles.normal
+        }
+    }
+
+    fn button_shape(&self, index: usize) -> Shape {
+        let styles = &self.style.buttons;
+        let style = self.button_style(index);
+        Shape::RoundedRect {
+            area: self.button_rect(index),
+            radius: 4.0,
+            fill: style.fill,
+            stroke: Some(ShapeStroke {
+                color: style.border,
+                width: styles.border_thickness.max(1.0),
+            }),
+        }
+    }
+
+    fn close_shape(&self) -> Shape {
+        let r = self.close_rect();
+        let (x0, y0) = (r.x + 5.0, r.y + 5.0);
+        let (x1, y1) = (x0 + r.width - 10.0, y0 + r.height - 10.0);
+        let color = if self.hovered == Some(Target::Close) {
+            self.style.text_color
+        } else {
+            self.style
+                .text_color
+                .with_alpha(self.style.text_color.a * 0.6)
+        };
+        Shape::Polyline {
+            points: vec![
+                (x0, y0),
+                (x1, y1),
+                ((x0 + x1) / 2.0, (y0 + y1) / 2.0),
+                (x1, y0),
+                (x0, y1),
+            ],
+            stroke: ShapeStroke { color, width: 1.5 },
+        }
+    }
+}
+
+impl Acetate for Modal {
+    fn id(&self) -> String {
+        self.id.clone()
+    }
+
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn z_index(&self) -> i32 {
+        if self.open {
+            MODAL_Z_INDEX.saturating_add(self.z_index)
+        } else {
+            self.z_index
+        }
+    }
+
+    fn area(&self) -> Rect {
+        self.area
+    }
+
+    fn relocate(&self, area: Rect) -> Box<dyn Acetate> {
+        Box::new(Self {
+            area,
+            ..self.clone()
+        })
+    }
+
+    fn restyle(
+        &self,
+        _border_thickness: Option<f32>,
+        font_size: Option<f32>,
+    ) -> Box<dyn Acetate> {
+        let mut style = self.style.clone();
+        style.font_size = font_size.unwrap_or(style.font_size);
+        Box::new(self.clone().with_style(style))
+    }
+
+    fn subscriptions(&self) -> Vec<EventKind> {
+        vec![
+            EventKind::MouseMoved,
+            EventKind::MouseDown,
+            EventKind::MouseUp,
+            EventKind::KeyPressed,
+            EventKind::KeyReleased,
+            EventKind::Action,
+        ]
+    }
+
+    fn react(&self, event: &Event, _scene: &SceneInfo) -> Option<Box<dyn Acetate>> {
+        if !self.open {
+            return match event {
+                Event::Action {
+                    source,
+                    action: Action::Click,
+                } if self.trigger.as_ref() == Some(source) => Some(Box::new(self.reopened())),
+                _ => None,
+            };
+        }
+
+        let mut next = self.clone();
+        next.modifiers = self.modifiers.after(event);
+        match event {
+            Event::MouseMoved(x, y) => next.hovered = self.target_at(*x, *y),
+            Event::MouseDown {
+                button: MouseButton::Left,
+                x,
+                y,
+            } => next.pressed = self.target_at(*x, *y),
+            Event::MouseUp {
+                button: MouseButton::Left,
+                x,
+                y,
+            } => {
+                next.pressed = None;
+                if let Some(target) = self.pressed
+                    && self.target_at(*x, *y) == Some(target)
+                {
+                    next.activate(target);
+                }
+            }
+            Event::KeyPressed(key) => next.react_key(key),
+            _ => {}
+        }
+
+        let unchanged = next.hovered == self.hovered
+            && next.pressed == self.pressed
+            && next.focused == self.focused
+            && next.modifiers == self.modifiers
+            && next.chosen == self.chosen
+            && next.action == self.action
+            && next.open == self.open;
+        if unchanged {
+            return None;
+        }
+        Some(Box::new(next))
+    }
+
+    fn perceive(&self, scene: &SceneInfo) -> SceneInfo {
+        scene.clone()
+    }
+
+    fn output(&self) -> AcetateIO {
+        AcetateIO {
+            content: self.chosen.map(|i| self.buttons[i].clone()),
+            focus: self.open,
+            status: AcetateStatus::Ready,
+            clipboard: None,
+            action: self.action.clone(),
+        }
+    }
+
+    fn design(&self) -> AcetateDesign {
+        if !self.open {
+            return AcetateDesign {
+                area: self.area,
+                ..AcetateDesign::default()
+            };
+        }
+        let text = match (&self.title, &self.message) {
+            (Some(title), Some(message)) => Some(format!("{title}\n\n{message}")),
+            (title, message) => title.clone().or_else(|| message.clone()),
+        };
+        let mut shapes: Vec<Shape> = (0..self.buttons.len())
+            .map(|index| self.button_shape(index))
+            .collect();
+        shapes.push(self.close_shape());
+        AcetateDesign {
+            area: self.area,
+            background: self.style.panel,
+            border: self.style.border,
+            border_thickness: 1.0,
+            text,
+            text_color: self.style.text_color,
+            font_size: self.style.font_size,
+            // Arriba a la izquierda, dejando sitio a la cruz de cerrar.
+            text_box: Some(Rect::new(
+                self.area.x + PADDING,
+                self.area.y + PADDING,
+                (self.area.width - 2.0 * PADDING - CLOSE_SIZE).max(0.0),
+                self.style.font_size * LINE_HEIGHT,
+            )),
+            shapes,
+            commands: Vec::new(),
+            tooltip: None,
+            ..AcetateDesign::default()
+        }
+    }
+
+    /// Rótulos de los botones.
+    fn children(&self) -> Vec<Box<dyn Acetate>> {
+        if !self.open {
+            return Vec::new();
+        }
+        self.buttons
+            .iter()
+            .enumerate()
+            .map(|(index, label)| {
+                let id = format!("{}/button/{index}", self.id);
+                Box::new(
+                    Label::new(&id, self.button_rect(index), label)
+                        .with_text_color(self.button_style(index).text_color)
+                        .with_font_size(self.style.buttons.font_size)
+                        .with_align(TextAlign::Center),
+                ) as Box<dyn Acetate>
+            })
+            .collect()
+    }
+
+    fn is_modal(&self) -> bool {
+        self.open
+    }
+
+    fn clone_box(&self) -> Box<dyn Acetate> {
+        Box::new(self.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::acetates::Button;
+    use crate::acetates::test_support::{click, rect};
+    use crate::contracts::scene::Scene;
+
+    #[test]
+    fn blocks_input_below_and_reopens_on_trigger() {
+        let modal = Modal::new("confirm", rect(50, 50, 300, 150))
+            .with_message("¿Eliminar?")
+            .with_buttons(["Cancelar", "Eliminar"])
+            .with_trigger("delete")
+            .with_open(false);
+        let delete = Button::new("delete", rect(0, 0, 400, 300));
+        let scene = Scene::from(vec![
+            Box::new(delete) as Box<dyn Acetate>,
+            Box::new(modal) as Box<dyn Acetate>,
+        ]);
+
+        let scene = scene.react(&[Event::Action {
+            source: "delete".to_string(),
+            action: Action::Click,
+        }]);
+        assert!(scene.acetates[1].is_modal());
+        assert!(scene.modal_z_index().is_some());
+
+        // El clic cae sobre el botón de debajo pero solo lo ve el modal.
+        let scene = scene.react(&click(10, 10));
+        assert!(scene.acetates[0].output().action.is_none());
+
+        let scene = scene.react(&[
+            Event::KeyPressed(keys::TAB.to_string()),
+            Event::KeyPressed(keys::ENTER.to_string()),
+        ]);
+        let output = scene.acetates[1].output();
+        assert_eq!(
+            output.action.map(|emitted| emitted.action),
+            Some(Action::Selected("Eliminar".to_string()))
+        );
+        assert!(scene.modal_z_index().is_none());
+
+        let scene = scene.react(&click(10, 10));
+        assert_eq!(
+            scene.acetates[0]
+                .output()
+                .action
+                .map(|emitted| emitted.action),
+            Some(Action::Click)
+        );
+    }
+
+    #[test]
+    fn key_releases_reach_the_modal_below_the_top_one() {
+        let first = Modal::new("first", rect(50, 50, 300, 150))
+            .with_buttons(["Uno", "Dos", "Tres"]);
+        let second = Modal::new("second", rect(80, 80, 200, 100))
+            .with_z_index(1)
+            .with_buttons(["Vale"])
+            .with_trigger("more")
+            .with_open(false);
+        let scene = Scene::from(vec![
+            Box::new(first) as Box<dyn Acetate>,
+            Box::new(second) as Box<dyn Acetate>,
+        ]);
+
+        // Shift se pulsa con el primero arriba y se suelta con el segundo encima.
+        let scene = scene.react(&[
+            Event::KeyPressed(keys::SHIFT.to_string()),
+            Event::Action {
+                source: "more".to_string(),
+                action: Action::Click,
+            },
+            Event::KeyReleased(keys::SHIFT.to_string()),
+            Event::KeyPressed(keys::ESCAPE.to_string()),
+        ]);
+        assert!(!scene.acetates[1].is_modal());
+
+        // Sin el Shift pegado, Tab avanza: de "Uno" a "Dos".
+        let scene = scene.react(&[
+            Event::KeyPressed(keys::TAB.to_string()),
+            Event::KeyPressed(keys::ENTER.to_string()),
+        ]);
+        assert_eq!(
+            scene.acetates[0].output().action.map(|emitted| emitted.action),
+            Some(Action::Selected("Dos".to_string()))
+        );
+    }
+
+    #[test]
+    fn events_that_change_nothing_do_not_rebuild_the_modal() {
+        let modal = Modal::new("confirm", rect(50, 50, 300, 150)).with_buttons(["Vale"]);
+        let info = Scene::from(vec![modal.clone_box()]).info();
+        let button = modal.button_rect(0);
+        let over = Event::MouseMoved(button.x as i32 + 2, button.y as i32 + 2);
+
+        assert!(modal.react(&Event::MouseMoved(60, 60), &info).is_none());
+        assert!(modal.react(&Event::KeyPressed("a".to_string()), &info).is_none());
+        let hovered = modal.react(&over, &info).expect("hover");
+        assert!(hovered.react(&over, &info).is_none());
+    }
+}
@@ -0,0 +1,138 @@
+//! Menú emergente compartido por el desplegable y el menú contextual.
+
+use crate::contracts::event::keys;
+use crate::contracts::scene::{AcetateDesign, Overlay, Rect, Shape};
+use crate::core::Color;
+
+/// Margen horizontal del texto de cada fila.
+const ROW_PADDING: f32 = 8.0;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct MenuStyle {
+    pub background: Color,
+    pub border: Color,
+    pub focus_border: Color,
+    /// Fondo de la fila resaltada (puntero o teclado).
+    pub highlight: Color,
+    pub text_color: Color,
+    pub font_size: f32,
+    pub row_height: u32,
+}
+
+impl Default for MenuStyle {
+    fn default() -> Self {
+        Self {
+            background: Color::rgba(0.13, 0.14, 0.18, 1.0),
+            border: Color::rgba(0.30, 0.32, 0.40, 1.0),
+            focus_border: Color::rgba(0.35, 0.55, 0.95, 1.0),
+            highlight: Color::rgba(0.22, 0.36, 0.78, 1.0),
+            text_color: Color::rgba(0.92, 0.93, 0.95, 1.0),
+            font_size: 14.0,
+            row_height: 24,
+        }
+    }
+}
+
+/// Lista de entradas abierta en un rectángulo ya colocado dentro de la ventana.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct PopupMenu {
+    pub rect: Rect,
+    pub highlighted: Option<usize>,
+    row_height: u32,
+    len: usize,
+}
+
+impl PopupMenu {
+    /// Abre el menú con su esquina superior izquierda en `(x, y)`. Si no cabe
+    /// hacia abajo se abre hacia arriba terminando en `flip_y`; en horizontal se
+    /// desplaza hasta caber en `bounds` (ancho, alto de la ventana).
+    pub fn open(
+        (x, y): (f32, f32),
+        flip_y: f32,
+        width: f32,
+        len: usize,
+        row_height: u32,
+        bounds: (u32, u32),
+    ) -> Self {
+        let height = row_height as f32 * len as f32;
+        let (max_w, max_h) = (bounds.0 as f32, bounds.1 as f32);
+        let x = x.min(max_w - width).max(0.0);
+        let y = if y + height <= max_h {
+            y
+        } else if flip_y - height >= 0.0 {
+            flip_y - height
+        } else {
+            (max_h - height).max(0.0)
+        };
+        Self {
+            rect: Rect::new(x, y, width, height),
+            highlighted: None,
+            row_height,
+            len,
+        }
+    }
+
+    pub fn with_highlighted(mut self, highlighted: Option<usize>) -> Self {
+        self.highlighted = highlighted.filter(|i| *i < self.len);
+        self
+    }
+
+    pub fn row_at(&self, x: i32, y: i32) -> Option<usize> {
+        if !self.rect.contains(x, y) || self.row_height == 0 {
+            return None;
+        }
+        let row = ((y as f32 - self.rect.y) / self.row_height as f32) as usize;
+        (row < self.len).then_some(row)
+    }
+
+    /// Nueva fila resaltada para una tecla de navegación (con vuelta al final/inicio).
+    pub fn navigate(&self, key: &str) -> Option<usize> {
+        let last = self.len.checked_sub(1)?;
+        Some(match (key, self.highlighted) {
+            (keys::ARROW_DOWN, Some(i)) if i < last => i + 1,
+            (keys::ARROW_DOWN, _) => 0,
+            (keys::ARROW_UP, Some(i)) if i > 0 => i - 1,
+            (keys::ARROW_UP, _) => last,
+            (keys::HOME | keys::PAGE_UP, _) => 0,
+            (keys::END | keys::PAGE_DOWN, _) => last,
+            _ => return None,
+        })
+    }
+
+    pub fn overlay(&self, owner_z_index: i32, items: &[String], style: &MenuStyle) -> Overlay {
+        let shapes = self
+            .highlighted
+            .map(|row| Shape::Rect {
+                area: Rect {
+                    y: self.rect.y + row as f32 * self.row_height as f32,
+                    height: self.row_height as f32,
+                    ..self.rect
+                },
+                fill: style.highlight,
+            })
+            .into_iter()
+            .collect();
+        Overlay::above(
+            owner_z_index,
+            AcetateDesign {
+                area: self.rect,
+                background: style.background,
+                border: style.border,
+                border_thickness: 1.0,
+                text: Some(items.join("\n")),
+                text_color: style.text_color,
+                font_size: style.font_size,
+                text_box: Some(Rect {
+                    x: self.rect.x + ROW_PADDING,
+                    width: (self.rect.width - 2.0 * ROW_PADDING).max(0.0),
+                    ..self.rect
+                }),
+                line_height: Some(self.row_height as f32),
+                shapes,
+                commands: Vec::new(),
+                tooltip: None,
+                ..AcetateDesign::default()
+            },
+        )
+    }
+}
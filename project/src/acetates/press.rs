@@ -0,0 +1,64 @@
+//! Seguimiento de hover/presión/foco compartido por los controles que se activan con clic.
+
+use crate::contracts::event::keys;
+use crate::contracts::event::{Event, MouseButton};
+use crate::contracts::scene::Rect;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PressState {
+    pub hovered: bool,
+    pub pressed: bool,
+    pub focused: bool,
+}
+
+impl PressState {
+    /// Estado tras el evento y si este completó una activación:
+    /// soltar dentro tras presionar dentro, o Enter/Espacio con foco.
+    pub fn after(self, event: &Event, area: &Rect) -> (Self, bool) {
+        match event {
+            Event::MouseMoved(x, y) => (
+                Self {
+                    hovered: area.contains(*x, *y),
+                    ..self
+                },
+                false,
+            ),
+            Event::MouseDown {
+                button: MouseButton::Left,
+                x,
+                y,
+            } => {
+                let inside = area.contains(*x, *y);
+                (
+                    Self {
+                        hovered: inside,
+                        pressed: inside,
+                        focused: inside,
+                    },
+                    false,
+                )
+            }
+            Event::MouseUp {
+                button: MouseButton::Left,
+                x,
+                y,
+            } => {
+                let inside = area.contains(*x, *y);
+                (
+                    Self {
+                        hovered: inside,
+                        pressed: false,
+                        ..self
+                    },
+                    self.pressed && inside,
+                )
+            }
+            Event::KeyPressed(key)
+                if self.focused && (key == keys::ENTER || key == keys::SPACE) =>
+            {
+                (self, true)
+            }
+            _ => (self, false),
+        }
+    }
+}
@@ -0,0 +1,484 @@
+//! Indicadores de actividad: barra de progreso (determinada o indeterminada) y spinner.
+
+use std::f32::consts::TAU;
+
+use crate::contracts::event::{AnimationFrame, Data, Event, EventKind};
+use crate::contracts::scene::{
+    Acetate, AcetateDesign, AcetateIO, AcetateStatus, Rect, SceneInfo, Shape, ShapeStroke,
+    TextAlign,
+};
+use crate::core::Color;
+
+/// Segundos que tarda el relleno en recorrer la barra entera al cambiar de valor.
+const FILL_TRAVEL_SECS: f32 = 0.4;
+/// Segundos de una pasada del segmento indeterminado.
+const SWEEP_SECS: f32 = 1.4;
+/// Fracción de la barra que ocupa el segmento indeterminado.
+const SEGMENT: f32 = 0.3;
+/// Segundos por vuelta del spinner.
+const TURN_SECS: f32 = 1.0;
+/// Apertura del arco del spinner (tres cuartos de vuelta).
+const SPINNER_SWEEP: f32 = TAU * 0.75;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProgressStyle {
+    pub track: Color,
+    pub fill: Color,
+    pub text_color: Color,
+    pub font_size: f32,
+    /// Grosor del anillo del spinner.
+    pub thickness: f32,
+}
+
+impl Default for ProgressStyle {
+    fn default() -> Self {
+        Self {
+            track: Color::rgba(0.16, 0.17, 0.22, 1.0),
+            fill: Color::rgba(0.22, 0.36, 0.78, 1.0),
+            text_color: Color::rgba(0.92, 0.93, 0.95, 1.0),
+            font_size: 12.0,
+            thickness: 4.0,
+        }
+    }
+}
+
+/// Barra de progreso. Con valor (0..1) el relleno se acerca a él suavemente; sin
+/// valor es indeterminada y un segmento la recorre en bucle. Ambas cosas avanzan
+/// con el `dt` de cada `Event::Tick`. La app actualiza el valor con
+/// `Data::Progress` dirigido a su id; `output().content` es el porcentaje.
+#[derive(Debug, Clone)]
+pub struct ProgressBar {
+    id: String,
+    name: String,
+    z_index: i32,
+    area: Rect,
+    value: Option<f32>,
+    label: Option<String>,
+    style: ProgressStyle,
+    status: AcetateStatus,
+    /// Fracción que se pinta ahora mismo (persigue a `value`).
+    shown: f32,
+    /// Posición (0..1) del segmento indeterminado dentro de su pasada.
+    phase: f32,
+}
+
+impl ProgressBar {
+    /// Barra indeterminada hasta que reciba un valor.
+    pub fn new(id: &str, area: Rect) -> Self {
+        Self {
+            id: id.to_string(),
+            name: id.to_string(),
+            z_index: 0,
+            area,
+            value: None,
+            label: None,
+            style: ProgressStyle::default(),
+            status: AcetateStatus::Ready,
+            shown: 0.0,
+            phase: 0.0,
+        }
+    }
+
+    pub fn with_name(mut self, name: &str) -> Self {
+        self.name = name.to_string();
+        self
+    }
+
+    pub fn with_z_index(mut self, z_index: i32) -> Self {
+        self.z_index = z_index;
+        self
+    }
+
+    /// Valor inicial; se pinta ya completo, sin animar desde cero.
+    pub fn with_value(mut self, value: f32) -> Self {
+        let value = value.clamp(0.0, 1.0);
+        self.value = Some(value);
+        self.shown = value;
+        self
+    }
+
+    /// Texto centrado sobre la barra.
+    pub fn with_label(mut self, label: &str) -> Self {
+        self.label = Some(label.to_string());
+        self
+    }
+
+    pub fn with_style(mut self, style: ProgressStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    pub fn with_status(mut self, status: AcetateStatus) -> Self {
+        self.status = status;
+        self
+    }
+
+    /// `None` si es indeterminada.
+    pub fn value(&self) -> Option<f32> {
+        self.value
+    }
+
+    fn animate_frame(&self, frame: &AnimationFrame) -> Option<Self> {
+        match self.value {
+            None => Some(Self {
+                phase: (self.phase + frame.dt / SWEEP_SECS).fract(),
+                ..self.clone()
+            }),
+            Some(target) if self.shown != target => {
+                let step = frame.dt / FILL_TRAVEL_SECS;
+                let shown = if self.shown < target {
+                    (self.shown + step).min(target)
+                } else {
+                    (self.shown - step).max(target)
+                };
+                Some(Self {
+                    shown,
+                    ..self.clone()
+                })
+            }
+            Some(_) => None,
+        }
+    }
+
+    /// Tramo relleno como fracciones (inicio, fin) del ancho de la barra.
+    fn filled_span(&self) -> (f32, f32) {
+        match self.value {
+            Some(_) => (0.0, self.shown),
+            None => {
+                // El segmento entra por la izquierda y sale por la derecha.
+                let start = self.phase * (1.0 + SEGMENT) - SEGMENT;
+                (start.max(0.0), (start + SEGMENT).min(1.0))
+            }
+        }
+    }
+}
+
+impl Acetate for ProgressBar {
+    fn id(&self) -> String {
+        self.id.clone()
+    }
+
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn z_index(&self) -> i32 {
+        self.z_index
+    }
+
+    fn area(&self) -> Rect {
+        self.area
+    }
+
+    fn relocate(&self, area: Rect) -> Box<dyn Acetate> {
+        Box::new(Self {
+            area,
+            ..self.clone()
+        })
+    }
+
+    fn subscriptions(&self) -> Vec<EventKind> {
+        vec![EventKind::Data]
+    }
+
+    fn react(&self, event: &Event, _scene: &SceneInfo) -> Option<Box<dyn Acetate>> {
+        match event {
+            Event::Data {
+                target,
+                data: Data::Progress(value),
+            } if *target == self.id => {
+                let value = value.map(|v| v.clamp(0.0, 1.0));
+                if value == self.value {
+                    return None;
+                }
+                Some(Box::new(Self {
+                    value,
+                    phase: 0.0,
+                    ..self.clone()
+                }))
+            }
+            _ => None,
+        }
+    }
+
+    fn animate(&self, frame: &AnimationFrame) -> Option<Box<dyn Acetate>> {
+        self.animate_frame(frame)
+            .map(|bar| Box::new(bar) as Box<dyn Acetate>)
+    }
+
+    fn perceive(&self, scene: &SceneInfo) -> SceneInfo {
+        scene.clone()
+    }
+
+    fn output(&self) -> AcetateIO {
+        AcetateIO {
+            content: self.value.map(|v| format!("{:.0}%", v * 100.0)),
+            focus: false,
+            status: self.status.clone(),
+            clipboard: None,
+            action: None,
+        }
+    }
+
+    fn design(&self) -> AcetateDesign {
+        let area = &self.area;
+        let radius = area.height / 2.0;
+        let mut shapes = vec![Shape::RoundedRect {
+            area: *area,
+            radius,
+            fill: self.style.track,
+            stroke: None,
+        }];
+        let (start, end) = self.filled_span();
+        let x0 = area.x + start * area.width;
+        let x1 = area.x + end * area.width;
+        if x1 > x0 {
+            shapes.push(Shape::RoundedRect {
+                area: Rect::new(x0, area.y, x1 - x0, area.height),
+                radius,
+                fill: self.style.fill,
+                stroke: None,
+            });
+        }
+        AcetateDesign {
+            area: *area,
+            background: Color::TRANSPARENT,
+            border: Color::TRANSPARENT,
+            border_thickness: 0.0,
+            text: self.label.clone(),
+            text_color: self.style.text_color,
+            font_size: self.style.font_size,
+            text_align: TextAlign::Center,
+            shapes,
+            ..AcetateDesign::default()
+        }
+    }
+
+    fn clone_box(&self) -> Box<dyn Acetate> {
+        Box::new(self.clone())
+    }
+}
+
+/// Spinner de actividad: un arco de tres cuartos de vuelta que gira sobre una pista
+/// circular, centrado en su área. Gira con el `dt` de cada `Event::Tick`.
+#[derive(Debug, Clone)]
+pub struct Spinner {
+    id: String,
+    name: String,
+    z_index: i32,
+    area: Rect,
+    style: ProgressStyle,
+    status: AcetateStatus,
+    /// Ángulo de inicio del arco, en radianes.
+    angle: f32,
+}
+
+impl Spinner {
+    pub fn new(id: &str, area: Rect) -> Self {
+        Self {
+            id: id.to_string(),
+            name: id.to_string(),
+            z_index: 0,
+            area,
+            style: ProgressStyle::default(),
+            status: AcetateStatus::Ready,
+            angle: 0.0,
+        }
+    }
+
+    pub fn with_name(mut self, name: &str) -> Self {
+        self.name = name.to_string();
+        self
+    }
+
+    pub fn with_z_index(mut self, z_index: i32) -> Self {
+        self.z_index = z_index;
+        self
+    }
+
+    pub fn with_style(mut self, style: ProgressStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    pub fn with_status(mut self, status: AcetateStatus) -> Self {
+        self.status = status;
+        self
+    }
+}
+
+impl Acetate for Spinner {
+    fn id(&self) -> String {
+        self.id.clone()
+    }
+
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn z_index(&self) -> i32 {
+        self.z_index
+    }
+
+    fn area(&self) -> Rect {
+        self.area
+    }
+
+    fn relocate(&self, area: Rect) -> Box<dyn Acetate> {
+        Box::new(Self {
+            area,
+            ..self.clone()
+        })
+    }
+
+    /// Solo para que la app lo trate como vivo y conserve el giro entre frames;
+    /// el giro en sí llega por `animate`.
+    fn subscriptions(&self) -> Vec<EventKind> {
+        vec![EventKind::Tick]
+    }
+
+    fn react(&self, _event: &Event, _scene: &SceneInfo) -> Option<Box<dyn Acetate>> {
+        None
+    }
+
+    fn animate(&self, frame: &AnimationFrame) -> Option<Box<dyn Acetate>> {
+        Some(Box::new(Self {
+            angle: (self.angle + frame.dt / TURN_SECS * TAU) % TAU,
+            ..self.clone()
+        }))
+    }
+
+    fn perceive(&self, scene: &SceneInfo) -> SceneInfo {
+        scene.clone()
+    }
+
+    fn output(&self) -> AcetateIO {
+        AcetateIO {
+            content: None,
+            focus: false,
+            status: self.status.clone(),
+            clipboard: None,
+            action: None,
+        }
+    }
+
+    fn design(&self) -> AcetateDesign {
+        let area = &self.area;
+        let center = (
+            area.x + area.width / 2.0,
+            area.y + area.height / 2.0,
+        );
+        let thickness = self.style.thickness;
+        let radius = (area.width.min(area.height) - thickness) / 2.0;
+        let shapes = vec![
+            Shape::Circle {
+                center,
+                radius,
+                fill: Color::TRANSPARENT,
+                stroke: Some(ShapeStroke {
+                    color: self.style.track,
+                    width: thickness,
+                }),
+            },
+            Shape::Arc {
+                center,
+                radius,
+                start_angle: self.angle,
+                sweep_angle: SPINNER_SWEEP,
+                stroke: ShapeStroke {
+                    color: self.style.fill,
+                    width: thickness,
+                },
+            },
+        ];
+        AcetateDesign {
+            area: *area,
+            background: Color::TRANSPARENT,
+            border: Color::TRANSPARENT,
+            border_thickness: 0.0,
+            shapes,
+            ..AcetateDesign::default()
+        }
+    }
+
+    fn clone_box(&self) -> Box<dyn Acetate> {
+        Box::new(self.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::contracts::scene::Scene;
+
+    fn frame(dt: f32) -> AnimationFrame {
+        AnimationFrame { tick: 1, dt }
+    }
+
+    #[test]
+    fn progress_fill_follows_value_over_ticks() {
+        let area = Rect::new(0.0, 0.0, 200.0, 8.0);
+        let bar = ProgressBar::new("load", area).with_value(0.0);
+        let info = Scene::from(Vec::new()).info();
+        let event = Event::Data {
+            target: "load".to_string(),
+            data: Data::Progress(Some(0.5)),
+        };
+        let bar = bar.react(&event, &info).unwrap();
+        assert_eq!(bar.output().content.as_deref(), Some("50%"));
+
+        // 0.1 s recorre un cuarto de barra; 0.2 s más la dejan en su valor y quieta.
+        let bar = bar.animate(&frame(0.1)).unwrap();
+        let filled = |b: &dyn Acetate| match &b.design().shapes[1] {
+            Shape::RoundedRect { area, .. } => area.width,
+            other => panic!("relleno inesperado: {other:?}"),
+        };
+        assert_eq!(filled(bar.as_ref()), 50.0);
+        let bar = bar.animate(&frame(0.2)).unwrap();
+        assert_eq!(filled(bar.as_ref()), 100.0);
+        assert!(bar.animate(&frame(0.1)).is_none());
+
+        // Sin valor pasa a indeterminada y sigue animándose.
+        let event = Event::Data {
+            target: "load".to_string(),
+            data: Data::Progress(None),
+        };
+        let bar = bar.react(&event, &info).unwrap();
+        assert!(bar.output().content.is_none());
+        assert!(bar.animate(&frame(0.5)).is_some());
+    }
+
+    #[test]
+    fn values_clamp_to_the_bar_and_none_is_indeterminate() {
+        let area = Rect::new(10.0, 0.0, 200.0, 8.0);
+        let info = Scene::from(Vec::new()).info();
+        let progress = |value| Event::Data {
+            target: "load".to_string(),
+            data: Data::Progress(value),
+        };
+
+        assert_eq!(ProgressBar::new("load", area).with_value(1.7).value(), Some(1.0));
+        let bar = ProgressBar::new("load", area).with_value(-0.3);
+        assert_eq!(bar.value(), Some(0.0));
+        assert_eq!(bar.output().content.as_deref(), Some("0%"));
+        assert!(bar.react(&progress(Some(-2.0)), &info).is_none());
+        let full = bar.react(&progress(Some(4.0)), &info).unwrap();
+        assert_eq!(full.output().content.as_deref(), Some("100%"));
+
+        // Indeterminada: sin contenido, y el segmento recorre la barra sin salirse.
+        let bar = ProgressBar::new("load", area);
+        assert_eq!(bar.value(), None);
+        assert!(bar.output().content.is_none());
+        assert!(bar.react(&progress(None), &info).is_none());
+        let mut bar: Box<dyn Acetate> = Box::new(bar);
+        let mut starts = Vec::new();
+        for _ in 0..20 {
+            bar = bar.animate(&frame(0.1)).expect("indeterminada");
+            if let Some(Shape::RoundedRect { area: fill, .. }) = bar.design().shapes.get(1) {
+                assert!(fill.x >= area.x && fill.x + fill.width <= area.x + area.width);
+                starts.push(fill.x);
+            }
+        }
+        assert!(starts.windows(2).any(|pair| pair[0] != pair[1]));
+    }
+}
@@ -0,0 +1,329 @@
+//! Grupo de opciones excluyentes (radio), una fila por opción.
+
+use super::press::PressState;
+use super::toggle::{LABEL_GAP, ToggleStyle};
+use crate::contracts::event::{Action, Event, EventKind, MouseButton, keys};
+use crate::contracts::scene::{
+    Acetate, AcetateDesign, AcetateIO, AcetateStatus, EmittedAction, Rect, SceneInfo, Shape,
+    ShapeStroke,
+};
+
+/// Grupo de radio. Clic sobre una fila la selecciona; con foco, las flechas
+/// mueven la selección y Espacio elige la fila bajo el puntero. Cada cambio emite
+/// `Action::Changed` y `output().content` es la etiqueta seleccionada.
+#[derive(Debug, Clone)]
+pub struct RadioGroup {
+    id: String,
+    name: String,
+    z_index: i32,
+    area: Rect,
+    options: Vec<String>,
+    selected: Option<usize>,
+    row_height: u32,
+    style: ToggleStyle,
+    status: AcetateStatus,
+    press: PressState,
+    /// Fila bajo el puntero, para el resaltado de hover.
+    hovered_row: Option<usize>,
+    /// Fila donde empezó la pulsación: solo se selecciona si se suelta en la misma.
+    pressed_row: Option<usize>,
+    action: Option<EmittedAction>,
+}
+
+impl RadioGroup {
+    pub fn new(id: &str, area: Rect) -> Self {
+        Self {
+            id: id.to_string(),
+            name: id.to_string(),
+            z_index: 0,
+            area,
+            options: Vec::new(),
+            selected: None,
+            row_height: 24,
+            style: ToggleStyle::default(),
+            status: AcetateStatus::Ready,
+            press: PressState::default(),
+            hovered_row: None,
+            pressed_row: None,
+            action: None,
+        }
+    }
+
+    pub fn with_name(mut self, name: &str) -> Self {
+        self.name = name.to_string();
+        self
+    }
+
+    pub fn with_z_index(mut self, z_index: i32) -> Self {
+        self.z_index = z_index;
+        self
+    }
+
+    pub fn with_options<S: Into<String>>(mut self, options: impl IntoIterator<Item = S>) -> Self {
+        self.options = options.into_iter().map(Into::into).collect();
+        self.selected = self.selected.filter(|i| *i < self.options.len());
+        self
+    }
+
+    pub fn with_selected(mut self, selected: Option<usize>) -> Self {
+        self.selected = selected.filter(|i| *i < self.options.len());
+        self
+    }
+
+    pub fn with_row_height(mut self, row_height: u32) -> Self {
+        self.row_height = row_height.max(1);
+        self
+    }
+
+    pub fn with_style(mut self, style: ToggleStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    pub fn with_status(mut self, status: AcetateStatus) -> Self {
+        self.status = status;
+        self
+    }
+
+    pub fn selected(&self) -> Option<usize> {
+        self.selected
+    }
+
+    pub fn selected_label(&self) -> Option<&str> {
+        self.selected.map(|i| self.options[i].as_str())
+    }
+
+    fn is_disabled(&self) -> bool {
+        !matches!(self.status, AcetateStatus::Ready)
+    }
+
+    fn row_at(&self, x: i32, y: i32) -> Option<usize> {
+        if !self.area.contains(x, y) {
+            return None;
+        }
+        let row = ((y as f32 - self.area.y) / self.row_height as f32) as usize;
+        (row < self.options.len()).then_some(row)
+    }
+
+    fn select(&mut self, row: usize) {
+        if self.selected != Some(row) {
+            self.selected = Some(row);
+            self.action = Some(EmittedAction::next(self.action.as_ref(), Action::Changed));
+        }
+    }
+
+    /// Teclas con foco: las flechas mueven la selección sin dar la vuelta y Espacio
+    /// selecciona la fila bajo el puntero (o la primera si aún no hay ninguna).
+    fn step(&self, key: &str) -> Option<usize> {
+        let last = self.options.len().checked_sub(1)?;
+        let current = self.selected;
+        match key {
+            keys::SPACE => Some(self.hovered_row.or(current).unwrap_or(0)),
+            keys::ARROW_DOWN | keys::ARROW_RIGHT => Some(current.map_or(0, |i| (i + 1).min(last))),
+            keys::ARROW_UP | keys::ARROW_LEFT => Some(current.map_or(0, |i| i.saturating_sub(1))),
+            _ => None,
+        }
+    }
+}
+
+impl Acetate for RadioGroup {
+    fn id(&self) -> String {
+        self.id.clone()
+    }
+
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn z_index(&self) -> i32 {
+        self.z_index
+    }
+
+    fn area(&self) -> Rect {
+        self.area
+    }
+
+    fn relocate(&self, area: Rect) -> Box<dyn Acetate> {
+        Box::new(Self {
+            area,
+            ..self.clone()
+        })
+    }
+
+    fn subscriptions(&self) -> Vec<EventKind> {
+        vec![
+            EventKind::MouseMoved,
+            EventKind::MouseDown,
+            EventKind::MouseUp,
+            EventKind::KeyPressed,
+        ]
+    }
+
+    fn react(&self, event: &Event, _scene: &SceneInfo) -> Option<Box<dyn Acetate>> {
+        if self.is_disabled() {
+            return None;
+        }
+
+        let (press, _) = self.press.after(event, &self.area);
+        let mut next = self.clone();
+        next.press = press;
+        match event {
+            Event::MouseMoved(x, y) => next.hovered_row = self.row_at(*x, *y),
+            Event::MouseDown {
+                button: MouseButton::Left,
+                x,
+                y,
+            } => next.pressed_row = self.row_at(*x, *y),
+            Event::MouseUp {
+                button: MouseButton::Left,
+                x,
+                y,
+            } => {
+                if let Some(row) = self.pressed_row
+                    && self.row_at(*x, *y) == Some(row)
+                {
+                    next.select(row);
+                }
+                next.pressed_row = None;
+            }
+            Event::KeyPressed(key) if self.press.focused => {
+                if let Some(row) = self.step(key) {
+                    next.select(row);
+                }
+            }
+            _ => {}
+        }
+
+        let unchanged = next.press == self.press
+            && next.hovered_row == self.hovered_row
+            && next.pressed_row == self.pressed_row
+            && next.selected == self.selected;
+        if unchanged {
+            return None;
+        }
+        Some(Box::new(next))
+    }
+
+    fn perceive(&self, scene: &SceneInfo) -> SceneInfo {
+        scene.clone()
+    }
+
+    fn output(&self) -> AcetateIO {
+        AcetateIO {
+            content: self.selected_label().map(str::to_string),
+            focus: self.press.focused,
+            status: self.status.clone(),
+            clipboard: None,
+            action: self.action.clone(),
+        }
+    }
+
+    fn design(&self) -> AcetateDesign {
+        let style = if self.is_disabled() {
+            self.style.dimmed()
+        } else {
+            self.style.clone()
+        };
+        let diameter = (self.row_height as f32 * 0.7).min(18.0);
+        let radius = diameter / 2.0;
+
+        let mut shapes = Vec::new();
+        for row in 0..self.options.len() {
+            let center = (
+                self.area.x + radius + 1.0,
+                self.area.y + (row as f32 + 0.5) * self.row_height as f32,
+            );
+            let row_press = PressState {
+                hovered: self.hovered_row == Some(row),
+                ..self.press
+            };
+            let selected = self.selected == Some(row);
+            shapes.push(Shape::Circle {
+                center,
+                radius,
+                fill: style.off_fill,
+                stroke: Some(ShapeStroke {
+                    color: if selected {
+                        style.on_fill
+                    } else {
+                        style.border_for(&row_press)
+                    },
+                    width: 1.5,
+                }),
+            });
+            if selected {
+                shapes.push(Shape::Circle {
+                    center,
+                    radius: radius * 0.5,
+                    fill: style.on_fill,
+                    stroke: None,
+                });
+            }
+        }
+
+        AcetateDesign {
+            area: self.area,
+            text: (!self.options.is_empty()).then(|| self.options.join("\n")),
+            text_color: style.text_color,
+            font_size: style.font_size,
+            // Cada opción en su fila, a la derecha del círculo.
+            text_box: Some(Rect {
+                x: self.area.x + diameter + LABEL_GAP,
+                width: (self.area.width - diameter - LABEL_GAP).max(0.0),
+                ..self.area
+            }),
+            line_height: Some(self.row_height as f32),
+            shapes,
+            ..AcetateDesign::default()
+        }
+    }
+
+    fn clone_box(&self) -> Box<dyn Acetate> {
+        Box::new(self.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::acetates::test_support::click;
+    use crate::contracts::scene::Scene;
+
+    #[test]
+    fn click_and_arrows_change_selection() {
+        let radio = RadioGroup::new(
+            "size",
+            Rect::new(0.0, 0.0, 120.0, 72.0),
+        )
+        .with_options(["S", "M", "L"]);
+        let scene = Scene::from(vec![Box::new(radio) as Box<dyn Acetate>]);
+
+        let scene = scene.react(&click(10, 30));
+        assert_eq!(scene.acetates[0].output().content.as_deref(), Some("M"));
+        let first = scene.acetates[0].output().action.unwrap();
+        assert_eq!(first.action, Action::Changed);
+
+        let scene = scene.react(&[Event::KeyPressed(keys::ARROW_DOWN.to_string())]);
+        let output = scene.acetates[0].output();
+        assert_eq!(output.content.as_deref(), Some("L"));
+        assert_ne!(output.action.unwrap().serial, first.serial);
+    }
+
+    #[test]
+    fn space_selects_the_hovered_row() {
+        let radio = RadioGroup::new("size", Rect::new(0.0, 0.0, 120.0, 96.0))
+            .with_options(["S", "M", "L"]);
+        let scene = Scene::from(vec![Box::new(radio) as Box<dyn Acetate>]);
+
+        // Clic bajo la última fila: gana el foco sin elegir nada.
+        let scene = scene.react(&click(100, 90));
+        assert_eq!(scene.acetates[0].output().content, None);
+        let scene = scene.react(&[
+            Event::MouseMoved(10, 54),
+            Event::KeyPressed(keys::SPACE.to_string()),
+        ]);
+        let output = scene.acetates[0].output();
+        assert_eq!(output.content.as_deref(), Some("L"));
+        assert_eq!(output.action.unwrap().action, Action::Changed);
+    }
+}
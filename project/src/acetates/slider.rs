@@ -0,0 +1,558 @@
+//! Deslizador de valor (uno o dos pulgares), horizontal o vertical.
+
+use crate::contracts::event::{Action, Event, EventKind, MouseButton, keys};
+use crate::contracts::scene::{
+    Acetate, AcetateDesign, AcetateIO, AcetateStatus, EmittedAction, Rect, SceneInfo, Shape,
+    ShapeStroke,
+};
+use crate::core::Color;
+
+/// Dirección del recorrido. En vertical el mínimo queda abajo.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Orientation {
+    #[default]
+    Horizontal,
+    Vertical,
+}
+
+impl Orientation {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim() {
+            "horizontal" => Some(Orientation::Horizontal),
+            "vertical" => Some(Orientation::Vertical),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SliderStyle {
+    pub track: Color,
+    /// Tramo activo: desde el mínimo hasta el pulgar, o entre ambos pulgares.
+    pub fill: Color,
+    pub thumb: Color,
+    pub thumb_border: Color,
+    pub focus_border: Color,
+    pub tick: Color,
+    pub track_thickness: f32,
+    pub thumb_radius: f32,
+}
+
+impl Default for SliderStyle {
+    fn default() -> Self {
+        Self {
+            track: Color::rgba(0.16, 0.17, 0.22, 1.0),
+            fill: Color::rgba(0.22, 0.36, 0.78, 1.0),
+            thumb: Color::rgba(1.0, 1.0, 1.0, 1.0),
+            thumb_border: Color::rgba(0.40, 0.42, 0.50, 1.0),
+            focus_border: Color::rgba(0.35, 0.55, 0.95, 1.0),
+            tick: Color::rgba(0.55, 0.57, 0.65, 1.0),
+            track_thickness: 4.0,
+            thumb_radius: 8.0,
+        }
+    }
+}
+
+/// Deslizador con `min`/`max`/`step`. Con `with_range` pasa a tener dos pulgares
+/// que no se cruzan. Mientras se arrastra, el pulgar sigue al puntero aunque salga
+/// del área (captura) hasta soltar el botón.
+///
+/// `output().content` es el valor (`"0.5"`) o el rango (`"0.2..0.8"`); cada cambio
+/// emite `Action::Changed`.
+#[derive(Debug, Clone)]
+pub struct Slider {
+    id: String,
+    name: String,
+    z_index: i32,
+    area: Rect,
+    orientation: Orientation,
+    min: f32,
+    max: f32,
+    step: f32,
+    /// Uno o dos valores, siempre ordenados.
+    values: Vec<f32>,
+    /// Cada cuántas unidades se dibuja una marca (sin marcas si es `None`).
+    tick_interval: Option<f32>,
+    style: SliderStyle,
+    status: AcetateStatus,
+    focused: bool,
+    /// Pulgar que responde al teclado: el último tomado con el puntero.
+    active: usize,
+    dragging: bool,
+    action: Option<EmittedAction>,
+}
+
+impl Slider {
+    pub fn new(id: &str, area: Rect) -> Self {
+        Self {
+            id: id.to_string(),
+            name: id.to_string(),
+            z_index: 0,
+            area,
+            orientation: Orientation::Horizontal,
+            min: 0.0,
+            max: 1.0,
+            step: 0.01,
+            values: vec![0.0],
+            tick_interval: None,
+            style: SliderStyle::default(),
+            status: AcetateStatus::Ready,
+            focused: false,
+            active: 0,
+            dragging: false,
+            action: None,
+        }
+    }
+
+    pub fn with_name(mut self, name: &str) -> Self {
+        self.name = name.to_string();
+        self
+    }
+
+    pub fn with_z_index(mut self, z_index: i32) -> Self {
+        self.z_index = z_index;
+        self
+    }
+
+    pub fn with_orientation(mut self, orientation: Orientation) -> Self {
+        self.orientation = orientation;
+        self
+    }
+
+    /// Límites y paso. Un `step` no positivo deja el valor continuo.
+    pub fn with_bounds(mut self, min: f32, max: f32, step: f32) -> Self {
+        self.min = min.min(max);
+        self.max = max.max(min);
+        self.step = step.max(0.0);
+        self.values = self.values.iter().map(|v| self.snap(*v)).collect();
+        self
+    }
+
+    pub fn with_value(mut self, value: f32) -> Self {
+        self.values = vec![self.snap(value)];
+        self.active = 0;
+        self
+    }
+
+    /// Variante de rango con dos pulgares.
+    pub fn with_range(mut self, low: f32, high: f32) -> Self {
+        let (low, high) = (self.snap(low.min(high)), self.snap(high.max(low)));
+        self.values = vec![low, high];
+        self
+    }
+
+    pub fn with_ticks(mut self, interval: Option<f32>) -> Self {
+        self.tick_interval = interval.filter(|i| *i > 0.0);
+        self
+    }
+
+    pub fn with_style(mut self, style: SliderStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    pub fn with_status(mut self, status: AcetateStatus) -> Self {
+        self.status = status;
+        self
+    }
+
+    pub fn values(&self) -> &[f32] {
+        &self.values
+    }
+
+    pub fn is_range(&self) -> bool {
+        self.values.len() == 2
+    }
+
+    fn is_disabled(&self) -> bool {
+        !matches!(self.status, AcetateStatus::Ready)
+    }
+
+    fn snap(&self, value: f32) -> f32 {
+        let value = value.clamp(self.min, self.max);
+        if self.step <= 0.0 {
+            return value;
+        }
+        let steps = ((value - self.min) / self.step).round();
+        (self.min + steps * self.step).min(self.max)
+    }
+
+    /// Decimales necesarios para mostrar un múltiplo de `step` sin ruido de coma flotante.
+    fn decimals(&self) -> usize {
+        let mut step = self.step;
+        for decimals in 0..6 {
+            if step <= 0.0 || (step - step.round()).abs() < 1e-4 {
+                return decimals;
+            }
+            step *= 10.0;
+        }
+        6
+    }
+
+    fn format(&self, value: f32) -> String {
+        format!("{:.*}", self.decimals(), value)
+    }
+
+    /// Inicio y longitud del recorrido útil (el pulgar no se sale del área).
+    fn travel(&self) -> (f32, f32) {
+        let r = self.style.thumb_radius;
+        match self.orientation {
+            Orientation::Horizontal => (self.area.x + r, self.area.width - 2.0 * r),
+            Orientation::Vertical => (self.area.y + r, self.area.height - 2.0 * r),
+        }
+    }
+
+    fn fraction(&self, value: f32) -> f32 {
+        let span = self.max - self.min;
+        if span <= 0.0 {
+            0.0
+        } else {
+            (value - self.min) / span
+        }
+    }
+
+    /// Coordenada (x, y) del centro del pulgar para un valor.
+    fn point_of(&self, value: f32) -> (f32, f32) {
+        let (start, length) = self.travel();
+        let t = self.fraction(value);
+        match self.orientation {
+            Orientation::Horizontal => (
+                start + t * length,
+                self.area.y + self.area.height / 2.0,
+            ),
+            Orientation::Vertical => (
+                self.area.x + self.area.width / 2.0,
+                start + (1.0 - t) * length,
+            ),
+        }
+    }
+
+    fn value_at(&self, x: i32, y: i32) -> f32 {
+        let (start, length) = self.travel();
+        let along = match self.orientation {
+            Orientation::Horizontal => x as f32,
+            Orientation::Vertical => y as f32,
+        };
+        let mut t = if length <= 0.0 {
+            0.0
+        } else {
+            ((along - start) / length).clamp(0.0, 1.0)
+        };
+        if self.orientation == Orientation::Vertical {
+            t = 1.0 - t;
+        }
+        self.snap(self.min + t * (self.max - self.min))
+    }
+
+    fn nearest_thumb(&self, value: f32) -> usize {
+        if !self.is_range() {
+            return 0;
+        }
+        let [low, high] = [self.values[0], self.values[1]];
+        // Con ambos pulgares juntos se toma el que permite moverse hacia el puntero.
+        if (value - low).abs() < (value - high).abs() || (low == high && value < low) {
+            0
+        } else {
+            1
+        }
+    }
+
+    /// Mueve el pulgar activo sin cruzar al otro.
+    fn set_active(&mut self, value: f32) {
+        let mut value = self.snap(value);
+        if self.is_range() {
+            value = if self.active == 0 {
+                value.min(self.values[1])
+            } else {
+                value.max(self.values[0])
+            };
+        }
+        self.values[self.active] = value;
+    }
+
+    fn keyboard_delta(&self, key: &str) -> Option<f32> {
+        let step = if self.step > 0.0 {
+            self.step
+        } else {
+            (self.max - self.min) / 100.0
+        };
+        let page = step.max((self.max - self.min) / 10.0);
+        match key {
+            keys::ARROW_RIGHT | keys::ARROW_UP => Some(step),
+            keys::ARROW_LEFT | keys::ARROW_DOWN => Some(-step),
+            keys::PAGE_UP => Some(page),
+            keys::PAGE_DOWN => Some(-page),
+            keys::HOME => Some(f32::NEG_INFINITY),
+            keys::END => Some(f32::INFINITY),
+            _ => None,
+        }
+    }
+
+    fn content(&self) -> String {
+        self.values
+            .iter()
+            .map(|v| self.format(*v))
+            .collect::<Vec<_>>()
+            .join("..")
+    }
+
+    fn tick_values(&self) -> Vec<f32> {
+        let Some(interval) = self.tick_interval else {
+            return Vec::new();
+        };
+        let count = ((self.max - self.min) / interval).floor() as usize;
+        (0..=count.min(1000))
+            .map(|i| self.min + i as f32 * interval)
+            .collect()
+    }
+
+    fn bar(&self, from: f32, to: f32, color: Color) -> Shape {
+        let thickness = self.style.track_thickness;
+        let (a, b) = (self.point_of(from), self.point_of(to));
+        let area = match self.orientation {
+            Orientation::Horizontal => {
+                Rect::new(a.0, a.1 - thickness / 2.0, (b.0 - a.0).max(0.0), thickness)
+            }
+            Orientation::Vertical => {
+                Rect::new(a.0 - thickness / 2.0, b.1, thickness, (a.1 - b.1).max(0.0))
+            }
+        };
+        Shape::RoundedRect {
+            area,
+            radius: thickness / 2.0,
+            fill: color,
+            stroke: None,
+        }
+    }
+}
+
+impl Acetate for Slider {
+    fn id(&self) -> String {
+        self.id.clone()
+    }
+
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn z_index(&self) -> i32 {
+        self.z_index
+    }
+
+    fn area(&self) -> Rect {
+        self.area
+    }
+
+    fn relocate(&self, area: Rect) -> Box<dyn Acetate> {
+        Box::new(Self {
+            area,
+            ..self.clone()
+        })
+    }
+
+    fn subscriptions(&self) -> Vec<EventKind> {
+        vec![
+            EventKind::MouseMoved,
+            EventKind::MouseDown,
+            EventKind::MouseUp,
+            EventKind::KeyPressed,
+        ]
+    }
+
+    fn react(&self, event: &Event, _scene: &SceneInfo) -> Option<Box<dyn Acetate>> {
+        if self.is_disabled() {
+            return None;
+        }
+
+        let mut next = self.clone();
+        match event {
+            Event::MouseDown {
+                button: MouseButton::Left,
+                x,
+                y,
+            } => {
+                next.focused = self.area.contains(*x, *y);
+                if next.focused {
+                    let value = self.value_at(*x, *y);
+                    next.active = self.nearest_thumb(value);
+                    next.dragging = true;
+                    next.set_active(value);
+                }
+            }
+            Event::MouseMoved(x, y) if self.dragging => next.set_active(self.value_at(*x, *y)),
+            Event::MouseUp {
+                button: MouseButton::Left,
+                ..
+            } if self.dragging => next.dragging = false,
+            Event::KeyPressed(key) if self.focused => {
+                let delta = self.keyboard_delta(key)?;
+                next.set_active(self.values[self.active] + delta);
+            }
+            _ => return None,
+        }
+
+        let unchanged = next.values == self.values
+            && next.focused == self.focused
+            && next.active == self.active
+            && next.dragging == self.dragging;
+        if unchanged {
+            return None;
+        }
+        if next.values != self.values {
+            next.action = Some(EmittedAction::next(self.action.as_ref(), Action::Changed));
+        }
+        Some(Box::new(next))
+    }
+
+    fn perceive(&self, scene: &SceneInfo) -> SceneInfo {
+        scene.clone()
+    }
+
+    fn output(&self) -> AcetateIO {
+        AcetateIO {
+            content: Some(self.content()),
+            focus: self.focused,
+            status: self.status.clone(),
+            clipboard: None,
+            action: self.action.clone(),
+        }
+    }
+
+    fn design(&self) -> AcetateDesign {
+        let style = &self.style;
+        let dim = |c: Color| {
+            if self.is_disabled() {
+                c.with_alpha(c.a * 0.45)
+            } else {
+                c
+            }
+        };
+
+        let mut shapes = vec![self.bar(self.min, self.max, dim(style.track))];
+        let (from, to) = match self.values[..] {
+            [low, high] => (low, high),
+            _ => (self.min, self.values[0]),
+        };
+        shapes.push(self.bar(from, to, dim(style.fill)));
+
+        let half = style.track_thickness / 2.0 + 3.0;
+        for value in self.tick_values() {
+            let (x, y) = self.point_of(value);
+            let (a, b) = match self.orientation {
+                Orientation::Horizontal => ((x, y + half), (x, y + half + 4.0)),
+                Orientation::Vertical => ((x + half, y), (x + half + 4.0, y)),
+            };
+            shapes.push(Shape::Polyline {
+                points: vec![a, b],
+                stroke: ShapeStroke {
+                    color: dim(style.tick),
+                    width: 1.0,
+                },
+            });
+        }
+
+        for (index, value) in self.values.iter().enumerate() {
+            let border = if self.focused && index == self.active {
+                style.focus_border
+            } else {
+                style.thumb_border
+            };
+            shapes.push(Shape::Circle {
+                center: self.point_of(*value),
+                radius: style.thumb_radius,
+                fill: dim(style.thumb),
+                stroke: Some(ShapeStroke {
+                    color: dim(border),
+                    width: 1.5,
+                }),
+            });
+        }
+
+        AcetateDesign {
+            area: self.area,
+            shapes,
+            ..AcetateDesign::default()
+        }
+    }
+
+    fn clone_box(&self) -> Box<dyn Acetate> {
+        Box::new(self.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::contracts::scene::Scene;
+
+    fn area() -> Rect {
+        Rect::new(0.0, 0.0, 116.0, 20.0)
+    }
+
+    #[test]
+    fn drag_captures_pointer_and_thumbs_do_not_cross() {
+        // Recorrido útil de 100 px (pulgar de radio 8): x = 8 + valor.
+        let slider = Slider::new("range", area())
+            .with_bounds(0.0, 100.0, 1.0)
+            .with_range(20.0, 60.0);
+        let scene = Scene::from(vec![Box::new(slider) as Box<dyn Acetate>]);
+
+        let scene = scene.react(&[
+            Event::MouseDown {
+                button: MouseButton::Left,
+                x: 33,
+                y: 10,
+            },
+            Event::MouseMoved(200, 300),
+        ]);
+        assert_eq!(
+            scene.acetates[0].output().content.as_deref(),
+            Some("60..60")
+        );
+
+        let scene = scene.react(&[
+            Event::MouseUp {
+                button: MouseButton::Left,
+                x: 200,
+                y: 300,
+            },
+            Event::MouseMoved(8, 10),
+            Event::KeyPressed(keys::PAGE_DOWN.to_string()),
+        ]);
+        assert_eq!(
+            scene.acetates[0].output().content.as_deref(),
+            Some("50..60")
+        );
+    }
+
+    #[test]
+    fn events_that_change_nothing_do_not_rebuild_the_slider() {
+        let slider = Slider::new("volume", area())
+            .with_bounds(0.0, 100.0, 1.0)
+            .with_value(100.0);
+        let info = Scene::from(vec![slider.clone_box()]).info();
+        let outside = Event::MouseDown {
+            button: MouseButton::Left,
+            x: 300,
+            y: 300,
+        };
+        assert!(slider.react(&outside, &info).is_none());
+        assert!(slider.react(&Event::MouseMoved(30, 10), &info).is_none());
+
+        // Enfocado y en el máximo, subir más no cambia nada.
+        let press = Event::MouseDown {
+            button: MouseButton::Left,
+            x: 108,
+            y: 10,
+        };
+        let focused = slider.react(&press, &info).expect("focus");
+        let release = Event::MouseUp {
+            button: MouseButton::Left,
+            x: 108,
+            y: 10,
+        };
+        let focused = focused.react(&release, &info).expect("release");
+        let up = Event::KeyPressed(keys::ARROW_RIGHT.to_string());
+        assert!(focused.react(&up, &info).is_none());
+    }
+}
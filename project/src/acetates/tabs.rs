@@ -0,0 +1,519 @@
+//! Pestañas (o barra de navegación vertical) que muestran un grupo de acetates a la vez.
+
+use super::label::Label;
+use super::slider::Orientation;
+use crate::contracts::event::keys::Modifiers;
+use crate::contracts::event::{Action, Event, EventKind, MouseButton, keys};
+use crate::contracts::scene::{
+    Acetate, AcetateDesign, AcetateIO, AcetateStatus, EmittedAction, Rect, SceneInfo, Shape,
+    TextAlign, text_width,
+};
+use crate::core::Color;
+
+const PADDING: f32 = 12.0;
+const MIN_TAB_WIDTH: f32 = 64.0;
+const INDICATOR: f32 = 3.0;
+
+/// Pestaña con los ids de los acetates que se ven mientras está activa.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Tab {
+    pub label: String,
+    pub group: Vec<String>,
+}
+
+impl Tab {
+    pub fn new(label: &str) -> Self {
+        Self {
+            label: label.to_string(),
+            group: Vec::new(),
+        }
+    }
+
+    pub fn with_group<S: Into<String>>(mut self, ids: impl IntoIterator<Item = S>) -> Self {
+        self.group = ids.into_iter().map(Into::into).collect();
+        self
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TabStyle {
+    pub background: Color,
+    pub hover: Color,
+    pub active: Color,
+    /// Barra que marca la pestaña activa (abajo en horizontal, a la izquierda en vertical).
+    pub indicator: Color,
+    pub focus_border: Color,
+    pub text_color: Color,
+    pub active_text: Color,
+    pub font_size: f32,
+    /// Alto de cada entrada en la barra vertical.
+    pub tab_height: u32,
+}
+
+impl Default for TabStyle {
+    fn default() -> Self {
+        Self {
+            background: Color::rgba(0.12, 0.13, 0.17, 1.0),
+            hover: Color::rgba(1.0, 1.0, 1.0, 0.06),
+            active: Color::rgba(0.17, 0.18, 0.23, 1.0),
+            indicator: Color::rgba(0.35, 0.55, 0.95, 1.0),
+            focus_border: Color::rgba(0.35, 0.55, 0.95, 1.0),
+            text_color: Color::rgba(0.70, 0.72, 0.78, 1.0),
+            active_text: Color::rgba(0.95, 0.95, 0.97, 1.0),
+            font_size: 14.0,
+            tab_height: 36,
+        }
+    }
+}
+
+/// Tira de pestañas. Clic activa una pestaña; Ctrl+Tab / Ctrl+Shift+Tab pasan a la
+/// siguiente o anterior desde cualquier sitio, y con foco también las flechas,
+/// Inicio y Fin. Los grupos inactivos se ocultan con `AcetateStatus::Hidden` vía
+/// `status_requests`. Cada cambio emite `Action::Changed`; `output().content` es
+/// la etiqueta activa.
+#[derive(Debug, Clone)]
+pub struct Tabs {
+    id: String,
+    name: String,
+    z_index: i32,
+    area: Rect,
+    tabs: Vec<Tab>,
+    active: usize,
+    orientation: Orientation,
+    style: TabStyle,
+    status: AcetateStatus,
+    focused: bool,
+    hovered_tab: Option<usize>,
+    pressed_tab: Option<usize>,
+    modifiers: Modifiers,
+    action: Option<EmittedAction>,
+}
+
+impl Tabs {
+    pub fn new(id: &str, area: Rect) -> Self {
+        Self {
+            id: id.to_string(),
+            name: id.to_string(),
+            z_index: 0,
+            area,
+            tabs: Vec::new(),
+            active: 0,
+            orientation: Orientation::Horizontal,
+            style: TabStyle::default(),
+            status: AcetateStatus::Ready,
+            focused: false,
+            hovered_tab: None,
+            pressed_tab: None,
+            modifiers: Modifiers::default(),
+            action: None,
+        }
+    }
+
+    pub fn with_name(mut self, name: &str) -> Self {
+        self.name = name.to_string();
+        self
+    }
+
+    pub fn with_z_index(mut self, z_index: i32) -> Self {
+        self.z_index = z_index;
+        self
+    }
+
+    pub fn with_tabs(mut self, tabs: impl IntoIterator<Item = Tab>) -> Self {
+        self.tabs = tabs.into_iter().collect();
+        self.active = self.active.min(self.tabs.len().saturating_sub(1));
+        self
+    }
+
+    pub fn with_active(mut self, active: usize) -> Self {
+        self.active = active.min(self.tabs.len().saturating_sub(1));
+        self
+    }
+
+    /// `Vertical` la convierte en barra de navegación (una entrada por fila).
+    pub fn with_orientation(mut self, orientation: Orientation) -> Self {
+        self.orientation = orientation;
+        self
+    }
+
+    pub fn with_style(mut self, style: TabStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    pub fn with_status(mut self, status: AcetateStatus) -> Self {
+        self.status = status;
+        self
+    }
+
+    pub fn active(&self) -> usize {
+        self.active
+    }
+
+    fn is_disabled(&self) -> bool {
+        !matches!(self.status, AcetateStatus::Ready)
+    }
+
+    fn tab_rects(&self) -> Vec<Rect> {
+        let mut offset = 0.0;
+        self.tabs
+            .iter()
+            .map(|tab| match self.orientation {
+                Orientation::Horizontal => {
+                    let text = text_width(&tab.label, self.style.font_size);
+                    let width = (text + 2.0 * PADDING).ceil().max(MIN_TAB_WIDTH);
+                    let rect = Rect {
+                        x: self.area.x + offset,
+                        y: self.area.y,
+                        width,
+                        height: self.area.height,
+                    };
+                    offset += width;
+                    rect
+                }
+                Orientation::Vertical => {
+                    let rect = Rect {
+                        x: self.area.x,
+                        y: self.area.y + offset,
+                        width: self.area.width,
+                        height: self.style.tab_height as f32,
+                    };
+                    offset += self.style.tab_height as f32;
+                    rect
+                }
+            })
+            .collect()
+    }
+
+    fn tab_at(&self, x: i32, y: i32) -> Option<usize> {
+        if !self.area.contains(x, y) {
+            return None;
+        }
+        self.tab_rects().iter().position(|rect| rect.contains(x, y))
+    }
+
+    fn activate(&mut self, tab: usize) {
+        if tab != self.active && tab < self.tabs.len() {
+            self.active = tab;
+            self.action = Some(EmittedAction::next(self.action.as_ref(), Action::Changed));
+        }
+    }
+
+    /// Pestaña destino de una tecla: Ctrl+Tab da la vuelta; las flechas, no.
+    fn navigate(&self, key: &str) -> Option<usize> {
+        let last = self.tabs.len().checked_sub(1)?;
+        let (back, forward) = match self.orientation {
+            Orientation::Horizontal => (keys::ARROW_LEFT, keys::ARROW_RIGHT),
+            Orientation::Vertical => (keys::ARROW_UP, keys::ARROW_DOWN),
+        };
+        if key == keys::TAB && self.modifiers.control {
+            return Some(if self.modifiers.shift {
+                self.active.checked_sub(1).unwrap_or(last)
+            } else if self.active < last {
+                self.active + 1
+            } else {
+                0
+            });
+        }
+        if !self.focused {
+            return None;
+        }
+        match key {
+            k if k == back => Some(self.active.saturating_sub(1)),
+            k if k == forward => Some((self.active + 1).min(last)),
+            keys::HOME => Some(0),
+            keys::END => Some(last),
+            _ => None,
+        }
+    }
+
+    fn indicator(&self, rect: &Rect) -> Rect {
+        match self.orientation {
+            Orientation::Horizontal => Rect {
+                x: rect.x,
+                y: rect.y + (rect.height - INDICATOR).max(0.0),
+                width: rect.width,
+                height: INDICATOR.min(rect.height),
+            },
+            Orientation::Vertical => Rect {
+                width: INDICATOR.min(rect.width),
+                ..*rect
+            },
+        }
+    }
+}
+
+impl Acetate for Tabs {
+    fn id(&self) -> String {
+        self.id.clone()
+    }
+
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn z_index(&self) -> i32 {
+        self.z_index
+    }
+
+    fn area(&self) -> Rect {
+        self.area
+    }
+
+    fn relocate(&self, area: Rect) -> Box<dyn Acetate> {
+        Box::new(Self {
+            area,
+            ..self.clone()
+        })
+    }
+
+    fn restyle(
+        &self,
+        _border_thickness: Option<f32>,
+        font_size: Option<f32>,
+    ) -> Box<dyn Acetate> {
+        let mut style = self.style.clone();
+        style.font_size = font_size.unwrap_or(style.font_size);
+        Box::new(self.clone().with_style(style))
+    }
+
+    fn subscriptions(&self) -> Vec<EventKind> {
+        vec![
+            EventKind::MouseMoved,
+            EventKind::MouseDown,
+            EventKind::MouseUp,
+            EventKind::KeyPressed,
+            EventKind::KeyReleased,
+        ]
+    }
+
+    fn react(&self, event: &Event, _scene: &SceneInfo) -> Option<Box<dyn Acetate>> {
+        if self.is_disabled() {
+            return None;
+        }
+
+        let mut next = self.clone();
+        next.modifiers = self.modifiers.after(event);
+        match event {
+            Event::MouseMoved(x, y) => next.hovered_tab = self.tab_at(*x, *y),
+            Event::MouseDown {
+                button: MouseButton::Left,
+                x,
+                y,
+            } => {
+                next.focused = self.area.contains(*x, *y);
+                next.pressed_tab = self.tab_at(*x, *y);
+            }
+            Event::MouseUp {
+                button: MouseButton::Left,
+                x,
+                y,
+            } => {
+                if let Some(tab) = self.pressed_tab
+                    && self.tab_at(*x, *y) == Some(tab)
+                {
+                    next.activate(tab);
+                }
+                next.pressed_tab = None;
+            }
+            Event::KeyPressed(key) => {
+                if let Some(tab) = self.navigate(key) {
+                    next.activate(tab);
+                }
+            }
+            _ => {}
+        }
+
+        let unchanged = next.active == self.active
+            && next.focused == self.focused
+            && next.hovered_tab == self.hovered_tab
+            && next.pressed_tab == self.pressed_tab
+            && next.modifiers == self.modifiers;
+        if unchanged {
+            return None;
+        }
+        Some(Box::new(next))
+    }
+
+    fn perceive(&self, scene: &SceneInfo) -> SceneInfo {
+        scene.clone()
+    }
+
+    fn output(&self) -> AcetateIO {
+        AcetateIO {
+            content: self.tabs.get(self.active).map(|tab| tab.label.clone()),
+            focus: self.focused,
+            status: self.status.clone(),
+            clipboard: None,
+            action: self.action.clone(),
+        }
+    }
+
+    fn design(&self) -> AcetateDesign {
+        let mut shapes = Vec::new();
+        for (index, rect) in self.tab_rects().into_iter().enumerate() {
+            if index == self.active {
+                shapes.push(Shape::Rect {
+                    area: rect,
+                    fill: self.style.active,
+                });
+                shapes.push(Shape::Rect {
+                    area: self.indicator(&rect),
+                    fill: self.style.indicator,
+                });
+            } else if self.hovered_tab == Some(index) {
+                shapes.push(Shape::Rect {
+                    area: rect,
+                    fill: self.style.hover,
+                });
+            }
+        }
+
+        let design = AcetateDesign {
+            area: self.area,
+            background: self.style.background,
+            border: self.style.focus_border,
+            border_thickness: if self.focused { 1.0 } else { 0.0 },
+            text_color: self.style.text_color,
+            font_size: self.style.font_size,
+            shapes,
+            ..AcetateDesign::default()
+        };
+        if self.is_disabled() {
+            return AcetateDesign {
+                text_color: design.text_color.with_alpha(design.text_color.a * 0.45),
+                ..design
+            };
+        }
+        design
+    }
+
+    fn children(&self) -> Vec<Box<dyn Acetate>> {
+        self.tab_rects()
+            .into_iter()
+            .zip(&self.tabs)
+            .enumerate()
+            .map(|(index, (rect, tab))| {
+                let color = if index == self.active {
+                    self.style.active_text
+                } else {
+                    self.style.text_color
+                };
+                let id = format!("{}/{index}", self.id);
+                Box::new(
+                    Label::new(&id, rect, &tab.label)
+                        .with_text_color(color)
+                        .with_font_size(self.style.font_size)
+                        .with_align(TextAlign::Center),
+                ) as Box<dyn Acetate>
+            })
+            .collect()
+    }
+
+    fn status_requests(&self) -> Vec<(String, AcetateStatus)> {
+        self.tabs
+            .iter()
+            .enumerate()
+            .flat_map(|(index, tab)| {
+                let status = if index == self.active {
+                    AcetateStatus::Ready
+                } else {
+                    AcetateStatus::Hidden
+                };
+                tab.group.iter().map(move |id| (id.clone(), status.clone()))
+            })
+            .collect()
+    }
+
+    fn clone_box(&self) -> Box<dyn Acetate> {
+        Box::new(self.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::acetates::Button;
+    use crate::acetates::test_support::{click, rect};
+    use crate::actors::snapshot_builder::SnapshotBuilder;
+    use crate::contracts::scene::Scene;
+
+    fn status(scene: &Scene, index: usize) -> AcetateStatus {
+        scene.acetates[index].output().status
+    }
+
+    #[test]
+    fn switching_tabs_hides_inactive_groups() {
+        let tabs = Tabs::new("settings", rect(0, 0, 300, 32)).with_tabs([
+            Tab::new("General").with_group(["save"]),
+            Tab::new("Avanzado").with_group(["reset"]),
+        ]);
+        let scene = Scene::from(vec![
+            Box::new(tabs) as Box<dyn Acetate>,
+            Box::new(Button::new("save", rect(0, 40, 80, 30))),
+            Box::new(Button::new("reset", rect(0, 40, 80, 30))),
+        ]);
+
+        let scene = scene.react(&[]);
+        assert_eq!(status(&scene, 2), AcetateStatus::Hidden);
+        assert_eq!(SnapshotBuilder::build(scene.clone()).layers.len(), 2 + 2);
+
+        // "General" mide 83 px (7 caracteres + relleno): x = 100 cae en "Avanzado".
+        let scene = scene.react(&click(100, 10));
+        assert_eq!(
+            scene.acetates[0].output().content.as_deref(),
+            Some("Avanzado")
+        );
+        assert_eq!(status(&scene, 1), AcetateStatus::Hidden);
+        assert_eq!(status(&scene, 2), AcetateStatus::Ready);
+
+        // Solo el botón visible recibe el clic aunque ambos ocupen el mismo sitio.
+        let scene = scene.react(&click(10, 50));
+        assert!(scene.acetates[1].output().action.is_none());
+        assert!(scene.acetates[2].output().action.is_some());
+
+        let scene = scene.react(&[
+            Event::KeyPressed(keys::CONTROL.to_string()),
+            Event::KeyPressed(keys::TAB.to_string()),
+        ]);
+        assert_eq!(
+            scene.acetates[0].output().content.as_deref(),
+            Some("General")
+        );
+        assert_eq!(status(&scene, 1), AcetateStatus::Ready);
+        assert_eq!(status(&scene, 2), AcetateStatus::Hidden);
+    }
+
+    #[test]
+    fn keyboard_moves_between_tabs_and_ctrl_tab_wraps() {
+        let tabs = Tabs::new("settings", rect(0, 0, 300, 32))
+            .with_tabs([Tab::new("A"), Tab::new("B"), Tab::new("C")]);
+        let scene = Scene::from(vec![Box::new(tabs) as Box<dyn Acetate>]);
+        let active = |scene: &Scene| scene.acetates[0].output().content;
+        let typed = |names: &[&str]| {
+            names
+                .iter()
+                .map(|name| Event::KeyPressed(name.to_string()))
+                .collect::<Vec<_>>()
+        };
+
+        // Sin foco las flechas no hacen nada.
+        let scene = scene.react(&typed(&[keys::ARROW_RIGHT]));
+        assert_eq!(active(&scene).as_deref(), Some("A"));
+
+        // Con foco las flechas se detienen en los extremos; Home y End saltan.
+        let scene = scene.react(&click(10, 10));
+        let scene = scene.react(&typed(&[keys::ARROW_RIGHT, keys::ARROW_RIGHT, keys::ARROW_RIGHT]));
+        assert_eq!(active(&scene).as_deref(), Some("C"));
+        let scene = scene.react(&typed(&[keys::HOME, keys::ARROW_LEFT]));
+        assert_eq!(active(&scene).as_deref(), Some("A"));
+        let scene = scene.react(&typed(&[keys::END]));
+        assert_eq!(active(&scene).as_deref(), Some("C"));
+
+        // Ctrl+Tab da la vuelta en los dos sentidos.
+        let scene = scene.react(&typed(&[keys::CONTROL, keys::TAB]));
+        assert_eq!(active(&scene).as_deref(), Some("A"));
+        let scene = scene.react(&typed(&[keys::SHIFT, keys::TAB]));
+        assert_eq!(active(&scene).as_deref(), Some("C"));
+    }
+}
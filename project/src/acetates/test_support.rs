@@ -0,0 +1,29 @@
+//! Ayudas compartidas por las pruebas de los acetates (y del layout).
+
+use crate::contracts::event::{Event, MouseButton};
+use crate::contracts::scene::Rect;
+
+pub fn rect(x: i32, y: i32, width: u32, height: u32) -> Rect {
+    Rect::new(x as f32, y as f32, width as f32, height as f32)
+}
+
+/// Pulsar y soltar `button` en el mismo punto.
+pub fn press(button: MouseButton, x: i32, y: i32) -> [Event; 2] {
+    [
+        Event::MouseDown { button, x, y },
+        Event::MouseUp { button, x, y },
+    ]
+}
+
+/// Clic izquierdo en `(x, y)`.
+pub fn click(x: i32, y: i32) -> [Event; 2] {
+    press(MouseButton::Left, x, y)
+}
+
+/// Pulsar el botón izquierdo en `(x, y)` y soltarlo en `release`.
+pub fn drag(x: i32, y: i32, release: (i32, i32)) -> [Event; 2] {
+    [
+        Event::MouseDown { button: MouseButton::Left, x, y },
+        Event::MouseUp { button: MouseButton::Left, x: release.0, y: release.1 },
+    ]
+}
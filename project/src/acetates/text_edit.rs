@@ -0,0 +1,440 @@
+//! Modelo de edición de texto (valor, cursor, selección e historial).
+//! No sabe nada de eventos ni de pintura: el `TextField` lo traduce.
+
+/// Foto del estado editable, usada por deshacer/rehacer.
+#[derive(Debug, Clone, PartialEq)]
+struct EditSnapshot {
+    text: String,
+    caret: usize,
+    anchor: Option<usize>,
+}
+
+/// Tipo de la última edición, para agrupar la escritura continua en un solo paso de deshacer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum EditKind {
+    Typing,
+    Other,
+}
+
+/// Estado editable de un texto. Los índices son de caracteres, no de bytes.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TextEdit {
+    text: String,
+    caret: usize,
+    anchor: Option<usize>,
+    /// Columna deseada al moverse en vertical entre líneas de distinto largo.
+    preferred_col: Option<usize>,
+    multiline: bool,
+    undo: Vec<EditSnapshot>,
+    redo: Vec<EditSnapshot>,
+    last_kind: Option<EditKind>,
+}
+
+const HISTORY_LIMIT: usize = 100;
+
+impl TextEdit {
+    pub fn new(text: &str, multiline: bool) -> Self {
+        let text = if multiline { text.to_string() } else { single_line(text) };
+        let caret = text.chars().count();
+        Self {
+            text,
+            caret,
+            anchor: None,
+            preferred_col: None,
+            multiline,
+            undo: Vec::new(),
+            redo: Vec::new(),
+            last_kind: None,
+        }
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    pub fn caret(&self) -> usize {
+        self.caret
+    }
+
+    pub fn is_multiline(&self) -> bool {
+        self.multiline
+    }
+
+    pub fn len(&self) -> usize {
+        self.text.chars().count()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.text.is_empty()
+    }
+
+    /// Rango seleccionado `(inicio, fin)` si la selección no está vacía.
+    pub fn selection(&self) -> Option<(usize, usize)> {
+        let anchor = self.anchor?;
+        if anchor == self.caret {
+            return None;
+        }
+        Some((anchor.min(self.caret), anchor.max(self.caret)))
+    }
+
+    pub fn selected_text(&self) -> Option<String> {
+        let (start, end) = self.selection()?;
+        Some(self.text.chars().skip(start).take(end - start).collect())
+    }
+
+    // --- navegación ---
+
+    /// Coloca el cursor en `index`; con `extend` conserva/crea la selección.
+    pub fn set_caret(&mut self, index: usize, extend: bool) {
+        let index = index.min(self.len());
+        if extend {
+            self.anchor.get_or_insert(self.caret);
+        } else {
+            self.anchor = None;
+        }
+        self.caret = index;
+        self.preferred_col = None;
+        self.last_kind = None;
+    }
+
+    pub fn select_all(&mut self) {
+        self.anchor = Some(0);
+        self.caret = self.len();
+        self.last_kind = None;
+    }
+
+    pub fn move_left(&mut self, extend: bool, word: bool) {
+        if !extend
+            && !word
+            && let Some((start, _)) = self.selection()
+        {
+            return self.set_caret(start, false);
+        }
+        let target = if word {
+            self.word_start_before(self.caret)
+        } else {
+            self.caret.saturating_sub(1)
+        };
+        self.set_caret(target, extend);
+    }
+
+    pub fn move_right(&mut self, extend: bool, word: bool) {
+        if !extend
+            && !word
+            && let Some((_, end)) = self.selection()
+        {
+            return self.set_caret(end, false);
+        }
+        let target = if word {
+            self.word_end_after(self.caret)
+        } else {
+            (self.caret + 1).min(self.len())
+        };
+        self.set_caret(target, extend);
+    }
+
+    /// Inicio de la línea actual (o del texto completo con `document`).
+    pub fn move_home(&mut self, extend: bool, document: bool) {
+        let target = if document {
+            0
+        } else {
+            let (line, _) = self.line_col(self.caret);
+            self.index_at(line, 0)
+        };
+        self.set_caret(target, extend);
+    }
+
+    /// Fin de la línea actual (o del texto completo con `document`).
+    pub fn move_end(&mut self, extend: bool, document: bool) {
+        let target = if document {
+            self.len()
+        } else {
+            let (line, _) = self.line_col(self.caret);
+            self.index_at(line, usize::MAX)
+        };
+        self.set_caret(target, extend);
+    }
+
+    /// Sube/baja `lines` líneas conservando la columna (solo multilínea).
+    pub fn move_vertical(&mut self, lines: isize, extend: bool) {
+        let (line, col) = self.line_col(self.caret);
+        let col = self.preferred_col.unwrap_or(col);
+        let last = self.line_count().saturating_sub(1);
+        let target_line = line.saturating_add_signed(lines).min(last);
+        let target = if lines < 0 && line == 0 {
+            0
+        } else if lines > 0 && line == last {
+            self.len()
+        } else {
+            self.index_at(target_line, col)
+        };
+        self.set_caret(target, extend);
+        self.preferred_col = Some(col);
+    }
+
+    // --- edición ---
+
+    /// Inserta texto reemplazando la selección. En una sola línea se descartan saltos.
+    pub fn insert(&mut self, input: &str) {
+        let input = if self.multiline { input.to_string() } else { single_line(input) };
+        if input.is_empty() && self.selection().is_none() {
+            return;
+        }
+        let typing = input.chars().count() == 1;
+        let kind = if typing { EditKind::Typing } else { EditKind::Other };
+        self.record(kind, self.selection().is_none());
+
+        let (start, end) = self.selection().unwrap_or((self.caret, self.caret));
+        self.replace_range(start, end, &input);
+        self.caret = start + input.chars().count();
+        self.anchor = None;
+    }
+
+    /// Borra hacia atrás: la selección, un carácter o una palabra.
+    pub fn backspace(&mut self, word: bool) {
+        let range = match self.selection() {
+            Some(range) => range,
+            None if self.caret == 0 => return,
+            None if word => (self.word_start_before(self.caret), self.caret),
+            None => (self.caret - 1, self.caret),
+        };
+        self.delete_range(range);
+    }
+
+    /// Borra hacia delante: la selección, un carácter o una palabra.
+    pub fn delete(&mut self, word: bool) {
+        let range = match self.selection() {
+            Some(range) => range,
+            None if self.caret >= self.len() => return,
+            None if word => (self.caret, self.word_end_after(self.caret)),
+            None => (self.caret, self.caret + 1),
+        };
+        self.delete_range(range);
+    }
+
+    /// Quita la selección y devuelve el texto eliminado (para cortar).
+    pub fn take_selection(&mut self) -> Option<String> {
+        let selected = self.selected_text()?;
+        let range = self.selection()?;
+        self.delete_range(range);
+        Some(selected)
+    }
+
+    pub fn undo(&mut self) -> bool {
+        let Some(previous) = self.undo.pop() else {
+            return false;
+        };
+        self.redo.push(self.snapshot());
+        self.restore(previous);
+        true
+    }
+
+    pub fn redo(&mut self) -> bool {
+        let Some(next) = self.redo.pop() else {
+            return false;
+        };
+        self.undo.push(self.snapshot());
+        self.restore(next);
+        true
+    }
+
+    // --- geometría lógica (líneas/columnas) ---
+
+    pub fn line_count(&self) -> usize {
+        self.text.split('\n').count()
+    }
+
+    /// Convierte un índice de carácter en `(línea, columna)`.
+    pub fn line_col(&self, index: usize) -> (usize, usize) {
+        let mut line = 0;
+        let mut col = 0;
+        for ch in self.text.chars().take(index) {
+            if ch == '\n' {
+                line += 1;
+                col = 0;
+            } else {
+                col += 1;
+            }
+        }
+        (line, col)
+    }
+
+    /// Índice de carácter para `(línea, columna)`, acotando la columna al largo de la línea.
+    pub fn index_at(&self, line: usize, col: usize) -> usize {
+        let mut index = 0;
+        for (current, text) in self.text.split('\n').enumerate() {
+            let len = text.chars().count();
+            if current == line {
+                return index + col.min(len);
+            }
+            index += len + 1;
+        }
+        self.len()
+    }
+
+    // --- internos ---
+
+    fn snapshot(&self) -> EditSnapshot {
+        EditSnapshot {
+            text: self.text.clone(),
+            caret: self.caret,
+            anchor: self.anchor,
+        }
+    }
+
+    fn restore(&mut self, snapshot: EditSnapshot) {
+        self.text = snapshot.text;
+        self.caret = snapshot.caret;
+        self.anchor = snapshot.anchor;
+        self.preferred_col = None;
+        self.last_kind = None;
+    }
+
+    /// Guarda el estado previo en el historial (la escritura continua se agrupa).
+    fn record(&mut self, kind: EditKind, can_coalesce: bool) {
+        let coalesce = can_coalesce
+            && kind == EditKind::Typing
+            && self.last_kind == Some(EditKind::Typing);
+        if !coalesce {
+            self.undo.push(self.snapshot());
+            if self.undo.len() > HISTORY_LIMIT {
+                self.undo.remove(0);
+            }
+        }
+        self.redo.clear();
+        self.last_kind = Some(kind);
+    }
+
+    fn delete_range(&mut self, (start, end): (usize, usize)) {
+        self.record(EditKind::Other, false);
+        self.replace_range(start, end, "");
+        self.caret = start;
+        self.anchor = None;
+    }
+
+    fn replace_range(&mut self, start: usize, end: usize, with: &str) {
+        let start_byte = self.byte_index(start);
+        let end_byte = self.byte_index(end);
+        self.text.replace_range(start_byte..end_byte, with);
+    }
+
+    fn byte_index(&self, index: usize) -> usize {
+        self.text
+            .char_indices()
+            .nth(index)
+            .map(|(byte, _)| byte)
+            .unwrap_or(self.text.len())
+    }
+
+    fn word_start_before(&self, index: usize) -> usize {
+        let chars: Vec<char> = self.text.chars().collect();
+        let mut i = index.min(chars.len());
+        while i > 0 && char_class(chars[i - 1]) == CharClass::Space {
+            i -= 1;
+        }
+        if i == 0 {
+            return 0;
+        }
+        let class = char_class(chars[i - 1]);
+        while i > 0 && char_class(chars[i - 1]) == class {
+            i -= 1;
+        }
+        i
+    }
+
+    fn word_end_after(&self, index: usize) -> usize {
+        let chars: Vec<char> = self.text.chars().collect();
+        let mut i = index.min(chars.len());
+        while i < chars.len() && char_class(chars[i]) == CharClass::Space {
+            i += 1;
+        }
+        if i == chars.len() {
+            return i;
+        }
+        let class = char_class(chars[i]);
+        while i < chars.len() && char_class(chars[i]) == class {
+            i += 1;
+        }
+        i
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CharClass {
+    Space,
+    Word,
+    Punctuation,
+}
+
+fn char_class(ch: char) -> CharClass {
+    if ch.is_whitespace() {
+        CharClass::Space
+    } else if ch.is_alphanumeric() || ch == '_' {
+        CharClass::Word
+    } else {
+        CharClass::Punctuation
+    }
+}
+
+fn single_line(text: &str) -> String {
+    text.chars().filter(|c| *c != '\n' && *c != '\r').collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn typing_replaces_selection_and_undoes_as_one_step() {
+        let mut edit = TextEdit::new("hola mundo", false);
+        edit.set_caret(5, false);
+        edit.set_caret(10, true);
+        assert_eq!(edit.selected_text().as_deref(), Some("mundo"));
+
+        for ch in ["t", "e", "s", "t"] {
+            edit.insert(ch);
+        }
+        assert_eq!(edit.text(), "hola test");
+
+        edit.move_home(false, false);
+        edit.insert("¡");
+        assert_eq!(edit.text(), "¡hola test");
+        assert!(edit.undo());
+        assert_eq!(edit.text(), "hola test");
+        assert!(edit.undo());
+        assert_eq!(edit.text(), "hola mundo");
+        assert!(edit.redo());
+        assert_eq!(edit.text(), "hola test");
+    }
+
+    #[test]
+    fn word_navigation_and_deletion() {
+        let mut edit = TextEdit::new("uno, dos tres", false);
+        edit.move_left(false, true);
+        assert_eq!(edit.caret(), 9);
+        edit.move_left(true, true);
+        assert_eq!(edit.selected_text().as_deref(), Some("dos "));
+        edit.move_right(false, false);
+        edit.backspace(true);
+        assert_eq!(edit.text(), "uno, tres");
+        edit.move_home(false, false);
+        edit.delete(true);
+        assert_eq!(edit.text(), ", tres");
+    }
+
+    #[test]
+    fn multiline_vertical_moves_keep_column() {
+        let mut edit = TextEdit::new("abcd\nx\nefgh", true);
+        edit.set_caret(3, false);
+        edit.move_vertical(1, false);
+        assert_eq!(edit.line_col(edit.caret()), (1, 1));
+        edit.move_vertical(1, false);
+        assert_eq!(edit.line_col(edit.caret()), (2, 3));
+        edit.move_end(false, false);
+        assert_eq!(edit.caret(), edit.len());
+
+        let mut single = TextEdit::new("a\nb", false);
+        single.insert("\nc");
+        assert_eq!(single.text(), "abc");
+    }
+}
@@ -0,0 +1,515 @@
+//! Campo de texto editable: cursor, selección, historial y cursor parpadeante.
+
+use std::time::{Duration, Instant};
+
+use super::text_edit::TextEdit;
+use crate::contracts::event::keys::{self, is_char_key};
+use crate::contracts::event::{Event, EventKind, Modifiers, MouseButton};
+use crate::contracts::scene::{
+    Acetate, AcetateDesign, AcetateIO, AcetateStatus, CHAR_WIDTH, ClipboardRequest, LINE_HEIGHT,
+    Rect, SceneInfo, Shape,
+};
+use crate::core::Color;
+
+/// Semiperiodo del parpadeo del cursor.
+const CARET_BLINK: Duration = Duration::from_millis(530);
+const PASSWORD_MASK: char = '•';
+
+/// Colores y métricas del campo.
+/// Sin motor de texto todavía, la geometría asume una fuente monoespaciada:
+/// cada carácter mide `font_size * 0.6` y cada línea `font_size * 1.25`.
+#[derive(Debug, Clone)]
+pub struct TextFieldStyle {
+    pub background: Color,
+    pub border: Color,
+    pub focus_border: Color,
+    pub border_thickness: f32,
+    pub text_color: Color,
+    pub placeholder_color: Color,
+    pub selection_color: Color,
+    pub caret_color: Color,
+    pub font_size: f32,
+    pub padding: u32,
+}
+
+impl Default for TextFieldStyle {
+    fn default() -> Self {
+        Self {
+            background: Color::rgba(0.12, 0.13, 0.17, 1.0),
+            border: Color::rgba(0.30, 0.32, 0.38, 1.0),
+            focus_border: Color::rgba(0.35, 0.55, 0.95, 1.0),
+            border_thickness: 1.0,
+            text_color: Color::rgba(0.92, 0.93, 0.95, 1.0),
+            placeholder_color: Color::rgba(0.50, 0.52, 0.58, 1.0),
+            selection_color: Color::rgba(0.30, 0.45, 0.85, 0.55),
+            caret_color: Color::rgba(0.92, 0.93, 0.95, 1.0),
+            font_size: 14.0,
+            padding: 6,
+        }
+    }
+}
+
+impl TextFieldStyle {
+    fn char_width(&self) -> f32 {
+        self.font_size * CHAR_WIDTH
+    }
+
+    fn line_height(&self) -> f32 {
+        self.font_size * LINE_HEIGHT
+    }
+}
+
+/// Acetate de texto editable. Su valor actual se expone en `AcetateIO::content`.
+#[derive(Debug, Clone)]
+pub struct TextField {
+    id: String,
+    name: String,
+    z_index: i32,
+    area: Rect,
+    style: TextFieldStyle,
+    placeholder: Option<String>,
+    password: bool,
+    edit: TextEdit,
+    status: AcetateStatus,
+    focused: bool,
+    dragging: bool,
+    modifiers: Modifiers,
+    caret_visible: bool,
+    blink_elapsed: Duration,
+    last_tick: Option<Instant>,
+    clipboard: Option<ClipboardRequest>,
+}
+
+impl TextField {
+    pub fn new(id: &str, area: Rect) -> Self {
+        Self {
+            id: id.to_string(),
+            name: id.to_string(),
+            z_index: 0,
+            area,
+            style: TextFieldStyle::default(),
+            placeholder: None,
+            password: false,
+            edit: TextEdit::new("", false),
+            status: AcetateStatus::Ready,
+            focused: false,
+            dragging: false,
+            modifiers: Modifiers::default(),
+            caret_visible: true,
+            blink_elapsed: Duration::ZERO,
+            last_tick: None,
+            clipboard: None,
+        }
+    }
+
+    pub fn with_name(mut self, name: &str) -> Self {
+        self.name = name.to_string();
+        self
+    }
+
+    pub fn with_z_index(mut self, z_index: i32) -> Self {
+        self.z_index = z_index;
+        self
+    }
+
+    pub fn with_style(mut self, style: TextFieldStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    pub fn with_value(mut self, value: &str) -> Self {
+        self.edit = TextEdit::new(value, self.edit.is_multiline());
+        self
+    }
+
+    pub fn with_placeholder(mut self, placeholder: &str) -> Self {
+        self.placeholder = Some(placeholder.to_string());
+        self
+    }
+
+    /// Enmascara el valor al pintarlo (el contenido expuesto sigue siendo el real).
+    pub fn password(mut self, password: bool) -> Self {
+        self.password = password;
+        self
+    }
+
+    /// En modo multilínea Enter inserta saltos y las flechas verticales cambian de línea.
+    pub fn multiline(mut self, multiline: bool) -> Self {
+        self.edit = TextEdit::new(self.edit.text(), multiline);
+        self
+    }
+
+    pub fn with_status(mut self, status: AcetateStatus) -> Self {
+        self.status = status;
+        self
+    }
+
+    pub fn with_focus(mut self, focused: bool) -> Self {
+        self.focused = focused;
+        self
+    }
+
+    pub fn value(&self) -> &str {
+        self.edit.text()
+    }
+
+    pub fn is_focused(&self) -> bool {
+        self.focused
+    }
+
+    // --- geometría ---
+
+    fn text_origin(&self) -> (f32, f32) {
+        let pad = self.style.padding as f32;
+        let x = self.area.x + pad;
+        let y = if self.edit.is_multiline() {
+            self.area.y + pad
+        } else {
+            self.area.y + (self.area.height - self.style.line_height()) / 2.0
+        };
+        (x, y)
+    }
+
+    /// Índice de carácter más cercano al punto dado.
+    fn index_at_point(&self, x: i32, y: i32) -> usize {
+        let (origin_x, origin_y) = self.text_origin();
+        let line = ((y as f32 - origin_y) / self.style.line_height()).floor().max(0.0) as usize;
+        let line = line.min(self.edit.line_count().saturating_sub(1));
+        let col = ((x as f32 - origin_x) / self.style.char_width()).round().max(0.0) as usize;
+        self.edit.index_at(line, col)
+    }
+
+    fn cell_rect(&self, line: usize, col: usize, cols: usize) -> Rect {
+        let (origin_x, origin_y) = self.text_origin();
+        let char_width = self.style.char_width();
+        let line_height = self.style.line_height();
+        Rect::new(
+            origin_x + col as f32 * char_width,
+            origin_y + line as f32 * line_height,
+            cols as f32 * char_width,
+            line_height,
+        )
+    }
+
+    fn selection_shapes(&self) -> Vec<Shape> {
+        let Some((start, end)) = self.edit.selection() else {
+            return Vec::new();
+        };
+        let (start_line, start_col) = self.edit.line_col(start);
+        let (end_line, end_col) = self.edit.line_col(end);
+
+        (start_line..=end_line)
+            .map(|line| {
+                let from = if line == start_line { start_col } else { 0 };
+                let to = if line == end_line {
+                    end_col
+                } else {
+                    // Fin de línea + un hueco para que se note el salto seleccionado.
+                    let line_end = self.edit.index_at(line, usize::MAX);
+                    self.edit.line_col(line_end).1 + 1
+                };
+                Shape::Rect {
+                    area: self.cell_rect(line, from, to.saturating_sub(from)),
+                    fill: self.style.selection_color,
+                }
+            })
+            .collect()
+    }
+
+    fn caret_shape(&self) -> Shape {
+        let (line, col) = self.edit.line_col(self.edit.caret());
+        let cell = self.cell_rect(line, col, 0);
+        Shape::Rect {
+            area: Rect { width: 2.0, ..cell },
+            fill: self.style.caret_color,
+        }
+    }
+
+    fn display_text(&self) -> Option<String> {
+        if self.edit.is_empty() {
+            return self.placeholder.clone();
+        }
+        if self.password {
+            return Some(self.edit.text().chars().map(|_| PASSWORD_MASK).collect());
+        }
+        Some(self.edit.text().to_string())
+    }
+
+    // --- reacción ---
+
+    fn is_interactive(&self) -> bool {
+        matches!(self.status, AcetateStatus::Ready)
+    }
+
+    /// Tras una edición, un movimiento o recuperar el foco el cursor se muestra y el
+    /// parpadeo reinicia: el tiempo desde el último `Tick` visto ya no cuenta.
+    fn touched(mut self) -> Self {
+        self.caret_visible = true;
+        self.blink_elapsed = Duration::ZERO;
+        self.last_tick = None;
+        self
+    }
+
+    fn next_clipboard_serial(&self) -> u64 {
+        self.clipboard.as_ref().map_or(0, ClipboardRequest::serial) + 1
+    }
+
+    /// Ctrl+C / Ctrl+X. Los campos de contraseña no exponen su valor al portapapeles.
+    fn on_clipboard_key(mut self, cut: bool) -> Option<Self> {
+        if self.password {
+            return None;
+        }
+        let serial = self.next_clipboard_serial();
+        let request = if cut {
+            ClipboardRequest::Cut {
+                serial,
+                text: self.edit.take_selection()?,
+            }
+        } else {
+            ClipboardRequest::Copy {
+                serial,
+                text: self.edit.selected_text()?,
+            }
+        };
+        self.clipboard = Some(request);
+        Some(self.touched())
+    }
+
+    fn on_key(mut self, key: &str) -> Option<Self> {
+        let Modifiers { shift, control, .. } = self.modifiers;
+        let edit = &mut self.edit;
+
+        match key {
+            keys::ARROW_LEFT => edit.move_left(shift, control),
+            keys::ARROW_RIGHT => edit.move_right(shift, control),
+            keys::ARROW_UP if edit.is_multiline() => edit.move_vertical(-1, shift),
+            keys::ARROW_DOWN if edit.is_multiline() => edit.move_vertical(1, shift),
+            keys::HOME => edit.move_home(shift, control),
+            keys::END => edit.move_end(shift, control),
+            keys::BACKSPACE => edit.backspace(control),
+            keys::DELETE => edit.delete(control),
+            keys::ENTER if edit.is_multiline() => edit.insert("\n"),
+            keys::ESCAPE => self.focused = false,
+            key if control && is_char_key(key, 'c') => return self.on_clipboard_key(false),
+            key if control && is_char_key(key, 'x') => return self.on_clipboard_key(true),
+            key if control && is_char_key(key, 'a') => edit.select_all(),
+            key if control && is_char_key(key, 'z') => {
+                if shift {
+                    edit.redo();
+                } else {
+                    edit.undo();
+                }
+            }
+            key if control && is_char_key(key, 'y') => {
+                edit.redo();
+            }
+            _ => return None,
+        }
+        Some(self.touched())
+    }
+
+    fn on_tick(mut self, timestamp: Instant) -> Self {
+        if let Some(last) = self.last_tick {
+            self.blink_elapsed += timestamp.saturating_duration_since(last);
+            if self.blink_elapsed >= CARET_BLINK {
+                self.caret_visible = !self.caret_visible;
+                self.blink_elapsed = Duration::ZERO;
+            }
+        }
+        self.last_tick = Some(timestamp);
+        self
+    }
+}
+
+impl Acetate for TextField {
+    fn id(&self) -> String {
+        self.id.clone()
+    }
+
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn z_index(&self) -> i32 {
+        self.z_index
+    }
+
+    fn area(&self) -> Rect {
+        self.area
+    }
+
+    fn relocate(&self, area: Rect) -> Box<dyn Acetate> {
+        Box::new(Self {
+            area,
+            ..self.clone()
+        })
+    }
+
+    fn subscriptions(&self) -> Vec<EventKind> {
+        vec![
+            EventKind::Tick,
+            EventKind::KeyPressed,
+            EventKind::KeyReleased,
+            EventKind::TextInput,
+            EventKind::Paste,
+            EventKind::MouseDown,
+            EventKind::MouseMoved,
+            EventKind::MouseUp,
+        ]
+    }
+
+    fn react(&self, event: &Event, _scene: &SceneInfo) -> Option<Box<dyn Acetate>> {
+        if !self.is_interactive() {
+            return None;
+        }
+
+        let mut next = self.clone();
+        next.modifiers = self.modifiers.after(event);
+
+        let next = match event {
+            // Los modificadores se siguen aunque no haya foco (Shift+clic, Ctrl+clic).
+            Event::KeyPressed(_) | Event::KeyReleased(_) if next.modifiers != self.modifiers => {
+                next
+            }
+            Event::KeyPressed(key) if self.focused => next.on_key(key)?,
+
+            Event::TextInput(text) if self.focused && !self.modifiers.control => {
+                next.edit.insert(text);
+                next.touched()
+            }
+            Event::Paste(text) if self.focused => {
+                next.edit.insert(text);
+                next.touched()
+            }
+
+            Event::MouseDown { button: MouseButton::Left, x, y } => {
+                if !self.area.contains(*x, *y) {
+                    if !self.focused {
+                        return None;
+                    }
+                    next.focused = false;
+                    next.dragging = false;
+                    next
+                } else {
+                    let index = next.index_at_point(*x, *y);
+                    next.edit.set_caret(index, self.focused && self.modifiers.shift);
+                    next.focused = true;
+                    next.dragging = true;
+                    next.touched()
+                }
+            }
+            Event::MouseMoved(x, y) if self.dragging => {
+                let index = next.index_at_point(*x, *y);
+                if index == self.edit.caret() {
+                    return None;
+                }
+                next.edit.set_caret(index, true);
+                next.touched()
+            }
+            Event::MouseUp { button: MouseButton::Left, .. } if self.dragging => {
+                next.dragging = false;
+                next
+            }
+
+            Event::Tick(tick) if self.focused => next.on_tick(tick.timestamp),
+
+            _ => return None,
+        };
+
+        Some(Box::new(next))
+    }
+
+    fn perceive(&self, scene: &SceneInfo) -> SceneInfo {
+        scene.clone()
+    }
+
+    fn output(&self) -> AcetateIO {
+        AcetateIO {
+            content: Some(self.edit.text().to_string()),
+            focus: self.focused,
+            status: self.status.clone(),
+            clipboard: self.clipboard.clone(),
+            action: None,
+        }
+    }
+
+    fn design(&self) -> AcetateDesign {
+        let mut shapes = self.selection_shapes();
+        if self.focused && self.caret_visible {
+            shapes.push(self.caret_shape());
+        }
+
+        AcetateDesign {
+            area: self.area,
+            background: self.style.background,
+            border: if self.focused {
+                self.style.focus_border
+            } else {
+                self.style.border
+            },
+            border_thickness: self.style.border_thickness,
+            text: self.display_text(),
+            text_color: if self.edit.is_empty() {
+                self.style.placeholder_color
+            } else {
+                self.style.text_color
+            },
+            font_size: self.style.font_size,
+            text_box: Some(self.area.inset(self.style.padding as f32)),
+            shapes,
+            commands: Vec::new(),
+            tooltip: None,
+            ..AcetateDesign::default()
+        }
+    }
+
+    fn clone_box(&self) -> Box<dyn Acetate> {
+        Box::new(self.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::actors::ticker::TickCodomain;
+    use crate::contracts::scene::Scene;
+
+    fn tick(timestamp: Instant) -> Event {
+        Event::Tick(TickCodomain {
+            number: 0,
+            dt: Duration::from_millis(16),
+            timestamp,
+            events: Vec::new(),
+        })
+    }
+
+    fn caret_visible(scene: &Scene) -> bool {
+        // Sin selección, la única forma es el cursor.
+        !scene.acetates[0].design().shapes.is_empty()
+    }
+
+    #[test]
+    fn refocus_restarts_the_caret_blink() {
+        let start = Instant::now();
+        let field = TextField::new("name", Rect::new(0.0, 0.0, 200.0, 30.0)).with_focus(true);
+        let scene = Scene::from(vec![Box::new(field) as Box<dyn Acetate>]).react(&[
+            tick(start),
+            tick(start + Duration::from_millis(100)),
+            Event::KeyPressed(keys::ESCAPE.to_string()),
+            tick(start + Duration::from_secs(5)),
+            Event::MouseDown {
+                button: MouseButton::Left,
+                x: 10,
+                y: 10,
+            },
+        ]);
+        assert!(caret_visible(&scene));
+
+        // El tiempo sin foco no se suma al parpadeo: el cursor sigue visible.
+        let refocused = start + Duration::from_secs(5) + Duration::from_millis(100);
+        let scene = scene.react(&[tick(refocused), tick(refocused + Duration::from_millis(100))]);
+        assert!(caret_visible(&scene));
+        let scene = scene.react(&[tick(refocused + CARET_BLINK)]);
+        assert!(!caret_visible(&scene));
+    }
+}
@@ -0,0 +1,529 @@
+//! Controles booleanos: casilla de verificación e interruptor.
+
+use super::press::PressState;
+use crate::contracts::event::{Action, AnimationFrame, Event, EventKind};
+use crate::contracts::scene::{
+    Acetate, AcetateDesign, AcetateIO, AcetateStatus, EmittedAction, Rect, SceneInfo, Shape,
+    ShapeStroke,
+};
+use crate::core::Color;
+
+/// Duración (en segundos) del recorrido completo de la perilla del interruptor.
+const SWITCH_TRAVEL_SECS: f32 = 0.15;
+/// Hueco entre el control (caja, interruptor o círculo) y su etiqueta.
+pub(crate) const LABEL_GAP: f32 = 8.0;
+
+/// Colores compartidos por casillas, interruptores y grupos de radio.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ToggleStyle {
+    /// Caja o pista en estado apagado.
+    pub off_fill: Color,
+    /// Acento del estado encendido/seleccionado.
+    pub on_fill: Color,
+    pub border: Color,
+    pub focus_border: Color,
+    /// Marca de verificación, perilla o punto central.
+    pub mark: Color,
+    pub text_color: Color,
+    pub font_size: f32,
+}
+
+impl Default for ToggleStyle {
+    fn default() -> Self {
+        Self {
+            off_fill: Color::rgba(0.16, 0.17, 0.22, 1.0),
+            on_fill: Color::rgba(0.22, 0.36, 0.78, 1.0),
+            border: Color::rgba(0.40, 0.42, 0.50, 1.0),
+            focus_border: Color::rgba(0.35, 0.55, 0.95, 1.0),
+            mark: Color::rgba(1.0, 1.0, 1.0, 1.0),
+            text_color: Color::rgba(0.92, 0.93, 0.95, 1.0),
+            font_size: 14.0,
+        }
+    }
+}
+
+impl ToggleStyle {
+    /// Estilo atenuado para controles deshabilitados.
+    pub(crate) fn dimmed(&self) -> Self {
+        let dim = |c: Color| c.with_alpha(c.a * 0.45);
+        Self {
+            off_fill: dim(self.off_fill),
+            on_fill: dim(self.on_fill),
+            border: dim(self.border),
+            focus_border: dim(self.focus_border),
+            mark: dim(self.mark),
+            text_color: dim(self.text_color),
+            font_size: self.font_size,
+        }
+    }
+
+    pub(crate) fn border_for(&self, press: &PressState) -> Color {
+        if press.focused {
+            self.focus_border
+        } else if press.hovered {
+            self.border.mix(self.on_fill, 0.5)
+        } else {
+            self.border
+        }
+    }
+}
+
+/// Estado común de los controles booleanos.
+#[derive(Debug, Clone)]
+struct ToggleCore {
+    id: String,
+    name: String,
+    z_index: i32,
+    area: Rect,
+    label: Option<String>,
+    style: ToggleStyle,
+    status: AcetateStatus,
+    value: bool,
+    press: PressState,
+    action: Option<EmittedAction>,
+}
+
+impl ToggleCore {
+    fn new(id: &str, area: Rect) -> Self {
+        Self {
+            id: id.to_string(),
+            name: id.to_string(),
+            z_index: 0,
+            area,
+            label: None,
+            style: ToggleStyle::default(),
+            status: AcetateStatus::Ready,
+            value: false,
+            press: PressState::default(),
+            action: None,
+        }
+    }
+
+    fn is_disabled(&self) -> bool {
+        !matches!(self.status, AcetateStatus::Ready)
+    }
+
+    fn style(&self) -> ToggleStyle {
+        if self.is_disabled() {
+            self.style.dimmed()
+        } else {
+            self.style.clone()
+        }
+    }
+
+    /// Reacción común: clic o Espacio/Enter alternan el valor y emiten `Action::Changed`.
+    fn react(&self, event: &Event) -> Option<Self> {
+        if self.is_disabled() {
+            return None;
+        }
+        let (press, activated) = self.press.after(event, &self.area);
+        if press == self.press && !activated {
+            return None;
+        }
+        let mut next = self.clone();
+        next.press = press;
+        if activated {
+            next.value = !self.value;
+            next.action = Some(EmittedAction::next(self.action.as_ref(), Action::Changed));
+        }
+        Some(next)
+    }
+
+    fn subscriptions(&self) -> Vec<EventKind> {
+        vec![
+            EventKind::MouseMoved,
+            EventKind::MouseDown,
+            EventKind::MouseUp,
+            EventKind::KeyPressed,
+        ]
+    }
+
+    fn output(&self) -> AcetateIO {
+        AcetateIO {
+            content: Some(self.value.to_string()),
+            focus: self.press.focused,
+            status: self.status.clone(),
+            clipboard: None,
+            action: self.action.clone(),
+        }
+    }
+
+    /// Diseño con la etiqueta a la derecha de un control de ancho `control_width`.
+    fn design(&self, shapes: Vec<Shape>, control_width: f32) -> AcetateDesign {
+        let style = self.style();
+        AcetateDesign {
+            area: self.area,
+            text: self.label.clone(),
+            text_color: style.text_color,
+            font_size: style.font_size,
+            text_box: Some(Rect {
+                x: self.area.x + control_width + LABEL_GAP,
+                width: (self.area.width - control_width - LABEL_GAP).max(0.0),
+                ..self.area
+            }),
+            shapes,
+            ..AcetateDesign::default()
+        }
+    }
+
+    /// Lado del control dentro del área (el resto queda para la etiqueta).
+    fn control_size(&self, max: f32) -> f32 {
+        self.area.height.min(max)
+    }
+
+    fn control_top(&self, size: f32) -> f32 {
+        self.area.y + (self.area.height - size) / 2.0
+    }
+}
+
+/// Casilla de verificación con etiqueta a la derecha.
+#[derive(Debug, Clone)]
+pub struct Checkbox {
+    core: ToggleCore,
+}
+
+impl Checkbox {
+    pub fn new(id: &str, area: Rect) -> Self {
+        Self {
+            core: ToggleCore::new(id, area),
+        }
+    }
+
+    pub fn with_name(mut self, name: &str) -> Self {
+        self.core.name = name.to_string();
+        self
+    }
+
+    pub fn with_z_index(mut self, z_index: i32) -> Self {
+        self.core.z_index = z_index;
+        self
+    }
+
+    pub fn with_label(mut self, label: &str) -> Self {
+        self.core.label = Some(label.to_string());
+        self
+    }
+
+    pub fn with_style(mut self, style: ToggleStyle) -> Self {
+        self.core.style = style;
+        self
+    }
+
+    pub fn with_status(mut self, status: AcetateStatus) -> Self {
+        self.core.status = status;
+        self
+    }
+
+    pub fn with_checked(mut self, checked: bool) -> Self {
+        self.core.value = checked;
+        self
+    }
+
+    pub fn is_checked(&self) -> bool {
+        self.core.value
+    }
+
+    fn control_width(&self) -> f32 {
+        self.core.control_size(18.0)
+    }
+
+    fn shapes(&self) -> Vec<Shape> {
+        let style = self.core.style();
+        let size = self.core.control_size(18.0);
+        let x = self.core.area.x;
+        let y = self.core.control_top(size);
+        let checked = self.core.value;
+
+        let mut shapes = vec![Shape::RoundedRect {
+            area: Rect::new(x, y, size, size),
+            radius: size * 0.2,
+            fill: if checked {
+                style.on_fill
+            } else {
+                style.off_fill
+            },
+            stroke: Some(ShapeStroke {
+                color: style.border_for(&self.core.press),
+                width: 1.5,
+            }),
+        }];
+        if checked {
+            let point = |u: f32, v: f32| (x + u * size, y + v * size);
+            shapes.push(Shape::Polyline {
+                points: vec![point(0.22, 0.52), point(0.42, 0.72), point(0.78, 0.30)],
+                stroke: ShapeStroke {
+                    color: style.mark,
+                    width: (size * 0.12).max(1.5),
+                },
+            });
+        }
+        shapes
+    }
+}
+
+impl Acetate for Checkbox {
+    fn id(&self) -> String {
+        self.core.id.clone()
+    }
+
+    fn name(&self) -> String {
+        self.core.name.clone()
+    }
+
+    fn z_index(&self) -> i32 {
+        self.core.z_index
+    }
+
+    fn area(&self) -> Rect {
+        self.core.area
+    }
+
+    fn relocate(&self, area: Rect) -> Box<dyn Acetate> {
+        let mut next = self.clone();
+        next.core.area = area;
+        Box::new(next)
+    }
+
+    fn subscriptions(&self) -> Vec<EventKind> {
+        self.core.subscriptions()
+    }
+
+    fn react(&self, event: &Event, _scene: &SceneInfo) -> Option<Box<dyn Acetate>> {
+        let core = self.core.react(event)?;
+        Some(Box::new(Self { core }))
+    }
+
+    fn perceive(&self, scene: &SceneInfo) -> SceneInfo {
+        scene.clone()
+    }
+
+    fn output(&self) -> AcetateIO {
+        self.core.output()
+    }
+
+    fn design(&self) -> AcetateDesign {
+        self.core.design(self.shapes(), self.control_width())
+    }
+
+    fn clone_box(&self) -> Box<dyn Acetate> {
+        Box::new(self.clone())
+    }
+}
+
+/// Interruptor encendido/apagado. La perilla se desliza con el `Animator`.
+#[derive(Debug, Clone)]
+pub struct Switch {
+    core: ToggleCore,
+    /// Posición de la perilla: 0 apagado, 1 encendido.
+    knob: f32,
+}
+
+impl Switch {
+    pub fn new(id: &str, area: Rect) -> Self {
+        Self {
+            core: ToggleCore::new(id, area),
+            knob: 0.0,
+        }
+    }
+
+    pub fn with_name(mut self, name: &str) -> Self {
+        self.core.name = name.to_string();
+        self
+    }
+
+    pub fn with_z_index(mut self, z_index: i32) -> Self {
+        self.core.z_index = z_index;
+        self
+    }
+
+    pub fn with_label(mut self, label: &str) -> Self {
+        self.core.label = Some(label.to_string());
+        self
+    }
+
+    pub fn with_style(mut self, style: ToggleStyle) -> Self {
+        self.core.style = style;
+        self
+    }
+
+    pub fn with_status(mut self, status: AcetateStatus) -> Self {
+        self.core.status = status;
+        self
+    }
+
+    pub fn with_checked(mut self, checked: bool) -> Self {
+        self.core.value = checked;
+        self.knob = if checked { 1.0 } else { 0.0 };
+        self
+    }
+
+    pub fn is_checked(&self) -> bool {
+        self.core.value
+    }
+
+    fn knob_target(&self) -> f32 {
+        if self.core.value { 1.0 } else { 0.0 }
+    }
+
+    fn control_width(&self) -> f32 {
+        self.core.control_size(22.0) * 1.8
+    }
+
+    fn shapes(&self) -> Vec<Shape> {
+        let style = self.core.style();
+        let height = self.core.control_size(22.0);
+        let width = height * 1.8;
+        let x = self.core.area.x;
+        let y = self.core.control_top(height);
+        let inset = 2.0;
+        let radius = height / 2.0 - inset;
+        let travel = width - 2.0 * (radius + inset);
+
+        vec![
+            Shape::RoundedRect {
+                area: Rect::new(x, y, width, height),
+                radius: height / 2.0,
+                fill: style.off_fill.mix(style.on_fill, self.knob),
+                stroke: Some(ShapeStroke {
+                    color: style.border_for(&self.core.press),
+                    width: 1.0,
+                }),
+            },
+            Shape::Circle {
+                center: (x + inset + radius + travel * self.knob, y + height / 2.0),
+                radius,
+                fill: style.mark,
+                stroke: None,
+            },
+        ]
+    }
+}
+
+impl Acetate for Switch {
+    fn id(&self) -> String {
+        self.core.id.clone()
+    }
+
+    fn name(&self) -> String {
+        self.core.name.clone()
+    }
+
+    fn z_index(&self) -> i32 {
+        self.core.z_index
+    }
+
+    fn area(&self) -> Rect {
+        self.core.area
+    }
+
+    fn relocate(&self, area: Rect) -> Box<dyn Acetate> {
+        let mut next = self.clone();
+        next.core.area = area;
+        Box::new(next)
+    }
+
+    fn subscriptions(&self) -> Vec<EventKind> {
+        self.core.subscriptions()
+    }
+
+    fn react(&self, event: &Event, _scene: &SceneInfo) -> Option<Box<dyn Acetate>> {
+        let core = self.core.react(event)?;
+        Some(Box::new(Self {
+            core,
+            knob: self.knob,
+        }))
+    }
+
+    /// Acerca la perilla a su destino a velocidad constante.
+    fn animate(&self, frame: &AnimationFrame) -> Option<Box<dyn Acetate>> {
+        let target = self.knob_target();
+        if self.knob == target {
+            return None;
+        }
+        let step = frame.dt / SWITCH_TRAVEL_SECS;
+        let knob = if self.knob < target {
+            (self.knob + step).min(target)
+        } else {
+            (self.knob - step).max(target)
+        };
+        Some(Box::new(Self {
+            knob,
+            ..self.clone()
+        }))
+    }
+
+    fn perceive(&self, scene: &SceneInfo) -> SceneInfo {
+        scene.clone()
+    }
+
+    fn output(&self) -> AcetateIO {
+        self.core.output()
+    }
+
+    fn design(&self) -> AcetateDesign {
+        self.core.design(self.shapes(), self.control_width())
+    }
+
+    fn clone_box(&self) -> Box<dyn Acetate> {
+        Box::new(self.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+
+    use super::*;
+    use crate::acetates::test_support::click;
+    use crate::actors::animator::Animator;
+    use crate::actors::ticker::TickCodomain;
+    use crate::contracts::event::keys;
+    use crate::contracts::scene::Scene;
+
+    fn checked(scene: &Scene) -> Option<String> {
+        scene.acetates[0].output().content
+    }
+
+    #[test]
+    fn checkbox_and_switch_toggle_on_click_and_space() {
+        let area = Rect::new(0.0, 0.0, 160.0, 24.0);
+        let toggles: [Box<dyn Acetate>; 2] = [
+            Box::new(Checkbox::new("terms", area)),
+            Box::new(Switch::new("wifi", area)),
+        ];
+        for toggle in toggles {
+            let scene = Scene::from(vec![toggle]);
+            let scene = scene.react(&click(10, 10));
+            assert_eq!(checked(&scene).as_deref(), Some("true"));
+            let first = scene.acetates[0].output().action.unwrap();
+            assert_eq!(first.action, Action::Changed);
+
+            let scene = scene.react(&[Event::KeyPressed(keys::SPACE.to_string())]);
+            assert_eq!(checked(&scene).as_deref(), Some("false"));
+            assert_ne!(scene.acetates[0].output().action.unwrap().serial, first.serial);
+        }
+    }
+
+    #[test]
+    fn switch_knob_slides_with_animator_steps() {
+        let switch = Switch::new("wifi", Rect::new(0.0, 0.0, 160.0, 20.0));
+        let scene = Scene::from(vec![Box::new(switch) as Box<dyn Acetate>]).react(&click(5, 5));
+        let knob_x = |scene: &Scene| match scene.acetates[0].design().shapes[1] {
+            Shape::Circle { center, .. } => center.0,
+            _ => panic!("la perilla es un círculo"),
+        };
+        // Pista de 36 px con perilla de radio 8 a 2 px del borde: recorre 16 px.
+        assert_eq!(knob_x(&scene), 10.0);
+
+        let tick = Event::Tick(TickCodomain {
+            number: 1,
+            dt: Duration::from_millis(75),
+            timestamp: Instant::now(),
+            events: Vec::new(),
+        });
+        let scene = Animator::step(scene, &tick);
+        assert!((knob_x(&scene) - 18.0).abs() < 1e-3);
+        let scene = Animator::step(Animator::step(scene, &tick), &tick);
+        assert_eq!(knob_x(&scene), 26.0);
+    }
+}
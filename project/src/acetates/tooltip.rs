@@ -0,0 +1,150 @@
+//! Globo de ayuda junto al puntero. Lo crea el runtime, no la escena.
+
+use crate::contracts::event::{Event, EventKind};
+use crate::contracts::scene::{
+    Acetate, AcetateDesign, AcetateIO, AcetateStatus, Decorated, Decoration, LINE_HEIGHT, Rect,
+    SceneInfo, TOOLTIP_Z_INDEX, text_width,
+};
+use crate::core::Color;
+
+/// Distancia del globo al puntero (a la derecha y por debajo del cursor).
+const POINTER_OFFSET: (f32, f32) = (12.0, 20.0);
+const PADDING: f32 = 6.0;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TooltipStyle {
+    pub background: Color,
+    pub border: Color,
+    pub text_color: Color,
+    pub font_size: f32,
+}
+
+impl Default for TooltipStyle {
+    fn default() -> Self {
+        Self {
+            background: Color::rgba(0.08, 0.09, 0.11, 0.95),
+            border: Color::rgba(0.30, 0.32, 0.40, 1.0),
+            text_color: Color::rgba(0.95, 0.95, 0.97, 1.0),
+            font_size: 12.0,
+        }
+    }
+}
+
+/// Tooltip colocado junto al puntero y desplazado para quedar dentro de la ventana.
+/// Su tamaño se estima con la misma métrica monoespaciada que `TextField`.
+#[derive(Debug, Clone)]
+pub struct Tooltip {
+    text: String,
+    area: Rect,
+    style: TooltipStyle,
+}
+
+impl Tooltip {
+    pub fn new(text: &str, pointer: (i32, i32), bounds: (u32, u32), style: TooltipStyle) -> Self {
+        let rows = text.lines().count().max(1);
+        let width = (text_width(text, style.font_size) + 2.0 * PADDING).ceil();
+        let height = (rows as f32 * style.font_size * LINE_HEIGHT + 2.0 * PADDING).ceil();
+        let (max_w, max_h) = (bounds.0 as f32, bounds.1 as f32);
+        let pointer = (pointer.0 as f32, pointer.1 as f32);
+
+        let x = (pointer.0 + POINTER_OFFSET.0).min(max_w - width).max(0.0);
+        let below = pointer.1 + POINTER_OFFSET.1;
+        // Si no cabe debajo, encima del puntero.
+        let y = if below + height <= max_h {
+            below
+        } else {
+            (pointer.1 - height - 4.0).max(0.0)
+        };
+        Self {
+            text: text.to_string(),
+            area: Rect::new(x, y, width, height),
+            style,
+        }
+    }
+}
+
+impl Acetate for Tooltip {
+    fn id(&self) -> String {
+        "__tooltip".to_string()
+    }
+
+    fn name(&self) -> String {
+        "Tooltip".to_string()
+    }
+
+    fn z_index(&self) -> i32 {
+        TOOLTIP_Z_INDEX
+    }
+
+    fn area(&self) -> Rect {
+        self.area
+    }
+
+    fn relocate(&self, area: Rect) -> Box<dyn Acetate> {
+        Box::new(Self {
+            area,
+            ..self.clone()
+        })
+    }
+
+    fn subscriptions(&self) -> Vec<EventKind> {
+        Vec::new()
+    }
+
+    fn react(&self, _event: &Event, _scene: &SceneInfo) -> Option<Box<dyn Acetate>> {
+        None
+    }
+
+    fn perceive(&self, scene: &SceneInfo) -> SceneInfo {
+        scene.clone()
+    }
+
+    fn output(&self) -> AcetateIO {
+        AcetateIO {
+            content: Some(self.text.clone()),
+            focus: false,
+            status: AcetateStatus::Ready,
+            clipboard: None,
+            action: None,
+        }
+    }
+
+    fn design(&self) -> AcetateDesign {
+        AcetateDesign {
+            area: self.area,
+            background: self.style.background,
+            border: self.style.border,
+            border_thickness: 1.0,
+            text: Some(self.text.clone()),
+            text_color: self.style.text_color,
+            font_size: self.style.font_size,
+            text_box: Some(self.area.inset(PADDING)),
+            ..AcetateDesign::default()
+        }
+    }
+
+    fn clone_box(&self) -> Box<dyn Acetate> {
+        Box::new(self.clone())
+    }
+}
+
+/// Añade un tooltip a cualquier acetate sin tocarlo: solo completa `design().tooltip`.
+pub type WithTooltip = Decorated<TooltipText>;
+
+#[derive(Debug, Clone)]
+pub struct TooltipText(pub String);
+
+impl WithTooltip {
+    pub fn new(inner: Box<dyn Acetate>, tooltip: &str) -> Self {
+        Decorated::decorate(inner, TooltipText(tooltip.to_string()))
+    }
+}
+
+impl Decoration for TooltipText {
+    fn design(&self, design: AcetateDesign) -> AcetateDesign {
+        AcetateDesign {
+            tooltip: Some(self.0.clone()),
+            ..design
+        }
+    }
+}
@@ -0,0 +1,697 @@
+//! Lista virtualizada: solo materializa acetates para las filas visibles.
+
+use std::collections::BTreeSet;
+use std::fmt;
+use std::sync::Arc;
+
+use crate::contracts::event::keys::Modifiers;
+use crate::contracts::event::{Action, Event, EventKind, MouseButton, keys};
+use crate::contracts::scene::{
+    Acetate, AcetateDesign, AcetateIO, AcetateStatus, EmittedAction, Rect, SceneInfo, Shape,
+};
+use crate::core::Color;
+
+/// Ancho de la barra de desplazamiento, que queda fuera del área de las filas.
+const SCROLLBAR_WIDTH: f32 = 8.0;
+const MIN_THUMB: f32 = 16.0;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ListStyle {
+    pub background: Color,
+    pub border: Color,
+    pub focus_border: Color,
+    pub selection: Color,
+    pub hover: Color,
+    pub scrollbar: Color,
+    /// Fondo de las filas impares (cebra); `None` las deja todas iguales.
+    pub stripe: Option<Color>,
+    pub text_color: Color,
+    pub font_size: f32,
+}
+
+impl Default for ListStyle {
+    fn default() -> Self {
+        Self {
+            background: Color::rgba(0.10, 0.11, 0.14, 1.0),
+            border: Color::rgba(0.30, 0.32, 0.40, 1.0),
+            focus_border: Color::rgba(0.35, 0.55, 0.95, 1.0),
+            selection: Color::rgba(0.22, 0.36, 0.78, 1.0),
+            hover: Color::rgba(1.0, 1.0, 1.0, 0.06),
+            scrollbar: Color::rgba(1.0, 1.0, 1.0, 0.25),
+            stripe: None,
+            text_color: Color::rgba(0.92, 0.93, 0.95, 1.0),
+            font_size: 14.0,
+        }
+    }
+}
+
+/// Cuántas filas se pueden seleccionar a la vez.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SelectionMode {
+    #[default]
+    Single,
+    /// Ctrl+clic alterna una fila; Shift+clic o Shift+flechas extienden un rango.
+    Multi,
+}
+
+impl SelectionMode {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim() {
+            "single" => Some(SelectionMode::Single),
+            "multi" => Some(SelectionMode::Multi),
+            _ => None,
+        }
+    }
+}
+
+/// Fila visible que se pide al constructor de filas.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ListRow {
+    pub index: usize,
+    /// Área de la fila en la ventana; puede salirse de la lista (se recorta).
+    pub area: Rect,
+    pub selected: bool,
+    pub hovered: bool,
+}
+
+type BuildRow = dyn Fn(&ListRow) -> Box<dyn Acetate> + Send + Sync;
+
+/// Construye el acetate de una fila visible a partir de su índice.
+#[derive(Clone)]
+pub struct RowBuilder(Arc<BuildRow>);
+
+impl RowBuilder {
+    pub fn new(build: impl Fn(&ListRow) -> Box<dyn Acetate> + Send + Sync + 'static) -> Self {
+        Self(Arc::new(build))
+    }
+
+    pub fn build(&self, row: &ListRow) -> Box<dyn Acetate> {
+        (self.0)(row)
+    }
+}
+
+impl fmt::Debug for RowBuilder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("RowBuilder")
+    }
+}
+
+/// Lista de `row_count` filas de alto fijo. Solo las filas que caen dentro del
+/// área según el desplazamiento se construyen (ver `children`), así que el coste
+/// por frame no depende del total. Rueda, barra, flechas, RePág/AvPág e
+/// Inicio/Fin desplazan; clic o teclado cambian la selección y emiten
+/// `Action::Changed`. `output().content` son los índices seleccionados
+/// separados por comas.
+#[derive(Debug, Clone)]
+pub struct VirtualList {
+    id: String,
+    name: String,
+    z_index: i32,
+    area: Rect,
+    row_count: usize,
+    row_height: u32,
+    scroll_offset: f32,
+    row_builder: Option<RowBuilder>,
+    style: ListStyle,
+    status: AcetateStatus,
+    focused: bool,
+    selection_mode: SelectionMode,
+    /// Compartida entre frames: solo se copia cuando cambia.
+    selection: Arc<BTreeSet<usize>>,
+    /// Fila activa del teclado.
+    cursor: Option<usize>,
+    /// Extremo fijo de los rangos con Shift.
+    anchor: Option<usize>,
+    modifiers: Modifiers,
+    hovered_row: Option<usize>,
+    /// Arrastre de la barra: distancia del puntero al inicio del pulgar.
+    thumb_grab: Option<f32>,
+    action: Option<EmittedAction>,
+}
+
+impl VirtualList {
+    pub fn new(id: &str, area: Rect) -> Self {
+        Self {
+            id: id.to_string(),
+            name: id.to_string(),
+            z_index: 0,
+            area,
+            row_count: 0,
+            row_height: 20,
+            scroll_offset: 0.0,
+            row_builder: None,
+            style: ListStyle::default(),
+            status: AcetateStatus::Ready,
+            focused: false,
+            selection_mode: SelectionMode::Single,
+            selection: Arc::default(),
+            cursor: None,
+            anchor: None,
+            modifiers: Modifiers::default(),
+            hovered_row: None,
+            thumb_grab: None,
+            action: None,
+        }
+    }
+
+    pub fn with_name(mut self, name: &str) -> Self {
+        self.name = name.to_string();
+        self
+    }
+
+    pub fn with_z_index(mut self, z_index: i32) -> Self {
+        self.z_index = z_index;
+        self
+    }
+
+    pub fn with_area(mut self, area: Rect) -> Self {
+        self.area = area;
+        self.scroll_offset = self.clamp_offset(self.scroll_offset);
+        self
+    }
+
+    pub fn with_row_count(mut self, row_count: usize) -> Self {
+        self.row_count = row_count;
+        if self.selection.last().is_some_and(|last| *last >= row_count) {
+            self.selection = Arc::new(self.selection.range(..row_count).copied().collect());
+        }
+        self.cursor = self.cursor.filter(|i| *i < row_count);
+        self.anchor = self.anchor.filter(|i| *i < row_count);
+        self.scroll_offset = self.clamp_offset(self.scroll_offset);
+        self
+    }
+
+    pub fn with_row_height(mut self, row_height: u32) -> Self {
+        self.row_height = row_height.max(1);
+        self.scroll_offset = self.clamp_offset(self.scroll_offset);
+        self
+    }
+
+    pub fn with_row_builder(
+        mut self,
+        build: impl Fn(&ListRow) -> Box<dyn Acetate> + Send + Sync + 'static,
+    ) -> Self {
+        self.row_builder = Some(RowBuilder::new(build));
+        self
+    }
+
+    pub fn with_scroll_offset(mut self, offset: f32) -> Self {
+        self.scroll_offset = self.clamp_offset(offset);
+        self
+    }
+
+    pub fn with_selected(mut self, selected: Option<usize>) -> Self {
+        self.set_selection(selected.into_iter().collect(), selected);
+        self
+    }
+
+    pub fn with_selection_mode(mut self, mode: SelectionMode) -> Self {
+        self.selection_mode = mode;
+        if mode == SelectionMode::Single && self.selection.len() > 1 {
+            self.set_selection(self.cursor.into_iter().collect(), self.cursor);
+        }
+        self
+    }
+
+    /// Sustituye la selección sin emitir acción (p. ej. al reordenar los datos).
+    pub fn with_selection(
+        mut self,
+        rows: impl IntoIterator<Item = usize>,
+        cursor: Option<usize>,
+    ) -> Self {
+        self.set_selection(rows.into_iter().collect(), cursor);
+        self
+    }
+
+    pub fn with_style(mut self, style: ListStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    pub fn with_status(mut self, status: AcetateStatus) -> Self {
+        self.status = status;
+        self
+    }
+
+    pub fn row_count(&self) -> usize {
+        self.row_count
+    }
+
+    pub fn scroll_offset(&self) -> f32 {
+        self.scroll_offset
+    }
+
+    /// Fila activa, si está seleccionada.
+    pub fn selected(&self) -> Option<usize> {
+        self.cursor.filter(|row| self.selection.contains(row))
+    }
+
+    pub fn selection(&self) -> &BTreeSet<usize> {
+        &self.selection
+    }
+
+    pub fn is_focused(&self) -> bool {
+        self.focused
+    }
+
+    pub fn modifiers(&self) -> Modifiers {
+        self.modifiers
+    }
+
+    /// Índices de las filas que tocan el área visible.
+    pub fn visible_rows(&self) -> std::ops::Range<usize> {
+        let row_height = self.row_height as f32;
+        let first = (self.scroll_offset / row_height).floor() as usize;
+        let end = ((self.scroll_offset + self.area.height) / row_height).ceil() as usize;
+        first.min(self.row_count)..end.min(self.row_count)
+    }
+
+    fn is_disabled(&self) -> bool {
+        !matches!(self.status, AcetateStatus::Ready)
+    }
+
+    fn content_height(&self) -> f32 {
+        self.row_count as f32 * self.row_height as f32
+    }
+
+    fn max_offset(&self) -> f32 {
+        (self.content_height() - self.area.height).max(0.0)
+    }
+
+    fn clamp_offset(&self, offset: f32) -> f32 {
+        offset.clamp(0.0, self.max_offset())
+    }
+
+    fn overflows(&self) -> bool {
+        self.max_offset() > 0.0
+    }
+
+    /// Ancho disponible para las filas (sin la barra cuando hace falta).
+    fn rows_width(&self) -> f32 {
+        if self.overflows() {
+            (self.area.width - SCROLLBAR_WIDTH).max(0.0)
+        } else {
+            self.area.width
+        }
+    }
+
+    fn row_area(&self, index: usize) -> Rect {
+        let top = index as f32 * self.row_height as f32 - self.scroll_offset;
+        Rect {
+            x: self.area.x,
+            y: self.area.y + top,
+            width: self.rows_width(),
+            height: self.row_height as f32,
+        }
+    }
+
+    fn row_at(&self, x: i32, y: i32) -> Option<usize> {
+        if !self.area.contains(x, y) || x as f32 >= self.area.x + self.rows_width() {
+            return None;
+        }
+        let local = y as f32 - self.area.y + self.scroll_offset;
+        let row = (local / self.row_height as f32) as usize;
+        (row < self.row_count).then_some(row)
+    }
+
+    /// Pulgar de la barra como (y, alto), en coordenadas de ventana.
+    fn thumb(&self) -> Option<(f32, f32)> {
+        if !self.overflows() {
+            return None;
+        }
+        let track = self.area.height;
+        let height = (track * track / self.content_height())
+            .max(MIN_THUMB)
+            .min(track);
+        let y = self.area.y + self.scroll_offset / self.max_offset() * (track - height);
+        Some((y, height))
+    }
+
+    fn in_scrollbar(&self, x: i32, y: i32) -> bool {
+        self.overflows() && self.area.contains(x, y) && x as f32 >= self.area.x + self.rows_width()
+    }
+
+    fn drag_thumb(&mut self, y: i32, grab: f32) {
+        let Some((_, height)) = self.thumb() else {
+            return;
+        };
+        let travel = self.area.height - height;
+        if travel > 0.0 {
+            let top = y as f32 - grab - self.area.y;
+            self.scroll_offset = self.clamp_offset(top / travel * self.max_offset());
+        }
+    }
+
+    /// Desplaza lo justo para que la fila quede dentro del área.
+    fn reveal(&mut self, row: usize) {
+        let top = row as f32 * self.row_height as f32;
+        let bottom = top + self.row_height as f32;
+        let view = self.area.height;
+        if top < self.scroll_offset {
+            self.scroll_offset = top;
+        } else if bottom > self.scroll_offset + view {
+            self.scroll_offset = bottom - view;
+        }
+        self.scroll_offset = self.clamp_offset(self.scroll_offset);
+    }
+
+    fn set_selection(&mut self, rows: BTreeSet<usize>, cursor: Option<usize>) {
+        let rows: BTreeSet<usize> = rows.range(..self.row_count).copied().collect();
+        let rows = match self.selection_mode {
+            SelectionMode::Single => rows.into_iter().take(1).collect(),
+            SelectionMode::Multi => rows,
+        };
+        self.cursor = cursor.filter(|i| *i < self.row_count);
+        self.anchor = self.cursor;
+        self.selection = Arc::new(rows);
+    }
+
+    /// Mueve la fila activa a `row` y actualiza la selección según el modo y los
+    /// modificadores. `toggle` solo aplica al clic (Ctrl+clic).
+    fn select(&mut self, row: usize, toggle: bool) {
+        self.reveal(row);
+        let multi = self.selection_mode == SelectionMode::Multi;
+        let selection: BTreeSet<usize> = match self.anchor {
+            Some(anchor) if multi && self.modifiers.shift => {
+                (anchor.min(row)..=anchor.max(row)).collect()
+            }
+            _ if multi && toggle && self.modifiers.control => {
+                let mut selection = (*self.selection).clone();
+                if !selection.remove(&row) {
+                    selection.insert(row);
+                }
+                self.anchor = Some(row);
+                selection
+            }
+            _ => {
+                self.anchor = Some(row);
+                BTreeSet::from([row])
+            }
+        };
+        self.cursor = Some(row);
+        if selection != *self.selection {
+            self.selection = Arc::new(selection);
+            self.action = Some(EmittedAction::next(self.action.as_ref(), Action::Changed));
+        }
+    }
+
+    /// Fila destino de una tecla de navegación, sin dar la vuelta.
+    fn navigate(&self, key: &str) -> Option<usize> {
+        let last = self.row_count.checked_sub(1)?;
+        let page = (self.area.height / self.row_height as f32).max(1.0) as usize;
+        let current = self.cursor;
+        Some(match key {
+            keys::ARROW_DOWN => current.map_or(0, |i| (i + 1).min(last)),
+            keys::ARROW_UP => current.map_or(0, |i| i.saturating_sub(1)),
+            keys::PAGE_DOWN => current.map_or(0, |i| (i + page).min(last)),
+            keys::PAGE_UP => current.map_or(0, |i| i.saturating_sub(page)),
+            keys::HOME => 0,
+            keys::END => last,
+            _ => return None,
+        })
+    }
+
+    fn row_shape(&self, row: usize, fill: Color) -> Shape {
+        Shape::Rect {
+            area: self.row_area(row),
+            fill,
+        }
+    }
+
+    /// Reacción con el tipo concreto, para acetates que contienen una lista.
+    pub fn reacted(&self, event: &Event) -> Option<Self> {
+        if self.is_disabled() {
+            return None;
+        }
+
+        let mut next = self.clone();
+        next.modifiers = self.modifiers.after(event);
+        match event {
+            Event::MouseMoved(x, y) => {
+                next.hovered_row = self.row_at(*x, *y);
+                if let Some(grab) = self.thumb_grab {
+                    next.drag_thumb(*y, grab);
+                }
+            }
+            Event::MouseDown {
+                button: MouseButton::Left,
+                x,
+                y,
+            } => {
+                next.focused = self.area.contains(*x, *y);
+                if self.in_scrollbar(*x, *y)
+                    && let Some((top, height)) = self.thumb()
+                {
+                    // Clic fuera del pulgar: lo centra en el puntero y empieza a arrastrar.
+                    let inside = (*y as f32) >= top && (*y as f32) < top + height;
+                    let grab = if inside {
+                        *y as f32 - top
+                    } else {
+                        height / 2.0
+                    };
+                    next.drag_thumb(*y, grab);
+                    next.thumb_grab = Some(grab);
+                } else if let Some(row) = self.row_at(*x, *y) {
+                    next.select(row, true);
+                }
+            }
+            Event::MouseUp {
+                button: MouseButton::Left,
+                ..
+            } => next.thumb_grab = None,
+            Event::Scroll { dy, x, y, .. } if self.area.contains(*x, *y) => {
+                next.scroll_offset = self.clamp_offset(self.scroll_offset + dy);
+                next.hovered_row = None;
+            }
+            Event::KeyPressed(key) if self.focused => {
+                if let Some(row) = self.navigate(key) {
+                    next.select(row, false);
+                }
+            }
+            Event::KeyPressed(_) | Event::KeyReleased(_) => {}
+            _ => return None,
+        }
+
+        let unchanged = next.focused == self.focused
+            && next.modifiers == self.modifiers
+            && next.cursor == self.cursor
+            && next.selection == self.selection
+            && next.hovered_row == self.hovered_row
+            && next.scroll_offset == self.scroll_offset
+            && next.thumb_grab == self.thumb_grab;
+        if unchanged {
+            return None;
+        }
+        Some(next)
+    }
+}
+
+impl Acetate for VirtualList {
+    fn id(&self) -> String {
+        self.id.clone()
+    }
+
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn z_index(&self) -> i32 {
+        self.z_index
+    }
+
+    fn area(&self) -> Rect {
+        self.area
+    }
+
+    fn relocate(&self, area: Rect) -> Box<dyn Acetate> {
+        Box::new(self.clone().with_area(area))
+    }
+
+    fn subscriptions(&self) -> Vec<EventKind> {
+        vec![
+            EventKind::MouseMoved,
+            EventKind::MouseDown,
+            EventKind::MouseUp,
+            EventKind::KeyPressed,
+            EventKind::KeyReleased,
+            EventKind::Scroll,
+        ]
+    }
+
+    fn react(&self, event: &Event, _scene: &SceneInfo) -> Option<Box<dyn Acetate>> {
+        self.reacted(event)
+            .map(|next| Box::new(next) as Box<dyn Acetate>)
+    }
+
+    fn perceive(&self, scene: &SceneInfo) -> SceneInfo {
+        scene.clone()
+    }
+
+    fn output(&self) -> AcetateIO {
+        AcetateIO {
+            content: (!self.selection.is_empty()).then(|| {
+                let rows: Vec<String> = self.selection.iter().map(usize::to_string).collect();
+                rows.join(",")
+            }),
+            focus: self.focused,
+            status: self.status.clone(),
+            clipboard: None,
+            action: self.action.clone(),
+        }
+    }
+
+    fn design(&self) -> AcetateDesign {
+        // Fondos bajo las filas: se ven si el acetate de la fila es transparente.
+        let mut shapes: Vec<Shape> = Vec::new();
+        for row in self.visible_rows() {
+            let fill = if self.selection.contains(&row) {
+                Some(self.style.selection)
+            } else if self.hovered_row == Some(row) {
+                Some(self.style.hover)
+            } else {
+                self.style.stripe.filter(|_| row % 2 == 1)
+            };
+            shapes.extend(fill.map(|fill| self.row_shape(row, fill)));
+        }
+
+        if let Some((y, height)) = self.thumb() {
+            shapes.push(Shape::RoundedRect {
+                area: Rect::new(
+                    self.area.x + self.rows_width() + 2.0,
+                    y,
+                    SCROLLBAR_WIDTH - 4.0,
+                    height,
+                ),
+                radius: (SCROLLBAR_WIDTH - 4.0) / 2.0,
+                fill: self.style.scrollbar,
+                stroke: None,
+            });
+        }
+
+        AcetateDesign {
+            area: self.area,
+            background: self.style.background,
+            border: if self.focused {
+                self.style.focus_border
+            } else {
+                self.style.border
+            },
+            border_thickness: 1.0,
+            text_color: self.style.text_color,
+            font_size: self.style.font_size,
+            shapes,
+            ..AcetateDesign::default()
+        }
+    }
+
+    fn children(&self) -> Vec<Box<dyn Acetate>> {
+        let Some(builder) = &self.row_builder else {
+            return Vec::new();
+        };
+        self.visible_rows()
+            .map(|index| {
+                builder.build(&ListRow {
+                    index,
+                    area: self.row_area(index),
+                    selected: self.selection.contains(&index),
+                    hovered: self.hovered_row == Some(index),
+                })
+            })
+            .collect()
+    }
+
+    fn clone_box(&self) -> Box<dyn Acetate> {
+        Box::new(self.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::acetates::Button;
+    use crate::actors::snapshot_builder::SnapshotBuilder;
+    use crate::contracts::scene::Scene;
+
+    #[test]
+    fn only_visible_rows_reach_the_snapshot() {
+        let list = VirtualList::new(
+            "log",
+            Rect::new(0.0, 0.0, 300.0, 200.0),
+        )
+        .with_row_count(100_000)
+        .with_row_height(20)
+        .with_row_builder(|row| {
+            Box::new(Button::new(
+                &format!("line-{}", row.index),
+                row.area,
+            ))
+        });
+        let scene = Scene::from(vec![Box::new(list) as Box<dyn Acetate>]);
+
+        // La lista y sus 10 filas visibles.
+        assert_eq!(SnapshotBuilder::build(scene.clone()).layers.len(), 11);
+
+        // Media fila de desplazamiento deja 11 filas parcialmente visibles.
+        let scene = scene.react(&[Event::Scroll {
+            dx: 0.0,
+            dy: 1_000_010.0,
+            x: 10,
+            y: 10,
+        }]);
+        let rows = scene.acetates[0].children();
+        assert_eq!(rows.len(), 11);
+        assert_eq!(rows[0].id(), "line-50000");
+        assert_eq!(rows[0].area().y, -10.0);
+
+        // End con foco lleva a la última fila y la deja a la vista.
+        let scene = scene.react(&[
+            Event::MouseDown {
+                button: MouseButton::Left,
+                x: 10,
+                y: 50,
+            },
+            Event::KeyPressed(keys::END.to_string()),
+        ]);
+        assert_eq!(scene.acetates[0].output().content.as_deref(), Some("99999"));
+        let rows = scene.acetates[0].children();
+        assert_eq!(rows.last().unwrap().id(), "line-99999");
+        assert_eq!(rows.last().unwrap().area().y, 180.0);
+    }
+
+    #[test]
+    fn scroll_clamps_and_only_visible_rows_are_built() {
+        use std::sync::Arc;
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let built = Arc::new(AtomicUsize::new(0));
+        let counter = built.clone();
+        let list = VirtualList::new("log", Rect::new(0.0, 0.0, 300.0, 200.0))
+            .with_row_count(1_000)
+            .with_row_height(20)
+            .with_row_builder(move |row| {
+                counter.fetch_add(1, Ordering::Relaxed);
+                Box::new(Button::new(&format!("line-{}", row.index), row.area))
+            });
+        let scroll = |dy| Event::Scroll { dx: 0.0, dy, x: 10, y: 10 };
+
+        // Arriba del todo no hay adónde subir.
+        assert!(list.reacted(&scroll(-50.0)).is_none());
+
+        // Pasarse del final deja la última fila al borde inferior.
+        let end = list.reacted(&scroll(1_000_000.0)).expect("scroll");
+        assert_eq!(end.scroll_offset(), 19_800.0);
+        assert_eq!(end.visible_rows(), 990..1_000);
+        assert!(end.reacted(&scroll(20.0)).is_none());
+
+        // De las 1000 filas solo se construyen las 10 visibles.
+        let rows = end.children();
+        assert_eq!(built.load(Ordering::Relaxed), 10);
+        assert_eq!(rows.first().unwrap().id(), "line-990");
+        assert_eq!(rows.last().unwrap().area().y, 180.0);
+
+        // Una lista más corta que su área no se desplaza.
+        let short = list.clone().with_row_count(5);
+        assert!(short.reacted(&scroll(100.0)).is_none());
+        assert_eq!(short.visible_rows(), 0..5);
+    }
+}
@@ -1,15 +1,192 @@
-use super::animate_domain::AnimateDomain;
+use std::collections::HashMap;
+
 use super::animate_codomain::AnimateCodomain;
+use super::animate_domain::AnimateDomain;
 use crate::contracts::event::Event;
+use crate::contracts::scene::{
+    AnimatedProperty, AnimationValue, Spring, SpringAnimation, WithDesign,
+};
 
 /// Actor de animación: dado el estado/escena y un evento (p. ej. Tick),
 /// devuelve una nueva escena (inmutable).
 pub struct Animator;
 
 impl Animator {
-    /// Paso de animación: hoy es passthrough; aquí pondrás keyframes/tweens.
-    pub fn step(scene: AnimateDomain, _event: &Event) -> AnimateCodomain {
-        // TODO: aplicar reglas de animación (keyframes, tween, easing) por acetato
-        scene
+    /// Paso de animación: en cada `Tick` avanza las animaciones y los muelles de la
+    /// escena y los acetatos con animación propia. Los valores se aplican aparte, con `apply`.
+    pub fn step(scene: AnimateDomain, event: &Event) -> AnimateCodomain {
+        let Event::Tick(tick) = event else {
+            return scene;
+        };
+        let frame = tick.animation_frame();
+        let acetates = scene
+            .acetates
+            .iter()
+            .map(|acetate| acetate.animate(&frame).unwrap_or_else(|| acetate.clone()))
+            .collect();
+        let animations = scene
+            .animations
+            .iter()
+            .map(|animation| animation.advance(tick.dt))
+            .collect();
+        let springs = scene
+            .springs
+            .iter()
+            .map(|spring| spring.advance(tick.dt))
+            .collect();
+        AnimateCodomain {
+            acetates,
+            animations,
+            springs,
+            ..scene
+        }
+    }
+
+    /// Lleva `property` de `target` hacia `to` con un muelle. Si ya hay uno en marcha
+    /// para esa propiedad cambia su destino sin perder la velocidad; si no, parte del
+    /// valor actual del acetate.
+    pub fn spring_to(
+        scene: AnimateDomain,
+        target: &str,
+        property: AnimatedProperty,
+        to: impl Into<AnimationValue>,
+        spring: Spring,
+    ) -> AnimateCodomain {
+        let to = to.into();
+        let mut springs = scene.springs.clone();
+        let running = springs
+            .iter_mut()
+            .find(|running| running.target == target && running.property == property);
+        match running {
+            Some(running) => {
+                *running = running.retarget(to).with_spring(spring);
+            }
+            None => {
+                let Some(acetate) = scene.acetates.iter().find(|a| a.id() == target) else {
+                    return scene;
+                };
+                springs.push(
+                    SpringAnimation::new(target, property, property.read(acetate.as_ref()), to)
+                        .with_spring(spring),
+                );
+            }
+        }
+        AnimateCodomain { springs, ..scene }
+    }
+
+    /// Impone a cada acetate el valor actual de sus animaciones y muelles (estos ganan
+    /// si coinciden): la geometría con `relocate` y el resto envolviéndolo en un
+    /// `WithDesign`. Va después del `LayoutEngine` para que el layout no pise lo
+    /// animado; la escena resultante es para pintar, no para guardarla como estado.
+    pub fn apply(scene: AnimateDomain) -> AnimateCodomain {
+        let mut values: HashMap<&str, Vec<(AnimatedProperty, AnimationValue)>> = HashMap::new();
+        for animation in &scene.animations {
+            if let Some(value) = animation.value() {
+                values
+                    .entry(animation.target.as_str())
+                    .or_default()
+                    .push((animation.property, value));
+            }
+        }
+        for spring in &scene.springs {
+            values
+                .entry(spring.target.as_str())
+                .or_default()
+                .push((spring.property, spring.value()));
+        }
+        if values.is_empty() {
+            return scene;
+        }
+
+        let acetates = scene
+            .acetates
+            .iter()
+            .map(|acetate| {
+                let Some(values) = values.get(acetate.id().as_str()) else {
+                    return acetate.clone();
+                };
+                let mut area = acetate.area();
+                let mut visual = Vec::new();
+                for &(property, value) in values {
+                    match (property, value) {
+                        (AnimatedProperty::X, AnimationValue::Number(x)) => area.x = x,
+                        (AnimatedProperty::Y, AnimationValue::Number(y)) => area.y = y,
+                        (AnimatedProperty::Width, AnimationValue::Number(width)) => {
+                            area.width = width.max(0.0)
+                        }
+                        (AnimatedProperty::Height, AnimationValue::Number(height)) => {
+                            area.height = height.max(0.0)
+                        }
+                        _ => visual.push((property, value)),
+                    }
+                }
+                let acetate = if area != acetate.area() {
+                    acetate.relocate(area)
+                } else {
+                    acetate.clone()
+                };
+                if visual.is_empty() {
+                    return acetate;
+                }
+                Box::new(WithDesign::new(acetate, visual)) as _
+            })
+            .collect();
+        AnimateCodomain { acetates, ..scene }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+
+    use super::*;
+    use crate::acetates::Label;
+    use crate::actors::ticker::TickCodomain;
+    use crate::contracts::scene::{Acetate, Animation, Easing, Rect, Scene};
+    use crate::core::Color;
+
+    #[test]
+    fn ticks_advance_animations_and_apply_moves_and_recolors() {
+        let label = Label::new("title", Rect::new(0.0, 10.0, 100.0, 20.0), "Hola");
+        let red = Color::rgba(1.0, 0.0, 0.0, 1.0);
+        let blue = Color::rgba(0.0, 0.0, 1.0, 1.0);
+        let scene = Scene {
+            animations: vec![
+                Animation::tween(
+                    "title",
+                    AnimatedProperty::X,
+                    0.0,
+                    200.0,
+                    Duration::from_millis(400),
+                )
+                .with_easing(Easing::Linear),
+                Animation::tween(
+                    "title",
+                    AnimatedProperty::Fill,
+                    red,
+                    blue,
+                    Duration::from_millis(400),
+                )
+                .with_easing(Easing::Linear),
+            ],
+            ..Scene::from(vec![Box::new(label) as Box<dyn Acetate>])
+        };
+
+        let tick = Event::Tick(TickCodomain {
+            number: 1,
+            dt: Duration::from_millis(100),
+            timestamp: Instant::now(),
+            events: Vec::new(),
+        });
+        let scene = Animator::step(Animator::step(scene, &tick), &tick);
+        assert_eq!(scene.animations[0].elapsed, Duration::from_millis(200));
+
+        let painted = Animator::apply(scene.clone());
+        let title = &painted.acetates[0];
+        assert_eq!(title.area(), Rect::new(100.0, 10.0, 100.0, 20.0));
+        assert_eq!(title.design().background, red.mix(blue, 0.5));
+        assert_eq!(title.id(), "title");
+        // El estado sigue sin animar: `apply` solo produce la escena a pintar.
+        assert_eq!(scene.acetates[0].area().x, 0.0);
     }
 }
@@ -4,6 +4,7 @@ pub type Codomain = self::animate_codomain::AnimateCodomain;
 
 pub mod animate_domain;
 pub mod animate_codomain;
+#[allow(clippy::module_inception)]
 pub mod animator;
 
 // Exports ergonómicos
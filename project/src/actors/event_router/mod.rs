@@ -4,6 +4,7 @@ pub type Codomain = self::interpret_codomain::InternalEvent;
 
 pub mod interpret_domain;
 pub mod interpret_codomain;
+#[allow(clippy::module_inception)]
 pub mod event_router;
 
 // Exports ergonómicos
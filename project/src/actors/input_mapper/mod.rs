@@ -3,6 +3,7 @@ pub type Domain   = self::translate_domain::TranslateDomain; // = InternalEvent
 pub type Codomain = crate::contracts::event::Event;
 
 pub mod translate_domain;
+#[allow(clippy::module_inception)]
 pub mod input_mapper;
 
 // Exports públicos ergonómicos
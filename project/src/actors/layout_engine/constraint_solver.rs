@@ -0,0 +1,305 @@
+use std::collections::HashMap;
+use std::fmt;
+
+use crate::contracts::scene::{Anchor, AnchorTarget, Constraints, Edge, Length, Rect, SceneInfo};
+
+/// Por qué no se pueden resolver las anclas de una escena.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConstraintError {
+    /// Hay anclas para un id que no está en la escena.
+    UnknownAcetate(String),
+    /// `acetate` se ancla a un id que no está en la escena.
+    UnknownTarget { acetate: String, target: String },
+    /// Las anclas se refieren unas a otras en círculo; el primero se repite al final.
+    Cycle(Vec<String>),
+    /// Más de dos anclas (contando el tamaño) en un mismo eje.
+    Overconstrained { acetate: String, axis: &'static str },
+}
+
+impl fmt::Display for ConstraintError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConstraintError::UnknownAcetate(id) => {
+                write!(f, "constraints for unknown acetate '{id}'")
+            }
+            ConstraintError::UnknownTarget { acetate, target } => {
+                write!(f, "acetate '{acetate}' is anchored to unknown '{target}'")
+            }
+            ConstraintError::Cycle(path) => {
+                write!(f, "constraint cycle: {}", path.join(" -> "))
+            }
+            ConstraintError::Overconstrained { acetate, axis } => write!(
+                f,
+                "acetate '{acetate}' has more than two anchors on the {axis} axis"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ConstraintError {}
+
+/// Resuelve `Scene::constraints` sobre los stubs de `SceneInfo`: cada acetate anclado
+/// se calcula después de aquellos a los que se ancla, y los no anclados se miden por
+/// su área actual. El tamaño que no fijan las anclas es el del stub. Las longitudes
+/// (`Length`) se resuelven contra el tamaño de la escena.
+pub struct ConstraintSolver<'a> {
+    info: &'a SceneInfo,
+    constraints: &'a HashMap<String, Constraints>,
+    solved: HashMap<String, Rect>,
+    visiting: Vec<String>,
+}
+
+impl<'a> ConstraintSolver<'a> {
+    pub fn solve(
+        info: &'a SceneInfo,
+        constraints: &'a HashMap<String, Constraints>,
+    ) -> Result<HashMap<String, Rect>, ConstraintError> {
+        let mut solver = Self {
+            info,
+            constraints,
+            solved: HashMap::new(),
+            visiting: Vec::new(),
+        };
+        // Orden estable para que el error reportado no dependa del hash.
+        let mut ids: Vec<&String> = constraints.keys().collect();
+        ids.sort();
+        for id in ids {
+            solver.resolve(id)?;
+        }
+        Ok(solver.solved)
+    }
+
+    fn stub_area(&self, id: &str) -> Option<&Rect> {
+        self.info
+            .stubs
+            .iter()
+            .find(|stub| stub.id == id)
+            .map(|stub| &stub.area)
+    }
+
+    fn resolve(&mut self, id: &str) -> Result<Rect, ConstraintError> {
+        if let Some(area) = self.solved.get(id) {
+            return Ok(*area);
+        }
+        let Some(constraints) = self.constraints.get(id) else {
+            return self
+                .stub_area(id)
+                .cloned()
+                .ok_or_else(|| ConstraintError::UnknownAcetate(id.to_string()));
+        };
+        if let Some(start) = self.visiting.iter().position(|visiting| visiting == id) {
+            let mut path = self.visiting[start..].to_vec();
+            path.push(id.to_string());
+            return Err(ConstraintError::Cycle(path));
+        }
+        let Some(own) = self.stub_area(id).cloned() else {
+            return Err(ConstraintError::UnknownAcetate(id.to_string()));
+        };
+
+        let (width, height) = (self.info.width as f32, self.info.height as f32);
+        let sizes = (
+            constraints.width.map(|length| self.length(length, width)),
+            constraints.height.map(|length| self.length(length, height)),
+        );
+        self.visiting.push(id.to_string());
+        let mut value = |anchor: &Option<Anchor>, reference| {
+            anchor
+                .as_ref()
+                .map(|anchor| self.anchor_value(id, anchor, reference))
+                .transpose()
+        };
+        let horizontal = (
+            value(&constraints.left, width)?,
+            value(&constraints.right, width)?,
+            value(&constraints.center_x, width)?,
+            sizes.0,
+        );
+        let vertical = (
+            value(&constraints.top, height)?,
+            value(&constraints.bottom, height)?,
+            value(&constraints.center_y, height)?,
+            sizes.1,
+        );
+        self.visiting.pop();
+
+        let (x, width) = axis(id, "horizontal", horizontal, own.x, own.width)?;
+        let (y, height) = axis(id, "vertical", vertical, own.y, own.height)?;
+        // Redondea los bordes, como el layout, para que anclas contiguas no dejen huecos.
+        let area = Rect::new(x, y, width, height).round();
+        self.solved.insert(id.to_string(), area);
+        Ok(area)
+    }
+
+    fn length(&self, length: Length, reference: f32) -> f32 {
+        let scene = (self.info.width as f32, self.info.height as f32);
+        length.resolve(reference, scene)
+    }
+
+    /// `reference` es el eje de la escena del borde restringido (para los `%`).
+    fn anchor_value(
+        &mut self,
+        id: &str,
+        anchor: &Anchor,
+        reference: f32,
+    ) -> Result<f32, ConstraintError> {
+        let (target, edge, offset) = match anchor {
+            Anchor::Fixed(value) => return Ok(self.length(*value, reference)),
+            Anchor::To {
+                target,
+                edge,
+                offset,
+            } => (target, *edge, *offset),
+        };
+        let area = match target {
+            AnchorTarget::Scene => Rect::new(
+                0.0,
+                0.0,
+                self.info.width as f32,
+                self.info.height as f32,
+            ),
+            AnchorTarget::Acetate(target) => {
+                if self.stub_area(target).is_none() {
+                    return Err(ConstraintError::UnknownTarget {
+                        acetate: id.to_string(),
+                        target: target.clone(),
+                    });
+                }
+                self.resolve(target)?
+            }
+        };
+        Ok(edge_value(&area, edge) + self.length(offset, reference))
+    }
+}
+
+fn edge_value(area: &Rect, edge: Edge) -> f32 {
+    match edge {
+        Edge::Left => area.x,
+        Edge::Right => area.right(),
+        Edge::Top => area.y,
+        Edge::Bottom => area.bottom(),
+        Edge::CenterX => area.center().0,
+        Edge::CenterY => area.center().1,
+        Edge::Width => area.width,
+        Edge::Height => area.height,
+    }
+}
+
+/// Posición y tamaño en un eje a partir de (inicio, fin, centro, tamaño).
+fn axis(
+    id: &str,
+    name: &'static str,
+    (start, end, center, size): (Option<f32>, Option<f32>, Option<f32>, Option<f32>),
+    position: f32,
+    declared: f32,
+) -> Result<(f32, f32), ConstraintError> {
+    let anchors = [start, end, center, size].iter().flatten().count();
+    if anchors > 2 {
+        return Err(ConstraintError::Overconstrained {
+            acetate: id.to_string(),
+            axis: name,
+        });
+    }
+    Ok(match (start, end, center) {
+        (Some(start), Some(end), _) => (start, end - start),
+        (Some(start), _, Some(center)) => (start, 2.0 * (center - start)),
+        (_, Some(end), Some(center)) => (2.0 * center - end, 2.0 * (end - center)),
+        _ => {
+            let size = size.unwrap_or(declared);
+            match (start, end, center) {
+                (Some(start), _, _) => (start, size),
+                (_, Some(end), _) => (end - size, size),
+                (_, _, Some(center)) => (center - size / 2.0, size),
+                _ => (position, size),
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::acetates::Label;
+    use crate::actors::layout_engine::LayoutEngine;
+    use crate::contracts::scene::{Acetate, Scene};
+
+    fn label(id: &str, width: u32, height: u32) -> Box<dyn Acetate> {
+        let area = Rect::new(0.0, 0.0, width as f32, height as f32);
+        Box::new(Label::new(id, area, id))
+    }
+
+    fn anchor(value: &str, own: Edge) -> Anchor {
+        Anchor::parse(value, own).expect("valid anchor")
+    }
+
+    #[test]
+    fn anchors_follow_scene_edges_and_other_acetates() {
+        let mut constraints = HashMap::new();
+        constraints.insert(
+            "sidebar".to_string(),
+            Constraints::default()
+                .with_top(anchor("scene", Edge::Top))
+                .with_bottom(anchor("status.top - 8", Edge::Bottom)),
+        );
+        constraints.insert(
+            "content".to_string(),
+            Constraints::default()
+                .with_left(anchor("sidebar.right + 8", Edge::Left))
+                .with_right(anchor("scene.right - 8", Edge::Right))
+                .with_top(anchor("sidebar", Edge::Top)),
+        );
+        constraints.insert(
+            "status".to_string(),
+            Constraints::default()
+                .with_bottom(anchor("scene.bottom - 16", Edge::Bottom))
+                .with_center_x(anchor("scene", Edge::CenterX)),
+        );
+        let scene = Scene {
+            width: 800,
+            height: 600,
+            constraints,
+            ..Scene::from(vec![
+                label("content", 100, 300),
+                label("sidebar", 200, 100),
+                label("status", 300, 24),
+            ])
+        };
+        let scene = LayoutEngine::apply(scene).expect("anchors solve");
+        let area = |index: usize| scene.acetates[index].area();
+        assert_eq!(
+            area(2),
+            Rect::new(250.0, 560.0, 300.0, 24.0)
+        );
+        assert_eq!(
+            area(1),
+            Rect::new(0.0, 0.0, 200.0, 552.0)
+        );
+        assert_eq!(
+            area(0),
+            Rect::new(208.0, 0.0, 584.0, 300.0)
+        );
+
+        let mut cyclic = scene.constraints.clone();
+        cyclic.insert(
+            "status".to_string(),
+            Constraints::default().with_top(anchor("content.bottom", Edge::Top)),
+        );
+        let scene = Scene {
+            constraints: cyclic,
+            ..scene
+        };
+        assert_eq!(
+            LayoutEngine::constrain(&scene),
+            Err(ConstraintError::Cycle(vec![
+                "content".to_string(),
+                "sidebar".to_string(),
+                "status".to_string(),
+                "content".to_string(),
+            ]))
+        );
+        // `apply` no se traga el error: quien la llama decide qué pintar.
+        assert!(matches!(
+            LayoutEngine::apply(scene),
+            Err(ConstraintError::Cycle(_))
+        ));
+    }
+}
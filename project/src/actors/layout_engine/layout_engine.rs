@@ -0,0 +1,460 @@
+use std::collections::{HashMap, HashSet};
+
+use super::constraint_solver::{ConstraintError, ConstraintSolver};
+use super::{Codomain, Domain};
+use crate::contracts::scene::{Align, Justify, LayoutKind, LayoutNode, Metrics, Rect, Track};
+
+/// Actor de layout: aplica los `Scene::breakpoints` que coinciden con el tamaño de la
+/// escena, resuelve `Scene::layout` y `Scene::sizes` contra ese tamaño y reubica los
+/// acetates que nombra; después resuelve `Scene::constraints`, que pueden anclarse a
+/// lo ya colocado. El runtime lo aplica entre `App::frame` y el `SnapshotBuilder` (ver
+/// `LayoutPass`). Es idempotente.
+pub struct LayoutEngine;
+
+impl LayoutEngine {
+    /// `arrange` y después `anchor`. Si las anclas no tienen solución, la app decide
+    /// qué pintar; `arrange` sola da la escena sin anclar.
+    pub fn apply(scene: Domain) -> Result<Codomain, ConstraintError> {
+        Self::anchor(&Self::arrange(scene))
+    }
+
+    /// Breakpoints activos, `Scene::layout` y `Scene::sizes`; no puede fallar.
+    pub fn arrange(scene: Domain) -> Codomain {
+        let scene = Self::respond(scene);
+        let scene = match &scene.layout {
+            Some(layout) => {
+                let areas = Self::solve(layout, scene.width, scene.height, &scene.metrics);
+                relocate(scene, &areas)
+            }
+            None => scene,
+        };
+        Self::restyle(scene)
+    }
+
+    /// Impone a cada acetate de `Scene::sizes` su borde y su fuente resueltos contra el
+    /// tamaño de la escena (ver `Acetate::restyle`).
+    pub fn restyle(scene: Domain) -> Codomain {
+        if scene.sizes.is_empty() {
+            return scene;
+        }
+        let viewport = (scene.width as f32, scene.height as f32);
+        let acetates = scene
+            .acetates
+            .iter()
+            .map(|acetate| {
+                let Some(sizes) = scene.sizes.get(&acetate.id()) else {
+                    return acetate.clone();
+                };
+                let (border_thickness, font_size) = sizes.resolve(viewport);
+                let design = acetate.design();
+                let same = border_thickness.is_none_or(|b| b == design.border_thickness)
+                    && font_size.is_none_or(|f| f == design.font_size);
+                if same {
+                    acetate.clone()
+                } else {
+                    acetate.restyle(border_thickness, font_size)
+                }
+            })
+            .collect();
+        Codomain { acetates, ..scene }
+    }
+
+    /// Reubica los acetates anclados (ver `constrain`).
+    pub fn anchor(scene: &Domain) -> Result<Codomain, ConstraintError> {
+        if scene.constraints.is_empty() {
+            return Ok(scene.clone());
+        }
+        let areas = Self::constrain(scene)?;
+        Ok(relocate(scene.clone(), &areas))
+    }
+
+    /// La escena con sus breakpoints activos aplicados, en orden.
+    pub fn respond(scene: Domain) -> Codomain {
+        let (width, height) = (scene.width, scene.height);
+        let breakpoints = scene.breakpoints.clone();
+        breakpoints
+            .iter()
+            .filter(|breakpoint| breakpoint.matches(width, height))
+            .fold(scene, |scene, breakpoint| breakpoint.apply(scene))
+    }
+
+    /// Área de cada acetate anclado, o por qué las anclas no tienen solución.
+    pub fn constrain(scene: &Domain) -> Result<HashMap<String, Rect>, ConstraintError> {
+        ConstraintSolver::solve(&scene.info(), &scene.constraints)
+    }
+
+    /// Área de cada acetate del layout en una escena de `width × height`.
+    pub fn solve(
+        layout: &LayoutNode,
+        width: u32,
+        height: u32,
+        metrics: &Metrics,
+    ) -> HashMap<String, Rect> {
+        let root = Rect {
+            x: 0.0,
+            y: 0.0,
+            width: width as f32,
+            height: height as f32,
+        }
+        .inset(metrics.margin as f32);
+        let mut areas = HashMap::new();
+        place(layout, root, metrics.spacing as f32, &mut areas);
+        areas
+    }
+}
+
+fn relocate(scene: Domain, areas: &HashMap<String, Rect>) -> Codomain {
+    let acetates = scene
+        .acetates
+        .iter()
+        .map(|acetate| match areas.get(&acetate.id()) {
+            Some(area) if *area != acetate.area() => acetate.relocate(*area),
+            _ => acetate.clone(),
+        })
+        .collect();
+    Codomain { acetates, ..scene }
+}
+
+fn place(node: &LayoutNode, frame: Rect, spacing: f32, areas: &mut HashMap<String, Rect>) {
+    let inner = frame.inset(node.padding);
+    match &node.kind {
+        LayoutKind::Acetate(id) => {
+            // Redondea los bordes (no el tamaño) para que hermanos contiguos no dejen huecos.
+            areas.insert(id.clone(), frame.round());
+        }
+        LayoutKind::Row => place_flex(node, inner, true, spacing, areas),
+        LayoutKind::Column => place_flex(node, inner, false, spacing, areas),
+        LayoutKind::Stack => {
+            for child in &node.children {
+                let cell = aligned(node.align, inner, natural(child, spacing));
+                place(child, cell, spacing, areas);
+            }
+        }
+        LayoutKind::Grid => place_grid(node, inner, spacing, areas),
+    }
+}
+
+/// Tamaño que pediría el nodo sin restricciones.
+fn natural(node: &LayoutNode, spacing: f32) -> (f32, f32) {
+    if let Some(size) = node.size {
+        return size;
+    }
+    let gap = node.gap.unwrap_or(spacing);
+    let gaps = gap * node.children.len().saturating_sub(1) as f32;
+    let sizes = node.children.iter().map(|child| natural(child, spacing));
+    let pad = 2.0 * node.padding;
+    let (width, height) = match node.kind {
+        LayoutKind::Acetate(_) => return (0.0, 0.0),
+        LayoutKind::Row => {
+            let (w, h) = sizes.fold((0.0f32, 0.0f32), |(w, h), (cw, ch)| (w + cw, h.max(ch)));
+            (w + gaps, h)
+        }
+        LayoutKind::Column => {
+            let (w, h) = sizes.fold((0.0f32, 0.0f32), |(w, h), (cw, ch)| (w.max(cw), h + ch));
+            (w, h + gaps)
+        }
+        LayoutKind::Stack => {
+            sizes.fold((0.0f32, 0.0f32), |(w, h), (cw, ch)| (w.max(cw), h.max(ch)))
+        }
+        LayoutKind::Grid => {
+            let fixed = |tracks: &[Track]| -> f32 {
+                let sum: f32 = tracks
+                    .iter()
+                    .map(|track| match track {
+                        Track::Fixed(px) => *px,
+                        Track::Fraction(_) => 0.0,
+                    })
+                    .sum();
+                sum + gap * tracks.len().saturating_sub(1) as f32
+            };
+            (fixed(&node.columns), fixed(&node.rows))
+        }
+    };
+    (width + pad, height + pad)
+}
+
+/// Posición y tamaño en un eje según la alineación.
+fn align_axis(align: Align, available: f32, natural: f32) -> (f32, f32) {
+    let size = natural.min(available);
+    match align {
+        Align::Stretch => (0.0, available),
+        Align::Start => (0.0, size),
+        Align::Center => ((available - size) / 2.0, size),
+        Align::End => (available - size, size),
+    }
+}
+
+fn aligned(align: Align, area: Rect, (width, height): (f32, f32)) -> Rect {
+    let (dx, width) = align_axis(align, area.width, width);
+    let (dy, height) = align_axis(align, area.height, height);
+    Rect {
+        x: area.x + dx,
+        y: area.y + dy,
+        width,
+        height,
+    }
+}
+
+fn place_flex(
+    node: &LayoutNode,
+    inner: Rect,
+    horizontal: bool,
+    spacing: f32,
+    areas: &mut HashMap<String, Rect>,
+) {
+    let children = &node.children;
+    if children.is_empty() {
+        return;
+    }
+    let count = children.len() as f32;
+    let gap = node.gap.unwrap_or(spacing);
+    let (main, cross) = if horizontal {
+        (inner.width, inner.height)
+    } else {
+        (inner.height, inner.width)
+    };
+    let naturals: Vec<(f32, f32)> = children.iter().map(|c| natural(c, spacing)).collect();
+    let along = |(w, h): (f32, f32)| if horizontal { (w, h) } else { (h, w) };
+
+    let mut sizes: Vec<f32> = children
+        .iter()
+        .zip(&naturals)
+        .map(|(child, natural)| child.basis.unwrap_or(along(*natural).0))
+        .collect();
+    let gaps = gap * (count - 1.0);
+    let free = main - gaps - sizes.iter().sum::<f32>();
+    if free > 0.0 {
+        let grow: f32 = children.iter().map(|c| c.grow).sum();
+        if grow > 0.0 {
+            for (size, child) in sizes.iter_mut().zip(children) {
+                *size += free * child.grow / grow;
+            }
+        }
+    } else if free < 0.0 {
+        // Ceder en proporción a shrink × tamaño, como en flexbox.
+        let weight: f32 = children.iter().zip(&sizes).map(|(c, s)| c.shrink * s).sum();
+        if weight > 0.0 {
+            for (size, child) in sizes.iter_mut().zip(children) {
+                *size = (*size + free * child.shrink * *size / weight).max(0.0);
+            }
+        }
+    }
+
+    let leftover = (main - gaps - sizes.iter().sum::<f32>()).max(0.0);
+    let (mut offset, between) = match node.justify {
+        Justify::Start => (0.0, 0.0),
+        Justify::Center => (leftover / 2.0, 0.0),
+        Justify::End => (leftover, 0.0),
+        Justify::SpaceBetween if count > 1.0 => (0.0, leftover / (count - 1.0)),
+        Justify::SpaceBetween => (0.0, 0.0),
+        Justify::SpaceAround => (leftover / (2.0 * count), leftover / count),
+        Justify::SpaceEvenly => (leftover / (count + 1.0), leftover / (count + 1.0)),
+    };
+
+    for ((child, natural), size) in children.iter().zip(&naturals).zip(sizes) {
+        let (cross_offset, cross_size) = align_axis(node.align, cross, along(*natural).1);
+        let frame = if horizontal {
+            Rect {
+                x: inner.x + offset,
+                y: inner.y + cross_offset,
+                width: size,
+                height: cross_size,
+            }
+        } else {
+            Rect {
+                x: inner.x + cross_offset,
+                y: inner.y + offset,
+                width: cross_size,
+                height: size,
+            }
+        };
+        place(child, frame, spacing, areas);
+        offset += size + gap + between;
+    }
+}
+
+/// Tamaño de cada pista: las fijas primero y el resto repartido por fracciones.
+fn resolve_tracks(tracks: &[Track], available: f32, gap: f32) -> Vec<f32> {
+    let gaps = gap * tracks.len().saturating_sub(1) as f32;
+    let (fixed, fractions) = tracks
+        .iter()
+        .fold((0.0, 0.0), |(px, fr), track| match track {
+            Track::Fixed(value) => (px + value, fr),
+            Track::Fraction(value) => (px, fr + value),
+        });
+    let free = (available - gaps - fixed).max(0.0);
+    tracks
+        .iter()
+        .map(|track| match track {
+            Track::Fixed(px) => *px,
+            Track::Fraction(fr) if fractions > 0.0 => free * fr / fractions,
+            Track::Fraction(_) => 0.0,
+        })
+        .collect()
+}
+
+fn place_grid(node: &LayoutNode, inner: Rect, spacing: f32, areas: &mut HashMap<String, Rect>) {
+    let gap = node.gap.unwrap_or(spacing);
+    let columns = if node.columns.is_empty() {
+        vec![Track::Fraction(1.0)]
+    } else {
+        node.columns.clone()
+    };
+    let column_count = columns.len();
+
+    // Colocación: celdas explícitas tal cual; el resto, en la siguiente libre por filas.
+    let mut occupied: HashSet<(usize, usize)> = HashSet::new();
+    let mut cursor = 0;
+    let mut cells = Vec::with_capacity(node.children.len());
+    for child in &node.children {
+        let span_columns = child.span.0.min(column_count);
+        let span_rows = child.span.1;
+        let (column, row) = match child.cell {
+            Some((column, row)) => (column.min(column_count - span_columns), row),
+            None => loop {
+                let (column, row) = (cursor % column_count, cursor / column_count);
+                let fits = column + span_columns <= column_count
+                    && (0..span_columns).all(|dc| {
+                        (0..span_rows).all(|dr| !occupied.contains(&(column + dc, row + dr)))
+                    });
+                if fits {
+                    break (column, row);
+                }
+                cursor += 1;
+            },
+        };
+        for dc in 0..span_columns {
+            for dr in 0..span_rows {
+                occupied.insert((column + dc, row + dr));
+            }
+        }
+        cells.push((column, row, span_columns, span_rows));
+    }
+
+    let row_count = cells
+        .iter()
+        .map(|(_, row, _, span)| row + span)
+        .max()
+        .unwrap_or(0);
+    let mut rows = node.rows.clone();
+    if rows.len() < row_count {
+        rows.resize(row_count, Track::Fraction(1.0));
+    }
+
+    let column_sizes = resolve_tracks(&columns, inner.width, gap);
+    let row_sizes = resolve_tracks(&rows, inner.height, gap);
+    let starts = |sizes: &[f32]| -> Vec<f32> {
+        sizes
+            .iter()
+            .scan(0.0, |offset, size| {
+                let start = *offset;
+                *offset += size + gap;
+                Some(start)
+            })
+            .collect()
+    };
+    let (column_starts, row_starts) = (starts(&column_sizes), starts(&row_sizes));
+    let extent = |sizes: &[f32], from: usize, span: usize| -> f32 {
+        let end = (from + span).min(sizes.len());
+        sizes[from.min(end)..end].iter().sum::<f32>()
+            + gap * (end - from.min(end)).saturating_sub(1) as f32
+    };
+
+    for (child, (column, row, span_columns, span_rows)) in node.children.iter().zip(cells) {
+        let cell = Rect {
+            x: inner.x + column_starts[column],
+            y: inner.y + row_starts[row],
+            width: extent(&column_sizes, column, span_columns),
+            height: extent(&row_sizes, row, span_rows),
+        };
+        place(
+            child,
+            aligned(node.align, cell, natural(child, spacing)),
+            spacing,
+            areas,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::acetates::test_support::rect;
+
+    fn metrics(margin: u32, spacing: u32) -> Metrics {
+        Metrics {
+            scale: 1.0,
+            margin,
+            spacing,
+        }
+    }
+
+    #[test]
+    fn column_with_growing_row_adapts_to_window_size() {
+        let layout = LayoutNode::column([
+            LayoutNode::acetate("header").with_size(0.0, 40.0),
+            LayoutNode::row([
+                LayoutNode::acetate("sidebar").with_size(200.0, 0.0),
+                LayoutNode::acetate("content").with_grow(1.0),
+            ])
+            .with_grow(1.0),
+            LayoutNode::grid(
+                [Track::Fixed(100.0), Track::Fraction(1.0)],
+                [],
+                [
+                    LayoutNode::acetate("ok"),
+                    LayoutNode::acetate("cancel"),
+                    LayoutNode::acetate("status").with_span(2, 1),
+                ],
+            )
+            .with_size(0.0, 70.0),
+        ]);
+
+        // Margen 10 y espaciado 10 de `Metrics`: el contenido mide 780 × 580.
+        let areas = LayoutEngine::solve(&layout, 800, 600, &metrics(10, 10));
+        assert_eq!(areas["header"], rect(10, 10, 780, 40));
+        assert_eq!(areas["sidebar"], rect(10, 60, 200, 450));
+        assert_eq!(areas["content"], rect(220, 60, 570, 450));
+        assert_eq!(areas["ok"], rect(10, 520, 100, 30));
+        assert_eq!(areas["cancel"], rect(120, 520, 670, 30));
+        assert_eq!(areas["status"], rect(10, 560, 780, 30));
+
+        // Sin coordenadas a mano: al estrechar la ventana solo cambia lo que crece.
+        let areas = LayoutEngine::solve(&layout, 400, 600, &metrics(10, 10));
+        assert_eq!(areas["sidebar"], rect(10, 60, 200, 450));
+        assert_eq!(areas["content"], rect(220, 60, 170, 450));
+    }
+
+    #[test]
+    fn restyle_follows_the_scene_size_without_nesting() {
+        use crate::acetates::Button;
+        use crate::contracts::scene::{Acetate, Length, Scene, Sizes};
+
+        let sizes = Sizes {
+            border_thickness: Some(Length::parse("1vw").unwrap()),
+            font_size: Some(Length::parse("10%").unwrap()),
+        };
+        let scene = |width, height| Scene {
+            width,
+            height,
+            sizes: [("ok".to_string(), sizes)].into(),
+            ..Scene::from(vec![
+                Box::new(Button::new("ok", rect(0, 0, 10, 10))) as Box<dyn Acetate>
+            ])
+        };
+
+        let small = LayoutEngine::restyle(scene(400, 200));
+        let design = small.acetates[0].design();
+        assert_eq!((design.border_thickness, design.font_size), (4.0, 20.0));
+
+        // Al redimensionar se sustituye el envoltorio en vez de anidar otro.
+        let large = LayoutEngine::restyle(Scene {
+            width: 800,
+            height: 400,
+            ..small
+        });
+        let design = large.acetates[0].design();
+        assert_eq!((design.border_thickness, design.font_size), (8.0, 40.0));
+        assert_eq!(format!("{:?}", large.acetates[0]).matches("SizeOverride").count(), 1);
+    }
+}
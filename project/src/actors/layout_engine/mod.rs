@@ -0,0 +1,9 @@
+// Alias monádicos locales
+pub type Domain = crate::contracts::scene::Scene;
+pub type Codomain = crate::contracts::scene::Scene;
+
+pub mod constraint_solver;
+#[allow(clippy::module_inception)]
+pub mod layout_engine;
+pub use constraint_solver::{ConstraintError, ConstraintSolver};
+pub use layout_engine::LayoutEngine;
@@ -5,3 +5,4 @@ pub mod event_router;
 pub mod input_mapper;
 pub mod ticker;
 pub mod animator;
+pub mod layout_engine;
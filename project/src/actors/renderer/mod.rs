@@ -1,5 +1,6 @@
 pub type Domain   = crate::core::RenderDomain;     // hoy = Snapshot
 pub type Codomain = crate::core::RenderedFrame;    // hoy = Vec<u8>
 
+#[allow(clippy::module_inception)]
 pub mod renderer;
 pub use renderer::RendererVello;
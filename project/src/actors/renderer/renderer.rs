@@ -157,6 +157,7 @@ impl RendererVello {
     }
 }
 
+#[allow(clippy::manual_div_ceil)]
 fn align_to_256(n: u32) -> u32 {
     const A: u32 = wgpu::COPY_BYTES_PER_ROW_ALIGNMENT; // 256
     ((n + (A - 1)) / A) * A
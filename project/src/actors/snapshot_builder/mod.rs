@@ -2,5 +2,6 @@
 pub type Domain   = crate::core::TranslateDomain;
 pub type Codomain = crate::core::Snapshot;
 
+#[allow(clippy::module_inception)]
 pub mod snapshot_builder;
 pub use snapshot_builder::SnapshotBuilder;
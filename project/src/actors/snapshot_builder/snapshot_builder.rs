@@ -1,34 +1,122 @@
-use crate::core::{Snapshot, SnapshotLayer, SnapshotStyle, TranslateDomain, Rect as SnapRect};
+use crate::contracts::scene::{
+    Acetate, AcetateDesign, LINE_HEIGHT, MODAL_SCRIM, Rect, Transform,
+};
+use crate::core::{Snapshot, SnapshotLayer, SnapshotStyle, TranslateDomain};
 
 pub struct SnapshotBuilder;
 
 impl SnapshotBuilder {
     pub fn build(scene: TranslateDomain) -> Snapshot {
-        let mut layers: Vec<SnapshotLayer> = scene
-            .acetates
-            .iter()
-            .map(|a| {
-                let design = a.design();
-                let area = SnapRect {
-                    x: design.area.x,
-                    y: design.area.y,
-                    width: design.area.width,
-                    height: design.area.height,
-                };
-                SnapshotLayer {
-                    z_index: a.z_index(),
-                    area,
-                    style: SnapshotStyle {
-                        fill_color: design.background,
-                        border_color: design.border,
-                        border_thickness: design.border_thickness,
-                        text: design.text,
-                    },
-                }
-            })
-            .collect();
+        let mut layers: Vec<SnapshotLayer> = Vec::new();
+        for acetate in scene.visible() {
+            Self::flatten(acetate.as_ref(), None, (1.0, Transform::IDENTITY), &mut layers);
+        }
+
+        // El velo va primero: al ordenar (de forma estable) queda justo debajo del modal.
+        if let Some(z_index) = scene.modal_z_index() {
+            let scrim = AcetateDesign {
+                area: Rect::new(0.0, 0.0, scene.width as f32, scene.height as f32),
+                background: MODAL_SCRIM,
+                ..AcetateDesign::default()
+            };
+            layers.insert(0, Self::layer(z_index, scrim));
+        }
 
         layers.sort_by_key(|l| l.z_index);
-        Snapshot { layers }
+        Snapshot {
+            layers,
+            scale: scene.metrics.scale,
+        }
+    }
+
+    /// Capa del acetate, sus hijos (mismo z, recortados a su área) y sus overlays.
+    /// Hijos y overlays heredan la opacidad y la transformación (`inherited`) del acetate.
+    fn flatten(
+        acetate: &dyn Acetate,
+        clip: Option<&Rect>,
+        inherited: (f32, Transform),
+        layers: &mut Vec<SnapshotLayer>,
+    ) {
+        let z_index = acetate.z_index();
+        let mut own = Self::inherit(Self::layer(z_index, acetate.design()), inherited);
+        own.clip = clip.copied();
+        let paint = (own.opacity, own.transform);
+        layers.push(own);
+
+        // Los nietos se recortan también a los abuelos.
+        let area = match clip {
+            Some(clip) => acetate.area().intersection(clip),
+            None => acetate.area(),
+        };
+        for child in acetate.children() {
+            let mut child_layers = Vec::new();
+            Self::flatten(child.as_ref(), Some(&area), paint, &mut child_layers);
+            layers.extend(child_layers.into_iter().map(|l| SnapshotLayer { z_index, ..l }));
+        }
+
+        layers.extend(
+            acetate
+                .overlays()
+                .into_iter()
+                .map(|overlay| Self::inherit(Self::layer(overlay.z_index, overlay.design), paint)),
+        );
+    }
+
+    /// Compone la opacidad y la transformación propias de la capa con las heredadas.
+    fn inherit(layer: SnapshotLayer, (opacity, transform): (f32, Transform)) -> SnapshotLayer {
+        SnapshotLayer {
+            opacity: layer.opacity * opacity,
+            transform: layer.transform.then(transform),
+            ..layer
+        }
+    }
+
+    /// La transformación del diseño gira y escala alrededor del centro de su área.
+    fn layer(z_index: i32, design: AcetateDesign) -> SnapshotLayer {
+        let transform = design.transform.about(design.area.center());
+        SnapshotLayer {
+            z_index,
+            area: design.area,
+            style: SnapshotStyle {
+                fill_color: design.background,
+                border_color: design.border,
+                border_thickness: design.border_thickness,
+                text: design.text,
+                text_color: design.text_color,
+                font_size: design.font_size,
+                text_align: design.text_align,
+                text_box: design.text_box.unwrap_or(design.area),
+                line_height: design
+                    .line_height
+                    .unwrap_or(design.font_size * LINE_HEIGHT),
+            },
+            shapes: design.shapes,
+            commands: design.commands,
+            clip: None,
+            opacity: design.opacity,
+            transform,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::acetates::Label;
+    use crate::contracts::scene::{Metrics, Scene};
+
+    #[test]
+    fn layers_stay_logical_and_carry_the_scene_scale() {
+        let area = Rect::new(10.5, 20.0, 100.0, 40.25);
+        let scene = Scene {
+            metrics: Metrics {
+                scale: 2.0,
+                ..Metrics::default()
+            },
+            ..Scene::from(vec![Box::new(Label::new("title", area, "Hola")) as Box<dyn Acetate>])
+        };
+        let snapshot = SnapshotBuilder::build(scene);
+        assert_eq!(snapshot.scale, 2.0);
+        assert_eq!(snapshot.layers[0].area, area);
     }
 }
@@ -1,7 +1,8 @@
 mod tick_domain;
 mod tick_codomain;
+#[allow(clippy::module_inception)]
 mod ticker;
 
 pub use tick_domain::{TickDomain, tick};
 pub use tick_codomain::TickCodomain;
-pub use ticker::Ticker;
+pub use ticker::{FIXED_STEP, Ticker, fixed_steps};
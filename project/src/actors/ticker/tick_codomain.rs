@@ -1,11 +1,13 @@
-use std::time::Instant;
-use crate::contracts::event::Event;
+use std::time::{Duration, Instant};
+use crate::contracts::event::{AnimationFrame, Event};
 
 /// Representa un instante funcional en el tiempo
 #[derive(Debug, Clone, PartialEq)]
 pub struct TickCodomain {
     pub number: u64,
     pub timestamp: Instant,
+    /// Tiempo transcurrido desde el tick anterior.
+    pub dt: Duration,
     pub events: Vec<Event>,
 }
 
@@ -15,10 +17,19 @@ impl TickCodomain {
         Self {
             number,
             timestamp,
+            dt: Duration::ZERO,
             events: vec![],
         }
     }
 
+    /// Información temporal para los acetatos animados.
+    pub fn animation_frame(&self) -> AnimationFrame {
+        AnimationFrame {
+            tick: self.number,
+            dt: self.dt.as_secs_f32(),
+        }
+    }
+
     /// Devuelve una copia del TickCodomain con nuevos eventos
     pub fn with_events(mut self, events: Vec<Event>) -> Self {
         self.events = events;
@@ -5,6 +5,8 @@ use std::time::Instant;
 pub struct TickDomain {
     pub tick_number: u64,
     pub start_instant: Instant,
+    /// Instante del tick anterior (para calcular el delta).
+    pub previous_instant: Instant,
 }
 
 /// Función pura que genera un nuevo TickCodomain a partir del dominio declarado.
@@ -12,6 +14,7 @@ pub fn tick(input: TickDomain) -> TickCodomain {
     TickCodomain {
         number: input.tick_number + 1,
         timestamp: input.start_instant,
+        dt: input.start_instant.saturating_duration_since(input.previous_instant),
         events: vec![],
     }
 }
@@ -1,6 +1,18 @@
-use std::time::Instant;
-use super::tick_domain::{TickDomain, tick};
 use super::tick_codomain::TickCodomain;
+use super::tick_domain::{TickDomain, tick};
+use std::time::{Duration, Instant};
+
+/// Paso fijo de las simulaciones (120 Hz). Los muelles lo integran tantas veces como
+/// quepa en el `dt` de cada tick, así que el resultado no depende de los fps.
+pub const FIXED_STEP: Duration = Duration::from_nanos(1_000_000_000 / 120);
+
+/// Pasos fijos que caben en `elapsed` y el tiempo sobrante, para sumarlo al siguiente tick.
+pub fn fixed_steps(elapsed: Duration) -> (u32, Duration) {
+    let step = FIXED_STEP.as_nanos();
+    let steps = (elapsed.as_nanos() / step).min(u32::MAX as u128) as u32;
+    let rest = Duration::from_nanos((elapsed.as_nanos() % step) as u64);
+    (steps, rest)
+}
 
 /// Actor que emite ticks funcionales.
 pub struct Ticker {
@@ -11,7 +23,9 @@ impl Ticker {
     /// Crea un nuevo ticker con un instante base.
     pub fn new(initial_instant: Instant) -> Self {
         let initial_tick = TickCodomain::new(0, initial_instant);
-        Self { last_tick: initial_tick }
+        Self {
+            last_tick: initial_tick,
+        }
     }
 
     /// Acción principal: avanza el tiempo funcional.
@@ -20,6 +34,7 @@ impl Ticker {
             tick_number: self.last_tick.number,
             // si luego quieres usar un intervalo fijo, cámbialo aquí
             start_instant: Instant::now(),
+            previous_instant: self.last_tick.timestamp,
         };
 
         let next = tick(input);
@@ -1,5 +1,7 @@
 pub type Domain   = crate::core::Snapshot;
 pub type Codomain = vello::Scene;
 
+mod text;
+#[allow(clippy::module_inception)]
 pub mod vello_adapter;
 pub use vello_adapter::VelloAdapter;
@@ -0,0 +1,83 @@
+//! Texto del adaptador: fuente monoespaciada incluida en el binario y colocación de
+//! líneas según `SnapshotStyle` (caja, alineación y alto de línea).
+
+use std::sync::{Arc, LazyLock};
+
+use skrifa::{FontRef, MetadataProvider};
+use vello::kurbo::Affine;
+use vello::peniko::{Blob, Brush, Fill, Font};
+use vello::{Glyph, Scene};
+
+use crate::contracts::scene::{CHAR_WIDTH, TextAlign};
+use crate::core::SnapshotStyle;
+
+/// DejaVu Sans Mono (licencia en `assets/fonts/LICENSE-DejaVu.txt`): su avance es el
+/// `CHAR_WIDTH` con el que los acetatos miden el texto.
+static FONT_DATA: &[u8] = include_bytes!("../../../assets/fonts/DejaVuSansMono.ttf");
+static FONT: LazyLock<Font> = LazyLock::new(|| Font::new(Blob::new(Arc::new(FONT_DATA)), 0));
+
+/// Distancia del centro de la línea a la línea base, en `font_size`: deja las
+/// mayúsculas centradas en la línea.
+const BASELINE_BELOW_CENTER: f32 = 0.36;
+
+/// Pinta `style.text` en su caja: una línea se centra en vertical y varias empiezan
+/// arriba, una por `line_height`.
+pub(super) fn draw_text(scene: &mut Scene, style: &SnapshotStyle, brush: &Brush) {
+    let Some(text) = style.text.as_deref() else {
+        return;
+    };
+    if text.is_empty() || style.font_size <= 0.0 {
+        return;
+    }
+    let area = style.text_box;
+    let lines: Vec<&str> = text.split('\n').collect();
+    let top = if lines.len() == 1 {
+        area.y + (area.height - style.line_height) / 2.0
+    } else {
+        area.y
+    };
+    for (row, line) in lines.into_iter().enumerate() {
+        let width = line.chars().count() as f32 * style.font_size * CHAR_WIDTH;
+        let x = match style.text_align {
+            TextAlign::Start => area.x,
+            TextAlign::Center => area.x + (area.width - width) / 2.0,
+            TextAlign::End => area.right() - width,
+        };
+        let center = top + (row as f32 + 0.5) * style.line_height;
+        let baseline = (x, center + style.font_size * BASELINE_BELOW_CENTER);
+        draw_run(scene, line, baseline, style.font_size, brush, Affine::IDENTITY);
+    }
+}
+
+/// Pinta una línea con la línea base izquierda en `baseline` (en el espacio de
+/// `transform`), un carácter por `CHAR_WIDTH`.
+pub(super) fn draw_run(
+    scene: &mut Scene,
+    line: &str,
+    baseline: (f32, f32),
+    font_size: f32,
+    brush: &Brush,
+    transform: Affine,
+) {
+    let Ok(font) = FontRef::new(FONT_DATA) else {
+        return;
+    };
+    let charmap = font.charmap();
+    let advance = font_size * CHAR_WIDTH;
+    let glyphs = line
+        .chars()
+        .enumerate()
+        .filter(|(_, ch)| !ch.is_whitespace())
+        .map(|(column, ch)| Glyph {
+            // Sin glifo para el carácter se pinta el `.notdef` (el 0).
+            id: charmap.map(ch).map_or(0, |glyph| glyph.to_u32()),
+            x: baseline.0 + column as f32 * advance,
+            y: baseline.1,
+        });
+    scene
+        .draw_glyphs(&FONT)
+        .font_size(font_size)
+        .transform(transform)
+        .brush(brush)
+        .draw(Fill::NonZero, glyphs);
+}
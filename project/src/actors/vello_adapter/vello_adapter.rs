@@ -1,11 +1,14 @@
 //! Snapshot -> vello::Scene (adapter)
 //! Convierte el codominio del SnapshotBuilder (Snapshot) en una vello::Scene.
 
-use crate::core::{Snapshot, SnapshotLayer, Color, Rect as SnapRect};
+use crate::contracts::scene::{DrawCommand, Rect as SceneRect, Shape, ShapeStroke, Transform};
+use crate::core::{Snapshot, SnapshotLayer, Color};
+
+use super::text::{draw_run, draw_text};
 
 use vello::Scene;
-use vello::kurbo::{Rect, Affine, Stroke};
-use vello::peniko::{Brush, Color as PColor, Fill};
+use vello::kurbo::{Rect, Affine, Arc, BezPath, Circle, Point, RoundedRect, Stroke};
+use vello::peniko::{Blob, Brush, Color as PColor, Fill, Image, ImageFormat, Mix};
 
 pub struct VelloAdapter;
 
@@ -36,16 +39,23 @@ fn to_pcolor(c: &Color) -> PColor {
     PColor::from_rgba8(to_u8(c.r), to_u8(c.g), to_u8(c.b), to_u8(c.a))
 }
 
-fn to_kurbo_rect(r: &SnapRect) -> Rect {
-    Rect::new(
-        r.x as f64,
-        r.y as f64,
-        (r.x + r.width as i32) as f64,
-        (r.y + r.height as i32) as f64,
-    )
+fn to_kurbo_rect(r: &SceneRect) -> Rect {
+    Rect::new(r.x as f64, r.y as f64, r.right() as f64, r.bottom() as f64)
 }
 
 fn build_scene(scene: &mut Scene, snapshot: &Snapshot) {
+    if snapshot.scale == 1.0 {
+        draw_layers(scene, snapshot);
+        return;
+    }
+    // HiDPI: se pinta en unidades lógicas y la escena entera se escala a píxeles
+    // físicos, trazos y texto incluidos.
+    let mut logical = Scene::new();
+    draw_layers(&mut logical, snapshot);
+    scene.append(&logical, Some(Affine::scale(snapshot.scale as f64)));
+}
+
+fn draw_layers(scene: &mut Scene, snapshot: &Snapshot) {
     // Dibuja capas por z ascendente (fondo primero)
     let mut layers = snapshot.layers.clone();
     layers.sort_by_key(|l| l.z_index);
@@ -56,6 +66,34 @@ fn build_scene(scene: &mut Scene, snapshot: &Snapshot) {
 }
 
 fn draw_layer(scene: &mut Scene, layer: &SnapshotLayer) {
+    let translucent = layer.opacity < 1.0;
+    if !translucent && layer.transform == Transform::IDENTITY {
+        draw_layer_content(scene, layer);
+        return;
+    }
+    if layer.opacity <= 0.0 {
+        return;
+    }
+    // Capa animada: se pinta aparte y se compone entera, transformada y con su opacidad,
+    // para que el relleno, las formas y el recorte se muevan y se desvanezcan juntos.
+    let mut content = Scene::new();
+    draw_layer_content(&mut content, layer);
+    if translucent {
+        // Sin recorte propio: lo limita el de la capa, ya dentro de `content`.
+        let everything = Rect::new(-1e6, -1e6, 1e6, 1e6);
+        scene.push_layer(Mix::Normal, layer.opacity, Affine::IDENTITY, &everything);
+    }
+    scene.append(&content, Some(to_affine(&layer.transform)));
+    if translucent {
+        scene.pop_layer();
+    }
+}
+
+fn draw_layer_content(scene: &mut Scene, layer: &SnapshotLayer) {
+    // Capas hijas: recortadas al área del padre
+    if let Some(clip) = &layer.clip {
+        scene.push_layer(Mix::Clip, 1.0, Affine::IDENTITY, &to_kurbo_rect(clip));
+    }
     let rect = to_kurbo_rect(&layer.area);
 
     // Relleno
@@ -69,5 +107,181 @@ fn draw_layer(scene: &mut Scene, layer: &SnapshotLayer) {
         scene.stroke(&stroke, Affine::IDENTITY, &stroke_brush, None, &rect);
     }
 
-    // TODO: texto (vello_glyph) cuando lo quieras
+    // Formas propias del acetate (selección, cursor, marcas...)
+    for shape in &layer.shapes {
+        draw_shape(scene, shape);
+    }
+
+    // Dibujo libre del acetate: coordenadas locales, recortado a su área
+    if !layer.commands.is_empty() {
+        scene.push_layer(Mix::Clip, 1.0, Affine::IDENTITY, &rect);
+        replay_commands(scene, &layer.commands, Affine::translate((rect.x0, rect.y0)));
+        scene.pop_layer();
+    }
+
+    // Texto, encima de todo lo demás
+    if layer.style.text_color.a > 0.0 {
+        draw_text(scene, &layer.style, &Brush::Solid(to_pcolor(&layer.style.text_color)));
+    }
+
+    if layer.clip.is_some() {
+        scene.pop_layer();
+    }
+}
+
+fn draw_shape(scene: &mut Scene, shape: &Shape) {
+    match shape {
+        Shape::Rect { area, fill } => {
+            let brush = Brush::Solid(to_pcolor(fill));
+            scene.fill(Fill::NonZero, Affine::IDENTITY, &brush, None, &to_kurbo_rect(area));
+        }
+        Shape::RoundedRect { area, radius, fill, stroke } => {
+            let rounded = RoundedRect::from_rect(to_kurbo_rect(area), *radius as f64);
+            fill_and_stroke(scene, &rounded, fill, stroke.as_ref());
+        }
+        Shape::Circle { center, radius, fill, stroke } => {
+            let circle = Circle::new((center.0 as f64, center.1 as f64), *radius as f64);
+            fill_and_stroke(scene, &circle, fill, stroke.as_ref());
+        }
+        Shape::Polyline { points, stroke } => {
+            let mut path = BezPath::new();
+            for (i, (x, y)) in points.iter().enumerate() {
+                let point = (*x as f64, *y as f64);
+                if i == 0 {
+                    path.move_to(point);
+                } else {
+                    path.line_to(point);
+                }
+            }
+            let brush = Brush::Solid(to_pcolor(&stroke.color));
+            scene.stroke(&Stroke::new(stroke.width as f64), Affine::IDENTITY, &brush, None, &path);
+        }
+        Shape::Arc { center, radius, start_angle, sweep_angle, stroke } => {
+            let arc = Arc::new(
+                (center.0 as f64, center.1 as f64),
+                (*radius as f64, *radius as f64),
+                *start_angle as f64,
+                *sweep_angle as f64,
+                0.0,
+            );
+            let brush = Brush::Solid(to_pcolor(&stroke.color));
+            scene.stroke(&Stroke::new(stroke.width as f64), Affine::IDENTITY, &brush, None, &arc);
+        }
+    }
+}
+
+fn to_affine(transform: &Transform) -> Affine {
+    Affine::new(transform.0.map(|v| v as f64))
+}
+
+/// Reproduce los comandos de un canvas; `origin` lleva lo local a la ventana.
+fn replay_commands(scene: &mut Scene, commands: &[DrawCommand], origin: Affine) {
+    let mut transform = origin;
+    let mut saved = Vec::new();
+    let mut path = BezPath::new();
+    let point = |t: &Affine, (x, y): (f32, f32)| *t * Point::new(x as f64, y as f64);
+
+    for command in commands {
+        match command {
+            DrawCommand::BeginPath => path = BezPath::new(),
+            DrawCommand::MoveTo(x, y) => path.move_to(point(&transform, (*x, *y))),
+            DrawCommand::LineTo(x, y) => path.line_to(point(&transform, (*x, *y))),
+            DrawCommand::QuadTo { control, to } => {
+                path.quad_to(point(&transform, *control), point(&transform, *to))
+            }
+            DrawCommand::CubicTo { control1, control2, to } => path.curve_to(
+                point(&transform, *control1),
+                point(&transform, *control2),
+                point(&transform, *to),
+            ),
+            DrawCommand::ClosePath => path.close_path(),
+            DrawCommand::Fill(color) => {
+                let brush = Brush::Solid(to_pcolor(color));
+                scene.fill(Fill::NonZero, Affine::IDENTITY, &brush, None, &path);
+            }
+            DrawCommand::Stroke { color, width } => {
+                let brush = Brush::Solid(to_pcolor(color));
+                scene.stroke(&Stroke::new(*width as f64), Affine::IDENTITY, &brush, None, &path);
+            }
+            DrawCommand::Text { text, position, size, color } => {
+                let brush = Brush::Solid(to_pcolor(color));
+                draw_run(scene, text, *position, *size, &brush, transform);
+            }
+            DrawCommand::Image { image, position, size } => {
+                let expected = image.width as usize * image.height as usize * 4;
+                if expected == 0 || image.pixels.len() < expected {
+                    continue;
+                }
+                let data = Image::new(
+                    Blob::new(image.pixels.clone()),
+                    ImageFormat::Rgba8,
+                    image.width,
+                    image.height,
+                );
+                let fit = Affine::translate((position.0 as f64, position.1 as f64))
+                    * Affine::scale_non_uniform(
+                        size.0 as f64 / image.width as f64,
+                        size.1 as f64 / image.height as f64,
+                    );
+                scene.draw_image(&data, transform * fit);
+            }
+            DrawCommand::Save => saved.push(transform),
+            DrawCommand::Restore => {
+                if let Some(previous) = saved.pop() {
+                    transform = previous;
+                }
+            }
+            DrawCommand::Transform(next) => transform *= to_affine(next),
+        }
+    }
+}
+
+fn fill_and_stroke(
+    scene: &mut Scene,
+    shape: &impl vello::kurbo::Shape,
+    fill: &Color,
+    stroke: Option<&ShapeStroke>,
+) {
+    if fill.a > 0.0 {
+        let brush = Brush::Solid(to_pcolor(fill));
+        scene.fill(Fill::NonZero, Affine::IDENTITY, &brush, None, shape);
+    }
+    if let Some(stroke) = stroke.filter(|s| s.width > 0.0) {
+        let brush = Brush::Solid(to_pcolor(&stroke.color));
+        scene.stroke(&Stroke::new(stroke.width as f64), Affine::IDENTITY, &brush, None, shape);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::acetates::{Canvas, Label};
+    use crate::actors::snapshot_builder::SnapshotBuilder;
+    use crate::contracts::scene::{Acetate, Scene};
+
+    #[test]
+    fn layer_text_and_canvas_text_are_drawn_as_glyphs() {
+        let white = Color::rgba(1.0, 1.0, 1.0, 1.0);
+        let label = Label::new("title", SceneRect::new(0.0, 0.0, 120.0, 20.0), "Hola mundo");
+        let canvas = Canvas::new("plot", SceneRect::new(0.0, 40.0, 120.0, 60.0)).with_commands(vec![
+            DrawCommand::Text {
+                text: "x=1".to_string(),
+                position: (4.0, 16.0),
+                size: 12.0,
+                color: white,
+            },
+        ]);
+        let scene = Scene::from(vec![
+            Box::new(label) as Box<dyn Acetate>,
+            Box::new(canvas) as Box<dyn Acetate>,
+        ]);
+        let vello = VelloAdapter::adapt(SnapshotBuilder::build(scene));
+
+        // Un glifo por carácter visible: "Holamundo" y "x=1".
+        let glyphs = &vello.encoding().resources.glyphs;
+        assert_eq!(glyphs.len(), 9 + 3);
+        assert!(glyphs.iter().all(|glyph| glyph.id != 0), "sin glifos .notdef");
+        // La etiqueta avanza un `CHAR_WIDTH` por carácter, espacios incluidos.
+        assert!((glyphs[4].x - glyphs[0].x - 5.0 * 14.0 * 0.6).abs() < 1e-3);
+    }
 }
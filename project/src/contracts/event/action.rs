@@ -0,0 +1,14 @@
+/// Acción semántica emitida por un acetate (no por el sistema).
+/// Llega al resto de la escena y a `App::frame` como `Event::Action`.
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq)]
+pub enum Action {
+    /// Activación de un control (clic, Enter o Espacio).
+    Click,
+    /// El valor del control cambió; el nuevo valor está en `output().content`.
+    Changed,
+    /// Se eligió una entrada de un menú; lleva su etiqueta.
+    Selected(String),
+    /// Se cerró un diálogo sin elegir (Escape o botón de cerrar).
+    Dismissed,
+}
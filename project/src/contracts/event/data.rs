@@ -0,0 +1,15 @@
+use std::sync::Arc;
+
+use super::Series;
+
+/// Datos que la app entrega a un acetate con `Event::Data`. Cada acetate de datos
+/// atiende la variante que le corresponde e ignora las demás.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Data {
+    /// Filas de una tabla (una `Vec` de celdas por fila).
+    Rows(Arc<Vec<Vec<String>>>),
+    /// Avance (0..1) de una barra de progreso; `None` la pasa a indeterminada.
+    Progress(Option<f32>),
+    /// Series de un gráfico; sustituyen a las anteriores.
+    Series(Arc<Vec<Series>>),
+}
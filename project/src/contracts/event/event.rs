@@ -1,5 +1,5 @@
 // Tipos de eventos del motor (contrato central, puro e inmutable)
-use super::EventKind;
+use super::{Action, Data, EventKind};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum MouseButton {
@@ -21,12 +21,33 @@ pub enum Event {
     KeyPressed(String),
     KeyReleased(String),
     TextInput(String),
+    /// Texto leído del portapapeles tras el atajo de pegar.
+    Paste(String),
 
     // Puntero
     MouseMoved(i32, i32),
     MouseClicked,
     MouseDown { button: MouseButton, x: i32, y: i32 },
     MouseUp   { button: MouseButton, x: i32, y: i32 },
+    /// Rueda o gesto de desplazamiento en píxeles lógicos, con el puntero en `(x, y)`.
+    /// `dy > 0` avanza el contenido (como bajar la rueda).
+    Scroll { dx: f32, dy: f32, x: i32, y: i32 },
+
+    // Acetatos
+    /// Acción emitida por el acetate `source` en el frame anterior.
+    Action { source: String, action: Action },
+
+    // Datos
+    /// Datos que la app entrega al acetate `target` (filas, avance o series).
+    Data { target: String, data: Data },
+
+    // Animaciones (de `Scene::animations` y `Scene::springs`, por `target` y `name`)
+    /// La animación pasó su retraso, o el muelle empezó a moverse.
+    AnimationStarted { target: String, name: String },
+    /// Terminó la vuelta `iteration` (desde 0) de una animación repetida y empieza la siguiente.
+    AnimationIteration { target: String, name: String, iteration: u32 },
+    /// La animación llegó al final, o el muelle se asentó.
+    AnimationFinished { target: String, name: String },
 }
 
 impl Event {
@@ -38,10 +59,17 @@ impl Event {
             Event::KeyPressed(_) => EventKind::KeyPressed,
             Event::KeyReleased(_) => EventKind::KeyReleased,
             Event::TextInput(_) => EventKind::TextInput,
+            Event::Paste(_) => EventKind::Paste,
             Event::MouseMoved(_, _) => EventKind::MouseMoved,
             Event::MouseClicked => EventKind::MouseClicked,
             Event::MouseDown { .. } => EventKind::MouseDown,
             Event::MouseUp { .. } => EventKind::MouseUp,
+            Event::Scroll { .. } => EventKind::Scroll,
+            Event::Action { .. } => EventKind::Action,
+            Event::Data { .. } => EventKind::Data,
+            Event::AnimationStarted { .. } => EventKind::AnimationStarted,
+            Event::AnimationIteration { .. } => EventKind::AnimationIteration,
+            Event::AnimationFinished { .. } => EventKind::AnimationFinished,
         }
     }
 
@@ -49,6 +77,33 @@ impl Event {
     pub fn is_exit(&self) -> bool { self.kind() == EventKind::Exit }
     pub fn is_resize(&self) -> bool { self.kind() == EventKind::WindowResized }
 
+    /// Eventos de entrada del usuario (teclado y puntero), los que bloquea un modal.
+    pub fn is_input(&self) -> bool {
+        matches!(
+            self,
+            Event::KeyPressed(_)
+                | Event::KeyReleased(_)
+                | Event::TextInput(_)
+                | Event::Paste(_)
+                | Event::MouseMoved(_, _)
+                | Event::MouseClicked
+                | Event::MouseDown { .. }
+                | Event::MouseUp { .. }
+                | Event::Scroll { .. }
+        )
+    }
+
+    /// Posición del puntero para los eventos de ratón que la llevan.
+    pub fn position(&self) -> Option<(i32, i32)> {
+        match self {
+            Event::MouseMoved(x, y)
+            | Event::MouseDown { x, y, .. }
+            | Event::MouseUp { x, y, .. }
+            | Event::Scroll { x, y, .. } => Some((*x, *y)),
+            _ => None,
+        }
+    }
+
     /// Compara dos eventos por variante, ignorando payloads dinámicos (p. ej. Tick).
     pub fn same_kind(a: &Event, b: &Event) -> bool {
         matches!(
@@ -59,10 +114,17 @@ impl Event {
                 | (Event::KeyPressed(_), Event::KeyPressed(_))
                 | (Event::KeyReleased(_), Event::KeyReleased(_))
                 | (Event::TextInput(_), Event::TextInput(_))
+                | (Event::Paste(_), Event::Paste(_))
                 | (Event::MouseMoved(_, _), Event::MouseMoved(_, _))
                 | (Event::MouseClicked, Event::MouseClicked)
                 | (Event::MouseDown { .. }, Event::MouseDown { .. })
                 | (Event::MouseUp { .. }, Event::MouseUp { .. })
+                | (Event::Scroll { .. }, Event::Scroll { .. })
+                | (Event::Action { .. }, Event::Action { .. })
+                | (Event::Data { .. }, Event::Data { .. })
+                | (Event::AnimationStarted { .. }, Event::AnimationStarted { .. })
+                | (Event::AnimationIteration { .. }, Event::AnimationIteration { .. })
+                | (Event::AnimationFinished { .. }, Event::AnimationFinished { .. })
         )
     }
 }
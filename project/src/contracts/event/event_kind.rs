@@ -7,8 +7,15 @@ pub enum EventKind {
     KeyPressed,
     KeyReleased,
     TextInput,
+    Paste,
     MouseMoved,
     MouseClicked,
     MouseDown,
     MouseUp,
+    Scroll,
+    Action,
+    Data,
+    AnimationStarted,
+    AnimationIteration,
+    AnimationFinished,
 }
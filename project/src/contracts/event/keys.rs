@@ -0,0 +1,56 @@
+//! Nombres de teclas tal como llegan en `Event::KeyPressed` / `Event::KeyReleased`.
+//! Las teclas con carácter llegan como el propio carácter (`"a"`, `"Z"`);
+//! las teclas con nombre llegan con el formato `Named(...)` del runtime.
+
+use super::event::Event;
+
+pub const BACKSPACE: &str = "Named(Backspace)";
+pub const DELETE: &str = "Named(Delete)";
+pub const ENTER: &str = "Named(Enter)";
+pub const ESCAPE: &str = "Named(Escape)";
+pub const TAB: &str = "Named(Tab)";
+pub const SPACE: &str = "Named(Space)";
+pub const ARROW_LEFT: &str = "Named(ArrowLeft)";
+pub const ARROW_RIGHT: &str = "Named(ArrowRight)";
+pub const ARROW_UP: &str = "Named(ArrowUp)";
+pub const ARROW_DOWN: &str = "Named(ArrowDown)";
+pub const HOME: &str = "Named(Home)";
+pub const END: &str = "Named(End)";
+pub const PAGE_UP: &str = "Named(PageUp)";
+pub const PAGE_DOWN: &str = "Named(PageDown)";
+pub const SHIFT: &str = "Named(Shift)";
+pub const CONTROL: &str = "Named(Control)";
+pub const SUPER: &str = "Named(Super)";
+pub const ALT: &str = "Named(Alt)";
+
+/// Estado de los modificadores, reconstruido a partir de los eventos de teclado.
+/// `Super` (Cmd en macOS) cuenta como `control` para los atajos.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Modifiers {
+    pub shift: bool,
+    pub control: bool,
+    pub alt: bool,
+}
+
+impl Modifiers {
+    /// Devuelve los modificadores resultantes tras el evento (o los mismos si no aplica).
+    pub fn after(self, event: &Event) -> Self {
+        let (key, down) = match event {
+            Event::KeyPressed(key) => (key.as_str(), true),
+            Event::KeyReleased(key) => (key.as_str(), false),
+            _ => return self,
+        };
+        match key {
+            SHIFT => Self { shift: down, ..self },
+            CONTROL | SUPER => Self { control: down, ..self },
+            ALT => Self { alt: down, ..self },
+            _ => self,
+        }
+    }
+}
+
+/// Compara una tecla de carácter sin distinguir mayúsculas (`"z"` == `"Z"`).
+pub fn is_char_key(key: &str, expected: char) -> bool {
+    let mut chars = key.chars();
+    matches!((chars.next(), chars.next()), (Some(c), None) if c.eq_ignore_ascii_case(&expected))
+}
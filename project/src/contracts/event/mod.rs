@@ -1,8 +1,17 @@
+pub mod action;
+#[allow(clippy::module_inception)]
 pub mod event;
 pub mod event_kind;
 pub mod animation_frame;
+pub mod keys;
+pub mod series;
+pub mod data;
 
 // Re-exports convenientes
+pub use action::Action;
 pub use event::*;
 pub use event_kind::EventKind;
 pub use animation_frame::*;
+pub use keys::Modifiers;
+pub use series::Series;
+pub use data::Data;
@@ -0,0 +1,16 @@
+/// Serie de datos con nombre que la app entrega a un gráfico (`Data::Series`).
+/// Los puntos son `(x, y)` en unidades de los datos; el gráfico los escala solo.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Series {
+    pub name: String,
+    pub points: Vec<(f32, f32)>,
+}
+
+impl Series {
+    pub fn new(name: &str, points: impl IntoIterator<Item = (f32, f32)>) -> Self {
+        Self {
+            name: name.to_string(),
+            points: points.into_iter().collect(),
+        }
+    }
+}
@@ -1,11 +1,14 @@
 use std::fmt::Debug;
 
 use super::rect::Rect;
-use super::acetate_io::AcetateIO;
+use super::acetate_io::{AcetateIO, AcetateStatus};
 use super::scene_info::SceneInfo;
 use super::design::AcetateDesign;
+use super::overlay::Overlay;
+use super::with_sizes::WithSizes;
+use super::with_status::WithStatus;
 
-use crate::contracts::event::{Event, EventKind};
+use crate::contracts::event::{AnimationFrame, Event, EventKind};
 
 pub trait Acetate: Send + Sync + Debug {
     fn id(&self) -> String;
@@ -18,9 +21,55 @@ pub trait Acetate: Send + Sync + Debug {
     }
 
     fn react(&self, event: &Event, scene: &SceneInfo) -> Option<Box<dyn Acetate>>;
+
+    /// Avance temporal propio del acetate, aplicado por el `Animator` en cada tick.
+    /// `None` si no hay nada en movimiento.
+    fn animate(&self, _frame: &AnimationFrame) -> Option<Box<dyn Acetate>> {
+        None
+    }
     fn perceive(&self, scene: &SceneInfo) -> SceneInfo;
     fn output(&self) -> AcetateIO;
     fn design(&self) -> AcetateDesign;
+
+    /// Capas emergentes abiertas (menús, popups). Se dibujan en el rango de z
+    /// reservado y reciben en exclusiva el puntero que cae dentro de ellas.
+    fn overlays(&self) -> Vec<Overlay> {
+        Vec::new()
+    }
+
+    /// Acetates hijos materializados para este frame (filas visibles de una lista).
+    /// Solo se pintan, recortados al área del padre; la entrada la gestiona el padre.
+    fn children(&self) -> Vec<Box<dyn Acetate>> {
+        Vec::new()
+    }
+
+    /// Estados que este acetate impone a otros por id (p. ej. unas pestañas que
+    /// ocultan los grupos inactivos). La escena los aplica tras cada evento.
+    fn status_requests(&self) -> Vec<(String, AcetateStatus)> {
+        Vec::new()
+    }
+
+    /// Copia del acetate con otro estado; por defecto lo envuelve en `WithStatus`.
+    fn restatus(&self, status: AcetateStatus) -> Box<dyn Acetate> {
+        Box::new(WithStatus::new(self.clone_box(), status))
+    }
+
+    /// Copia del acetate ocupando `area`; la usa el `LayoutEngine` para colocarlo.
+    fn relocate(&self, area: Rect) -> Box<dyn Acetate>;
+
+    /// Copia con otro grosor de borde y tamaño de fuente (`None` los deja como están);
+    /// la usa el `LayoutEngine` para los `Scene::sizes`. Por defecto los impone sobre
+    /// `design()` con `WithSizes`; los acetatos que los usan por dentro (menús, tablas)
+    /// los cambian en su estilo.
+    fn restyle(&self, border_thickness: Option<f32>, font_size: Option<f32>) -> Box<dyn Acetate> {
+        Box::new(WithSizes::new(self.clone_box(), border_thickness, font_size))
+    }
+
+    /// Un acetate modal bloquea la entrada de todo lo que tenga un z menor
+    /// y se pinta sobre un velo que oscurece la escena.
+    fn is_modal(&self) -> bool {
+        false
+    }
     fn clone_box(&self) -> Box<dyn Acetate>;
 }
 
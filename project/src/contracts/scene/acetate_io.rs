@@ -1,5 +1,7 @@
 // acetate_io.rs for scene module
 
+use crate::contracts::event::Action;
+
 // TODO: implement
 /// Representa el estado funcional de entrada/salida de un acetate.
 #[derive(Debug, Clone)]
@@ -7,14 +9,71 @@ pub struct AcetateIO {
     pub content: Option<String>,
     pub focus: bool,
     pub status: AcetateStatus,
+    /// Última petición al portapapeles emitida por el acetate (copiar/cortar).
+    pub clipboard: Option<ClipboardRequest>,
+    /// Última acción emitida por el acetate (clic, cierre...).
+    pub action: Option<EmittedAction>,
+}
+
+impl Default for AcetateIO {
+    fn default() -> Self {
+        Self {
+            content: None,
+            focus: false,
+            status: AcetateStatus::Ready,
+            clipboard: None,
+            action: None,
+        }
+    }
 }
 
 
 /// Estado lógico de un acetate (activo, oculto, error, etc.)
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum AcetateStatus {
     Ready,
     Hidden,
     Disabled,
     Error(String),
 }
+
+/// Petición de escritura al portapapeles.
+/// `serial` crece con cada petición nueva del acetate, así el runtime
+/// la aplica una sola vez aunque el acetate la siga exponiendo en frames posteriores.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ClipboardRequest {
+    Copy { serial: u64, text: String },
+    Cut { serial: u64, text: String },
+}
+
+impl ClipboardRequest {
+    pub fn serial(&self) -> u64 {
+        match self {
+            ClipboardRequest::Copy { serial, .. } | ClipboardRequest::Cut { serial, .. } => *serial,
+        }
+    }
+
+    pub fn text(&self) -> &str {
+        match self {
+            ClipboardRequest::Copy { text, .. } | ClipboardRequest::Cut { text, .. } => text,
+        }
+    }
+}
+
+/// Acción emitida por un acetate. `serial` cumple el mismo papel que en
+/// `ClipboardRequest`: el runtime entrega cada acción una sola vez.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EmittedAction {
+    pub serial: u64,
+    pub action: Action,
+}
+
+impl EmittedAction {
+    /// Siguiente emisión a partir de la anterior (si la hubo).
+    pub fn next(previous: Option<&EmittedAction>, action: Action) -> Self {
+        Self {
+            serial: previous.map_or(0, |emitted| emitted.serial) + 1,
+            action,
+        }
+    }
+}
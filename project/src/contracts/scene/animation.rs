@@ -0,0 +1,515 @@
+// animation.rs for scene module
+
+use std::time::Duration;
+
+use super::acetate::Acetate;
+use super::draw::Transform;
+use crate::core::Color;
+
+/// Curva de avance: convierte el progreso lineal de un tramo (0..1) en el animado.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum Easing {
+    Linear,
+    /// `cubic-bezier(0.25, 0.1, 0.25, 1)`, la de CSS por defecto.
+    #[default]
+    Ease,
+    EaseIn,
+    EaseOut,
+    EaseInOut,
+    /// Puntos de control `(x1, y1, x2, y2)` de una Bézier cúbica de (0,0) a (1,1).
+    CubicBezier(f32, f32, f32, f32),
+}
+
+impl Easing {
+    /// Nombres de CSS: `"linear"`, `"ease"`, `"ease-in"`, `"ease-out"`, `"ease-in-out"`
+    /// o `"cubic-bezier(x1, y1, x2, y2)"`.
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim() {
+            "linear" => Some(Easing::Linear),
+            "ease" => Some(Easing::Ease),
+            "ease-in" => Some(Easing::EaseIn),
+            "ease-out" => Some(Easing::EaseOut),
+            "ease-in-out" => Some(Easing::EaseInOut),
+            other => {
+                let points = other
+                    .strip_prefix("cubic-bezier(")?
+                    .strip_suffix(')')?
+                    .split(',')
+                    .map(|point| point.trim().parse::<f32>().ok())
+                    .collect::<Option<Vec<f32>>>()?;
+                match points[..] {
+                    [x1, y1, x2, y2] if (0.0..=1.0).contains(&x1) && (0.0..=1.0).contains(&x2) => {
+                        Some(Easing::CubicBezier(x1, y1, x2, y2))
+                    }
+                    _ => None,
+                }
+            }
+        }
+    }
+
+    pub fn apply(self, t: f32) -> f32 {
+        let t = t.clamp(0.0, 1.0);
+        match self {
+            Easing::Linear => t,
+            Easing::Ease => cubic_bezier((0.25, 0.1, 0.25, 1.0), t),
+            Easing::EaseIn => cubic_bezier((0.42, 0.0, 1.0, 1.0), t),
+            Easing::EaseOut => cubic_bezier((0.0, 0.0, 0.58, 1.0), t),
+            Easing::EaseInOut => cubic_bezier((0.42, 0.0, 0.58, 1.0), t),
+            Easing::CubicBezier(x1, y1, x2, y2) => cubic_bezier((x1, y1, x2, y2), t),
+        }
+    }
+}
+
+/// `y` de la curva en la abscisa `x`: se busca el parámetro por Newton y, si no
+/// converge, por bisección.
+fn cubic_bezier((x1, y1, x2, y2): (f32, f32, f32, f32), x: f32) -> f32 {
+    let x1 = x1.clamp(0.0, 1.0);
+    let x2 = x2.clamp(0.0, 1.0);
+    let coordinate = |a: f32, b: f32, s: f32| {
+        let inverse = 1.0 - s;
+        3.0 * inverse * inverse * s * a + 3.0 * inverse * s * s * b + s * s * s
+    };
+    let slope = |a: f32, b: f32, s: f32| {
+        let inverse = 1.0 - s;
+        3.0 * inverse * inverse * a + 6.0 * inverse * s * (b - a) + 3.0 * s * s * (1.0 - b)
+    };
+
+    let mut s = x;
+    for _ in 0..8 {
+        let error = coordinate(x1, x2, s) - x;
+        if error.abs() < 1e-5 {
+            return coordinate(y1, y2, s);
+        }
+        let derivative = slope(x1, x2, s);
+        if derivative.abs() < 1e-6 {
+            break;
+        }
+        s = (s - error / derivative).clamp(0.0, 1.0);
+    }
+    let (mut low, mut high) = (0.0, 1.0);
+    s = x;
+    for _ in 0..32 {
+        if coordinate(x1, x2, s) < x {
+            low = s;
+        } else {
+            high = s;
+        }
+        s = (low + high) / 2.0;
+    }
+    coordinate(y1, y2, s)
+}
+
+/// Propiedad de un acetate que puede animarse.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AnimatedProperty {
+    X,
+    Y,
+    Width,
+    Height,
+    Fill,
+    BorderColor,
+    Opacity,
+    Transform,
+}
+
+impl AnimatedProperty {
+    pub const ALL: [AnimatedProperty; 8] = [
+        AnimatedProperty::X,
+        AnimatedProperty::Y,
+        AnimatedProperty::Width,
+        AnimatedProperty::Height,
+        AnimatedProperty::Fill,
+        AnimatedProperty::BorderColor,
+        AnimatedProperty::Opacity,
+        AnimatedProperty::Transform,
+    ];
+
+    /// Nombres del ui.toml: `x`, `y`, `w`/`width`, `h`/`height`, `fill`, `border`,
+    /// `opacity` y `transform`.
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim() {
+            "x" => Some(AnimatedProperty::X),
+            "y" => Some(AnimatedProperty::Y),
+            "w" | "width" => Some(AnimatedProperty::Width),
+            "h" | "height" => Some(AnimatedProperty::Height),
+            "fill" => Some(AnimatedProperty::Fill),
+            "border" => Some(AnimatedProperty::BorderColor),
+            "opacity" => Some(AnimatedProperty::Opacity),
+            "transform" => Some(AnimatedProperty::Transform),
+            _ => None,
+        }
+    }
+
+    /// Valor actual de la propiedad en `acetate`, sin animaciones aplicadas.
+    pub fn read(self, acetate: &dyn Acetate) -> AnimationValue {
+        let area = acetate.area();
+        match self {
+            AnimatedProperty::X => AnimationValue::Number(area.x),
+            AnimatedProperty::Y => AnimationValue::Number(area.y),
+            AnimatedProperty::Width => AnimationValue::Number(area.width),
+            AnimatedProperty::Height => AnimationValue::Number(area.height),
+            AnimatedProperty::Fill => AnimationValue::Color(acetate.design().background),
+            AnimatedProperty::BorderColor => AnimationValue::Color(acetate.design().border),
+            AnimatedProperty::Opacity => AnimationValue::Number(acetate.design().opacity),
+            AnimatedProperty::Transform => AnimationValue::Transform(acetate.design().transform),
+        }
+    }
+}
+
+/// Valor de una propiedad en un fotograma clave.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AnimationValue {
+    /// Geometría (`X`, `Y`, `Width`, `Height`) u `Opacity`.
+    Number(f32),
+    /// `Fill` o `BorderColor`.
+    Color(Color),
+    Transform(Transform),
+}
+
+impl AnimationValue {
+    /// Interpolación lineal; valores de distinto tipo saltan a `other` al final. Las
+    /// transformaciones interpolan escala, giro (por el camino más corto) y traslación por
+    /// separado, para que un giro no encoja la figura a mitad de camino.
+    pub fn lerp(self, other: AnimationValue, t: f32) -> AnimationValue {
+        match (self, other) {
+            (AnimationValue::Number(a), AnimationValue::Number(b)) => {
+                AnimationValue::Number(a + (b - a) * t)
+            }
+            (AnimationValue::Color(a), AnimationValue::Color(b)) => {
+                AnimationValue::Color(a.mix(b, t))
+            }
+            (AnimationValue::Transform(_), AnimationValue::Transform(_)) => {
+                let from = self.components();
+                let to = other.components_near(&from);
+                let mixed: Vec<f32> = from.iter().zip(&to).map(|(a, b)| a + (b - a) * t).collect();
+                self.with_components(&mixed)
+            }
+            _ if t < 1.0 => self,
+            _ => other,
+        }
+    }
+
+    /// Componentes numéricos: `[n]`, `[r, g, b, a]` o, en una transformación,
+    /// `[escala x, escala y, giro, x, y]`.
+    pub fn components(&self) -> Vec<f32> {
+        match self {
+            AnimationValue::Number(value) => vec![*value],
+            AnimationValue::Color(color) => vec![color.r, color.g, color.b, color.a],
+            AnimationValue::Transform(transform) => decompose(*transform).to_vec(),
+        }
+    }
+
+    /// Valor del mismo tipo que `self` con otros componentes (ver `components`).
+    pub fn with_components(&self, components: &[f32]) -> AnimationValue {
+        let at = |index: usize| components.get(index).copied().unwrap_or_default();
+        match self {
+            AnimationValue::Number(_) => AnimationValue::Number(at(0)),
+            AnimationValue::Color(_) => {
+                AnimationValue::Color(Color::rgba(at(0), at(1), at(2), at(3)))
+            }
+            AnimationValue::Transform(_) => {
+                AnimationValue::Transform(compose([at(0), at(1), at(2), at(3), at(4)]))
+            }
+        }
+    }
+
+    /// Como `components`, pero con el giro a menos de media vuelta del de `reference`
+    /// (otros componentes de transformación), para llegar por el camino más corto.
+    pub fn components_near(&self, reference: &[f32]) -> Vec<f32> {
+        let mut components = self.components();
+        if let (AnimationValue::Transform(_), Some(angle)) = (self, reference.get(2)) {
+            components[2] = nearest_turn(components[2], *angle);
+        }
+        components
+    }
+}
+
+/// Escala, giro y traslación de una transformación sin sesgo: `[sx, sy, giro, x, y]`.
+fn decompose(Transform([a, b, c, d, e, f]): Transform) -> [f32; 5] {
+    let scale_x = a.hypot(b);
+    let scale_y = if scale_x > 0.0 {
+        (a * d - b * c) / scale_x
+    } else {
+        c.hypot(d)
+    };
+    [scale_x, scale_y, b.atan2(a), e, f]
+}
+
+fn compose([scale_x, scale_y, angle, x, y]: [f32; 5]) -> Transform {
+    Transform::scale(scale_x, scale_y)
+        .then(Transform::rotate(angle))
+        .then(Transform::translate(x, y))
+}
+
+/// `angle` más o menos una vuelta, lo más cerca posible de `reference`.
+fn nearest_turn(angle: f32, reference: f32) -> f32 {
+    let turn = (angle - reference).rem_euclid(std::f32::consts::TAU);
+    if turn > std::f32::consts::PI {
+        reference + turn - std::f32::consts::TAU
+    } else {
+        reference + turn
+    }
+}
+
+impl From<f32> for AnimationValue {
+    fn from(value: f32) -> Self {
+        AnimationValue::Number(value)
+    }
+}
+
+impl From<Color> for AnimationValue {
+    fn from(value: Color) -> Self {
+        AnimationValue::Color(value)
+    }
+}
+
+impl From<Transform> for AnimationValue {
+    fn from(value: Transform) -> Self {
+        AnimationValue::Transform(value)
+    }
+}
+
+/// Valor que la propiedad toma en `offset` (0..1 dentro de una iteración).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Keyframe {
+    pub offset: f32,
+    pub value: AnimationValue,
+}
+
+/// Cuántas veces se recorre la animación.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Repeat {
+    Times(u32),
+    Forever,
+}
+
+impl Default for Repeat {
+    fn default() -> Self {
+        Repeat::Times(1)
+    }
+}
+
+/// Animación declarada en `Scene::animations`: lleva una propiedad del acetate `target`
+/// por sus fotogramas clave. El `Animator` la avanza con cada `Event::Tick` y aplica el
+/// valor a la escena; al terminar mantiene el último valor hasta que la app la quita.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Animation {
+    pub target: String,
+    /// Nombre con el que llegan sus `Event::AnimationStarted`/`Iteration`/`Finished`;
+    /// vacío si no se le da uno.
+    pub name: String,
+    pub property: AnimatedProperty,
+    /// Ordenados por `offset`; la curva se aplica a cada tramo entre dos de ellos.
+    pub keyframes: Vec<Keyframe>,
+    /// Duración de una iteración.
+    pub duration: Duration,
+    pub delay: Duration,
+    pub easing: Easing,
+    pub repeat: Repeat,
+    /// Las iteraciones impares se recorren al revés.
+    pub alternate: bool,
+    /// Tiempo transcurrido desde que se añadió, retraso incluido.
+    pub elapsed: Duration,
+}
+
+impl Animation {
+    /// De `from` a `to` en `duration`.
+    pub fn tween(
+        target: &str,
+        property: AnimatedProperty,
+        from: impl Into<AnimationValue>,
+        to: impl Into<AnimationValue>,
+        duration: Duration,
+    ) -> Self {
+        let keyframes = vec![
+            Keyframe {
+                offset: 0.0,
+                value: from.into(),
+            },
+            Keyframe {
+                offset: 1.0,
+                value: to.into(),
+            },
+        ];
+        Self::keyframes(target, property, keyframes, duration)
+    }
+
+    pub fn keyframes(
+        target: &str,
+        property: AnimatedProperty,
+        keyframes: impl IntoIterator<Item = Keyframe>,
+        duration: Duration,
+    ) -> Self {
+        let mut keyframes: Vec<Keyframe> = keyframes.into_iter().collect();
+        keyframes.sort_by(|a, b| a.offset.total_cmp(&b.offset));
+        Self {
+            target: target.to_string(),
+            name: String::new(),
+            property,
+            keyframes,
+            duration,
+            delay: Duration::ZERO,
+            easing: Easing::default(),
+            repeat: Repeat::default(),
+            alternate: false,
+            elapsed: Duration::ZERO,
+        }
+    }
+
+    pub fn with_name(mut self, name: &str) -> Self {
+        self.name = name.to_string();
+        self
+    }
+
+    pub fn with_delay(mut self, delay: Duration) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    pub fn with_easing(mut self, easing: Easing) -> Self {
+        self.easing = easing;
+        self
+    }
+
+    pub fn with_repeat(mut self, repeat: Repeat) -> Self {
+        self.repeat = repeat;
+        self
+    }
+
+    pub fn with_alternate(mut self, alternate: bool) -> Self {
+        self.alternate = alternate;
+        self
+    }
+
+    /// La misma animación `dt` más adelante.
+    pub fn advance(&self, dt: Duration) -> Self {
+        Self {
+            elapsed: self.elapsed + dt,
+            ..self.clone()
+        }
+    }
+
+    pub fn is_started(&self) -> bool {
+        self.elapsed >= self.delay
+    }
+
+    pub fn is_finished(&self) -> bool {
+        match self.repeat {
+            Repeat::Forever => false,
+            Repeat::Times(times) => {
+                self.elapsed >= self.delay + self.duration.saturating_mul(times)
+            }
+        }
+    }
+
+    /// Iteración en curso (desde 0), o la última si ya terminó; `None` mientras dura
+    /// el retraso.
+    pub fn iteration(&self) -> Option<u32> {
+        let active = self.elapsed.checked_sub(self.delay)?;
+        let duration = self.duration.as_secs_f32();
+        Some(if self.is_finished() || duration <= 0.0 {
+            match self.repeat {
+                Repeat::Times(times) => times.saturating_sub(1),
+                Repeat::Forever => 0,
+            }
+        } else {
+            (active.as_secs_f32() / duration).floor() as u32
+        })
+    }
+
+    /// Progreso (0..1) dentro de la iteración en curso, ya invertido en las alternas;
+    /// `None` mientras dura el retraso.
+    pub fn progress(&self) -> Option<f32> {
+        let iteration = self.iteration()?;
+        let duration = self.duration.as_secs_f32();
+        let local = if self.is_finished() || duration <= 0.0 {
+            1.0
+        } else {
+            let active = self.elapsed - self.delay;
+            (active.as_secs_f32() / duration).fract()
+        };
+        Some(if self.alternate && iteration % 2 == 1 {
+            1.0 - local
+        } else {
+            local
+        })
+    }
+
+    /// Valor actual de la propiedad; `None` durante el retraso o sin fotogramas.
+    pub fn value(&self) -> Option<AnimationValue> {
+        let progress = self.progress()?;
+        let first = self.keyframes.first()?;
+        if progress <= first.offset {
+            return Some(first.value);
+        }
+        let segment = self
+            .keyframes
+            .windows(2)
+            .find(|pair| progress <= pair[1].offset);
+        let Some([from, to]) = segment else {
+            return self.keyframes.last().map(|last| last.value);
+        };
+        let span = to.offset - from.offset;
+        let local = if span > 0.0 {
+            (progress - from.offset) / span
+        } else {
+            1.0
+        };
+        Some(from.value.lerp(to.value, self.easing.apply(local)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(animation: &Animation, millis: u64) -> Option<AnimationValue> {
+        Animation {
+            elapsed: Duration::from_millis(millis),
+            ..animation.clone()
+        }
+        .value()
+    }
+
+    #[test]
+    fn tweens_follow_delay_easing_repeat_and_alternate() {
+        let slide = Animation::tween(
+            "panel",
+            AnimatedProperty::X,
+            0.0,
+            100.0,
+            Duration::from_millis(1000),
+        )
+        .with_delay(Duration::from_millis(500))
+        .with_easing(Easing::Linear)
+        .with_repeat(Repeat::Times(2))
+        .with_alternate(true);
+        assert_eq!(at(&slide, 400), None);
+        assert_eq!(at(&slide, 750), Some(AnimationValue::Number(25.0)));
+        assert_eq!(at(&slide, 1750), Some(AnimationValue::Number(75.0)));
+        assert_eq!(at(&slide, 5000), Some(AnimationValue::Number(0.0)));
+        assert!(slide.advance(Duration::from_millis(2500)).is_finished());
+
+        let Some(AnimationValue::Number(eased)) =
+            at(&slide.clone().with_easing(Easing::EaseIn), 750)
+        else {
+            panic!("number");
+        };
+        assert!(eased > 0.0 && eased < 25.0);
+        assert!((Easing::EaseInOut.apply(0.5) - 0.5).abs() < 1e-3);
+
+        let pulse = Animation::keyframes(
+            "dot",
+            AnimatedProperty::Opacity,
+            [(0.0, 1.0), (0.5, 0.2), (1.0, 1.0)].map(|(offset, value)| Keyframe {
+                offset,
+                value: AnimationValue::Number(value),
+            }),
+            Duration::from_millis(1000),
+        )
+        .with_easing(Easing::Linear)
+        .with_repeat(Repeat::Forever);
+        assert_eq!(at(&pulse, 3250), Some(AnimationValue::Number(0.6)));
+        assert!(!pulse.advance(Duration::from_secs(60)).is_finished());
+    }
+}
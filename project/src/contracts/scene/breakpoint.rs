@@ -0,0 +1,98 @@
+// breakpoint.rs for scene module
+
+use std::collections::HashMap;
+
+use super::constraint::Constraints;
+use super::layout::LayoutNode;
+use super::scene::Scene;
+
+/// Variante de la escena para un rango de tamaños: mientras la escena cabe en los
+/// límites, su layout sustituye al de la escena (y a las anclas de los acetates que
+/// coloca), sus anclas sustituyen a las de los mismos acetates y los ocultos dejan de
+/// formar parte de ella. El `LayoutEngine` aplica, en orden, todos los que coinciden.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Breakpoint {
+    pub name: String,
+    pub min_width: Option<u32>,
+    pub max_width: Option<u32>,
+    pub min_height: Option<u32>,
+    pub max_height: Option<u32>,
+    pub layout: Option<LayoutNode>,
+    pub constraints: HashMap<String, Constraints>,
+    pub hidden: Vec<String>,
+}
+
+impl Breakpoint {
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            ..Self::default()
+        }
+    }
+
+    pub fn with_min_width(mut self, width: u32) -> Self {
+        self.min_width = Some(width);
+        self
+    }
+
+    pub fn with_max_width(mut self, width: u32) -> Self {
+        self.max_width = Some(width);
+        self
+    }
+
+    pub fn with_min_height(mut self, height: u32) -> Self {
+        self.min_height = Some(height);
+        self
+    }
+
+    pub fn with_max_height(mut self, height: u32) -> Self {
+        self.max_height = Some(height);
+        self
+    }
+
+    pub fn with_layout(mut self, layout: LayoutNode) -> Self {
+        self.layout = Some(layout);
+        self
+    }
+
+    pub fn with_constraints(mut self, id: &str, constraints: Constraints) -> Self {
+        self.constraints.insert(id.to_string(), constraints);
+        self
+    }
+
+    pub fn with_hidden(mut self, id: &str) -> Self {
+        self.hidden.push(id.to_string());
+        self
+    }
+
+    /// Límites inclusivos; los ausentes no restringen.
+    pub fn matches(&self, width: u32, height: u32) -> bool {
+        self.min_width.is_none_or(|min| width >= min)
+            && self.max_width.is_none_or(|max| width <= max)
+            && self.min_height.is_none_or(|min| height >= min)
+            && self.max_height.is_none_or(|max| height <= max)
+    }
+
+    /// La escena con este breakpoint aplicado, coincida o no.
+    pub fn apply(&self, scene: Scene) -> Scene {
+        let mut constraints = scene.constraints;
+        if let Some(layout) = &self.layout {
+            for id in layout.ids() {
+                constraints.remove(id);
+            }
+        }
+        constraints.extend(self.constraints.clone());
+        constraints.retain(|id, _| !self.hidden.contains(id));
+        let acetates = scene
+            .acetates
+            .into_iter()
+            .filter(|acetate| !self.hidden.contains(&acetate.id()))
+            .collect();
+        Scene {
+            layout: self.layout.clone().or(scene.layout),
+            constraints,
+            acetates,
+            ..scene
+        }
+    }
+}
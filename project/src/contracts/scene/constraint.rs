@@ -0,0 +1,243 @@
+// constraint.rs for scene module
+
+use std::fmt;
+
+use super::length::Length;
+
+/// Borde o eje de un rectángulo al que se puede anclar otro.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Edge {
+    Left,
+    Right,
+    Top,
+    Bottom,
+    CenterX,
+    CenterY,
+    Width,
+    Height,
+}
+
+impl Edge {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim() {
+            "left" => Some(Edge::Left),
+            "right" => Some(Edge::Right),
+            "top" => Some(Edge::Top),
+            "bottom" => Some(Edge::Bottom),
+            "center_x" => Some(Edge::CenterX),
+            "center_y" => Some(Edge::CenterY),
+            "width" => Some(Edge::Width),
+            "height" => Some(Edge::Height),
+            _ => None,
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Edge::Left => "left",
+            Edge::Right => "right",
+            Edge::Top => "top",
+            Edge::Bottom => "bottom",
+            Edge::CenterX => "center_x",
+            Edge::CenterY => "center_y",
+            Edge::Width => "width",
+            Edge::Height => "height",
+        }
+    }
+}
+
+/// Con qué se mide un ancla: la escena entera u otro acetate por id.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum AnchorTarget {
+    Scene,
+    Acetate(String),
+}
+
+/// Valor de una restricción: una coordenada fija o el borde de otro rectángulo
+/// desplazado `offset`. Los porcentajes se toman del eje de la escena que se restringe.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Anchor {
+    Fixed(Length),
+    To {
+        target: AnchorTarget,
+        edge: Edge,
+        offset: Length,
+    },
+}
+
+impl Anchor {
+    pub fn scene(edge: Edge) -> Self {
+        Anchor::To {
+            target: AnchorTarget::Scene,
+            edge,
+            offset: Length::default(),
+        }
+    }
+
+    pub fn acetate(id: &str, edge: Edge) -> Self {
+        Anchor::To {
+            target: AnchorTarget::Acetate(id.to_string()),
+            edge,
+            offset: Length::default(),
+        }
+    }
+
+    /// Suma `offset` al ancla (en `Fixed`, a la coordenada).
+    pub fn offset(self, delta: impl Into<Length>) -> Self {
+        let delta = delta.into();
+        match self {
+            Anchor::Fixed(value) => Anchor::Fixed(value + delta),
+            Anchor::To {
+                target,
+                edge,
+                offset,
+            } => Anchor::To {
+                target,
+                edge,
+                offset: offset + delta,
+            },
+        }
+    }
+
+    /// Lee `"120"`, `"50%"`, `"scene"`, `"sidebar.right + 8"` o `"scene.bottom - 2dp"`
+    /// (ver `Length`). Sin `.borde` se usa `own`, el borde que se está restringiendo.
+    pub fn parse(value: &str, own: Edge) -> Option<Self> {
+        let value = value.trim();
+        if let Some(fixed) = Length::parse(value) {
+            return Some(Anchor::Fixed(fixed));
+        }
+        // Un `-` sin longitud detrás es parte del id (`close-button.left`).
+        let split = value
+            .rfind(['+', '-'])
+            .filter(|at| *at > 0)
+            .and_then(|at| Some((at, Length::parse(&value[at + 1..])?)));
+        let (reference, offset) = match split {
+            Some((at, amount)) if value[at..].starts_with('-') => (value[..at].trim(), -amount),
+            Some((at, amount)) => (value[..at].trim(), amount),
+            None => (value, Length::default()),
+        };
+        let (target, edge) = match reference.rsplit_once('.') {
+            Some((target, edge)) => (target, Edge::parse(edge)?),
+            None => (reference, own),
+        };
+        if target.is_empty() || target.contains(char::is_whitespace) {
+            return None;
+        }
+        let target = match target {
+            "scene" => AnchorTarget::Scene,
+            id => AnchorTarget::Acetate(id.to_string()),
+        };
+        Some(Anchor::To {
+            target,
+            edge,
+            offset,
+        })
+    }
+}
+
+impl fmt::Display for Anchor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Anchor::Fixed(value) => write!(f, "{value}"),
+            Anchor::To {
+                target,
+                edge,
+                offset,
+            } => {
+                match target {
+                    AnchorTarget::Scene => write!(f, "scene.{}", edge.name())?,
+                    AnchorTarget::Acetate(id) => write!(f, "{id}.{}", edge.name())?,
+                }
+                if *offset != Length::default() {
+                    write!(f, " + {offset}")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Anclas de un acetate, como alternativa al árbol de `LayoutNode`. Por eje valen
+/// hasta dos de (inicio, fin, centro, tamaño): el tamaño que no fijan se deduce o, con
+/// una sola, se conserva el declarado. El `LayoutEngine` las resuelve en orden de
+/// dependencia.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Constraints {
+    pub left: Option<Anchor>,
+    pub right: Option<Anchor>,
+    pub top: Option<Anchor>,
+    pub bottom: Option<Anchor>,
+    pub center_x: Option<Anchor>,
+    pub center_y: Option<Anchor>,
+    pub width: Option<Length>,
+    pub height: Option<Length>,
+}
+
+impl Constraints {
+    /// Ocupa toda la escena.
+    pub fn fill_scene() -> Self {
+        Self::default()
+            .with_left(Anchor::scene(Edge::Left))
+            .with_right(Anchor::scene(Edge::Right))
+            .with_top(Anchor::scene(Edge::Top))
+            .with_bottom(Anchor::scene(Edge::Bottom))
+    }
+
+    pub fn with_left(mut self, anchor: Anchor) -> Self {
+        self.left = Some(anchor);
+        self
+    }
+
+    pub fn with_right(mut self, anchor: Anchor) -> Self {
+        self.right = Some(anchor);
+        self
+    }
+
+    pub fn with_top(mut self, anchor: Anchor) -> Self {
+        self.top = Some(anchor);
+        self
+    }
+
+    pub fn with_bottom(mut self, anchor: Anchor) -> Self {
+        self.bottom = Some(anchor);
+        self
+    }
+
+    pub fn with_center_x(mut self, anchor: Anchor) -> Self {
+        self.center_x = Some(anchor);
+        self
+    }
+
+    pub fn with_center_y(mut self, anchor: Anchor) -> Self {
+        self.center_y = Some(anchor);
+        self
+    }
+
+    pub fn with_width(mut self, width: impl Into<Length>) -> Self {
+        self.width = Some(width.into());
+        self
+    }
+
+    pub fn with_height(mut self, height: impl Into<Length>) -> Self {
+        self.height = Some(height.into());
+        self
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.anchors().next().is_none() && self.width.is_none() && self.height.is_none()
+    }
+
+    /// Anclas presentes con el borde al que restringen.
+    pub fn anchors(&self) -> impl Iterator<Item = (Edge, &Anchor)> {
+        [
+            (Edge::Left, &self.left),
+            (Edge::Right, &self.right),
+            (Edge::Top, &self.top),
+            (Edge::Bottom, &self.bottom),
+            (Edge::CenterX, &self.center_x),
+            (Edge::CenterY, &self.center_y),
+        ]
+        .into_iter()
+        .filter_map(|(edge, anchor)| anchor.as_ref().map(|anchor| (edge, anchor)))
+    }
+}
@@ -0,0 +1,140 @@
+//! Envoltorio genérico que delega todo en un acetate interno salvo lo que cambie su
+//! `Decoration` (ver `WithStatus`, `WithDesign`, `WithSizes` y `WithTooltip`).
+
+use std::fmt;
+
+use super::acetate::Acetate;
+use super::acetate_io::{AcetateIO, AcetateStatus};
+use super::design::AcetateDesign;
+use super::overlay::Overlay;
+use super::rect::Rect;
+use super::scene_info::SceneInfo;
+
+use crate::contracts::event::{AnimationFrame, Event, EventKind};
+
+/// Lo que un `Decorated` impone sobre el acetate interno. Por defecto no cambia nada.
+pub trait Decoration: fmt::Debug + Clone + Send + Sync + 'static {
+    fn output(&self, output: AcetateIO) -> AcetateIO {
+        output
+    }
+
+    fn design(&self, design: AcetateDesign) -> AcetateDesign {
+        design
+    }
+
+    /// Copia con otro estado; por defecto cambia el del interno y conserva la decoración.
+    fn restatus(decorated: &Decorated<Self>, status: AcetateStatus) -> Box<dyn Acetate> {
+        decorated.rewrap(decorated.inner.restatus(status))
+    }
+
+    /// Copia con otros borde y fuente; por defecto los cambia en el interno.
+    fn restyle(
+        decorated: &Decorated<Self>,
+        border_thickness: Option<f32>,
+        font_size: Option<f32>,
+    ) -> Box<dyn Acetate> {
+        decorated.rewrap(decorated.inner.restyle(border_thickness, font_size))
+    }
+}
+
+/// Acetate interno más una decoración. Las reacciones, animaciones y reubicaciones
+/// del interno se vuelven a envolver, así que la decoración sobrevive a todas ellas.
+#[derive(Debug, Clone)]
+pub struct Decorated<D: Decoration> {
+    inner: Box<dyn Acetate>,
+    decoration: D,
+}
+
+impl<D: Decoration> Decorated<D> {
+    pub fn decorate(inner: Box<dyn Acetate>, decoration: D) -> Self {
+        Self { inner, decoration }
+    }
+
+    pub fn inner(&self) -> &dyn Acetate {
+        self.inner.as_ref()
+    }
+
+    pub fn decoration(&self) -> &D {
+        &self.decoration
+    }
+
+    pub fn rewrap(&self, inner: Box<dyn Acetate>) -> Box<dyn Acetate> {
+        Box::new(Self::decorate(inner, self.decoration.clone()))
+    }
+}
+
+impl<D: Decoration> Acetate for Decorated<D> {
+    fn id(&self) -> String {
+        self.inner.id()
+    }
+
+    fn name(&self) -> String {
+        self.inner.name()
+    }
+
+    fn z_index(&self) -> i32 {
+        self.inner.z_index()
+    }
+
+    fn area(&self) -> Rect {
+        self.inner.area()
+    }
+
+    fn relocate(&self, area: Rect) -> Box<dyn Acetate> {
+        self.rewrap(self.inner.relocate(area))
+    }
+
+    fn subscriptions(&self) -> Vec<EventKind> {
+        self.inner.subscriptions()
+    }
+
+    fn react(&self, event: &Event, scene: &SceneInfo) -> Option<Box<dyn Acetate>> {
+        self.inner
+            .react(event, scene)
+            .map(|inner| self.rewrap(inner))
+    }
+
+    fn animate(&self, frame: &AnimationFrame) -> Option<Box<dyn Acetate>> {
+        self.inner.animate(frame).map(|inner| self.rewrap(inner))
+    }
+
+    fn perceive(&self, scene: &SceneInfo) -> SceneInfo {
+        self.inner.perceive(scene)
+    }
+
+    fn output(&self) -> AcetateIO {
+        self.decoration.output(self.inner.output())
+    }
+
+    fn design(&self) -> AcetateDesign {
+        self.decoration.design(self.inner.design())
+    }
+
+    fn overlays(&self) -> Vec<Overlay> {
+        self.inner.overlays()
+    }
+
+    fn children(&self) -> Vec<Box<dyn Acetate>> {
+        self.inner.children()
+    }
+
+    fn status_requests(&self) -> Vec<(String, AcetateStatus)> {
+        self.inner.status_requests()
+    }
+
+    fn restatus(&self, status: AcetateStatus) -> Box<dyn Acetate> {
+        D::restatus(self, status)
+    }
+
+    fn restyle(&self, border_thickness: Option<f32>, font_size: Option<f32>) -> Box<dyn Acetate> {
+        D::restyle(self, border_thickness, font_size)
+    }
+
+    fn is_modal(&self) -> bool {
+        self.inner.is_modal()
+    }
+
+    fn clone_box(&self) -> Box<dyn Acetate> {
+        Box::new(self.clone())
+    }
+}
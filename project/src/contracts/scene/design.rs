@@ -1,6 +1,9 @@
 // design.rs for scene module
 
+use super::draw::{DrawCommand, Transform};
 use super::rect::Rect;
+use super::shape::Shape;
+use super::text::TextAlign;
 use crate::core::Color;
 
 /// Describe visualmente cómo debe representarse un acetate.
@@ -11,4 +14,46 @@ pub struct AcetateDesign {
     pub border: Color,
     pub border_thickness: f32,
     pub text: Option<String>,
+    pub text_color: Color,
+    pub font_size: f32,
+    /// Alineación horizontal de cada línea del texto.
+    pub text_align: TextAlign,
+    /// Caja en la que se coloca el texto; `None` usa `area`. Una sola línea se centra
+    /// en vertical; varias empiezan arriba.
+    pub text_box: Option<Rect>,
+    /// Alto de cada línea; `None` usa `font_size * LINE_HEIGHT`.
+    pub line_height: Option<f32>,
+    /// Formas pintadas encima del fondo, en orden.
+    pub shapes: Vec<Shape>,
+    /// Dibujo libre pintado después de las formas (ver `DrawCommand`).
+    pub commands: Vec<DrawCommand>,
+    /// Texto de ayuda que el runtime muestra al dejar el puntero encima.
+    pub tooltip: Option<String>,
+    /// Opacidad del acetate entero (0..1), hijos y overlays incluidos.
+    pub opacity: f32,
+    /// Transformación del acetate entero alrededor del centro de su área; la entrada
+    /// sigue usando el área sin transformar.
+    pub transform: Transform,
+}
+
+impl Default for AcetateDesign {
+    fn default() -> Self {
+        Self {
+            area: Rect::default(),
+            background: Color::TRANSPARENT,
+            border: Color::TRANSPARENT,
+            border_thickness: 0.0,
+            text: None,
+            text_color: Color::rgba(1.0, 1.0, 1.0, 1.0),
+            font_size: 14.0,
+            text_align: TextAlign::Start,
+            text_box: None,
+            line_height: None,
+            shapes: Vec::new(),
+            commands: Vec::new(),
+            tooltip: None,
+            opacity: 1.0,
+            transform: Transform::IDENTITY,
+        }
+    }
 }
@@ -0,0 +1,114 @@
+// draw.rs for scene module
+
+use std::sync::Arc;
+
+use crate::core::Color;
+
+/// Transformación afín 2D `[a, b, c, d, e, f]`, con el mismo orden que `kurbo::Affine`:
+/// `x' = a·x + c·y + e`, `y' = b·x + d·y + f`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Transform(pub [f32; 6]);
+
+impl Transform {
+    pub const IDENTITY: Transform = Transform([1.0, 0.0, 0.0, 1.0, 0.0, 0.0]);
+
+    pub fn translate(x: f32, y: f32) -> Self {
+        Self([1.0, 0.0, 0.0, 1.0, x, y])
+    }
+
+    pub fn scale(sx: f32, sy: f32) -> Self {
+        Self([sx, 0.0, 0.0, sy, 0.0, 0.0])
+    }
+
+    /// Giro en radianes, en sentido horario (y hacia abajo).
+    pub fn rotate(radians: f32) -> Self {
+        let (sin, cos) = radians.sin_cos();
+        Self([cos, sin, -sin, cos, 0.0, 0.0])
+    }
+
+    /// Aplica `self` y después `next`.
+    pub fn then(self, next: Transform) -> Self {
+        let [a, b, c, d, e, f] = self.0;
+        let [na, nb, nc, nd, ne, nf] = next.0;
+        Self([
+            na * a + nc * b,
+            nb * a + nd * b,
+            na * c + nc * d,
+            nb * c + nd * d,
+            na * e + nc * f + ne,
+            nb * e + nd * f + nf,
+        ])
+    }
+
+    /// La misma transformación con origen en `(x, y)` en vez de en `(0, 0)`.
+    pub fn about(self, (x, y): (f32, f32)) -> Self {
+        Self::translate(-x, -y)
+            .then(self)
+            .then(Self::translate(x, y))
+    }
+
+    pub fn apply(&self, (x, y): (f32, f32)) -> (f32, f32) {
+        let [a, b, c, d, e, f] = self.0;
+        (a * x + c * y + e, b * x + d * y + f)
+    }
+}
+
+/// Mapa de bits RGBA8 (sin premultiplicar, fila a fila). Los píxeles se comparten
+/// entre frames sin copiarse.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CanvasImage {
+    pub width: u32,
+    pub height: u32,
+    pub pixels: Arc<Vec<u8>>,
+}
+
+/// Orden de dibujo inmediato, al estilo de un canvas 2D. Las coordenadas son locales
+/// al área del acetate (origen en su esquina superior izquierda) y lo pintado se
+/// recorta a esa área.
+///
+/// Los puntos del trazado se transforman al añadirse con la transformación vigente;
+/// `Fill` y `Stroke` pintan el trazado actual sin descartarlo (eso lo hace `BeginPath`).
+#[derive(Debug, Clone, PartialEq)]
+pub enum DrawCommand {
+    /// Descarta el trazado actual y empieza uno vacío.
+    BeginPath,
+    MoveTo(f32, f32),
+    LineTo(f32, f32),
+    QuadTo {
+        control: (f32, f32),
+        to: (f32, f32),
+    },
+    CubicTo {
+        control1: (f32, f32),
+        control2: (f32, f32),
+        to: (f32, f32),
+    },
+    ClosePath,
+    /// Rellena el trazado actual (regla no-cero).
+    Fill(Color),
+    /// Traza el trazado actual; el grosor va en píxeles.
+    Stroke {
+        color: Color,
+        width: f32,
+    },
+    /// Una línea de texto con la línea base izquierda en `position`, con la fuente
+    /// monoespaciada del motor (cada carácter avanza `size * CHAR_WIDTH`).
+    Text {
+        text: String,
+        position: (f32, f32),
+        size: f32,
+        color: Color,
+    },
+    /// Imagen con su esquina superior izquierda en `position`, escalada a `size`.
+    Image {
+        image: CanvasImage,
+        position: (f32, f32),
+        size: (f32, f32),
+    },
+    /// Guarda la transformación vigente en la pila.
+    Save,
+    /// Recupera la última transformación guardada.
+    Restore,
+    /// Compone con la transformación vigente; se aplica antes que ella.
+    Transform(Transform),
+}
@@ -0,0 +1,235 @@
+// layout.rs for scene module
+
+/// Qué hace un nodo del layout con su área.
+#[derive(Debug, Clone, PartialEq)]
+pub enum LayoutKind {
+    /// Hoja: el área se asigna al acetate con este id.
+    Acetate(String),
+    /// Hijos en fila, de izquierda a derecha.
+    Row,
+    /// Hijos en columna, de arriba abajo.
+    Column,
+    /// Hijos superpuestos ocupando la misma área.
+    Stack,
+    /// Hijos en celdas de una rejilla de pistas.
+    Grid,
+}
+
+/// Colocación en el eje cruzado (en `Stack`, en ambos ejes).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Align {
+    Start,
+    Center,
+    End,
+    /// Ocupa todo el eje cruzado.
+    #[default]
+    Stretch,
+}
+
+impl Align {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim() {
+            "start" => Some(Align::Start),
+            "center" => Some(Align::Center),
+            "end" => Some(Align::End),
+            "stretch" => Some(Align::Stretch),
+            _ => None,
+        }
+    }
+}
+
+/// Reparto del espacio sobrante en el eje principal.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Justify {
+    #[default]
+    Start,
+    Center,
+    End,
+    SpaceBetween,
+    SpaceAround,
+    SpaceEvenly,
+}
+
+impl Justify {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim() {
+            "start" => Some(Justify::Start),
+            "center" => Some(Justify::Center),
+            "end" => Some(Justify::End),
+            "space_between" => Some(Justify::SpaceBetween),
+            "space_around" => Some(Justify::SpaceAround),
+            "space_evenly" => Some(Justify::SpaceEvenly),
+            _ => None,
+        }
+    }
+}
+
+/// Tamaño de una columna o fila de rejilla.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Track {
+    /// Píxeles fijos.
+    Fixed(f32),
+    /// Fracción (`fr`) del espacio que dejan las pistas fijas.
+    Fraction(f32),
+}
+
+impl Track {
+    /// `"120"` o `"120px"` son fijas; `"1fr"` o `"2.5fr"`, fracciones.
+    pub fn parse(value: &str) -> Option<Self> {
+        let value = value.trim();
+        if let Some(fr) = value.strip_suffix("fr") {
+            return fr
+                .trim()
+                .parse()
+                .ok()
+                .filter(|fr: &f32| *fr >= 0.0)
+                .map(Track::Fraction);
+        }
+        let px = value.strip_suffix("px").unwrap_or(value);
+        px.trim()
+            .parse()
+            .ok()
+            .filter(|px: &f32| *px >= 0.0)
+            .map(Track::Fixed)
+    }
+}
+
+/// Declaración de layout: un árbol cuyas hojas son ids de acetates. El
+/// `LayoutEngine` lo resuelve contra el tamaño de la ventana y reubica cada acetate
+/// (`Acetate::relocate`). `gap` ausente usa `Metrics::spacing`; la raíz se separa
+/// del borde de la ventana `Metrics::margin`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LayoutNode {
+    pub kind: LayoutKind,
+    pub children: Vec<LayoutNode>,
+    pub gap: Option<f32>,
+    pub padding: f32,
+    pub align: Align,
+    pub justify: Justify,
+    /// Pistas de la rejilla (`Grid`).
+    pub columns: Vec<Track>,
+    pub rows: Vec<Track>,
+    /// Tamaño natural `(ancho, alto)`; en contenedores se deduce de los hijos.
+    pub size: Option<(f32, f32)>,
+    /// Tamaño de partida en el eje principal del padre; por defecto, el natural.
+    pub basis: Option<f32>,
+    /// Parte del espacio libre que toma dentro de una fila o columna.
+    pub grow: f32,
+    /// Parte del déficit que cede cuando no cabe (ponderada por su tamaño).
+    pub shrink: f32,
+    /// Celda `(columna, fila)` en la rejilla padre, desde 0; sin ella se coloca en
+    /// la siguiente libre.
+    pub cell: Option<(usize, usize)>,
+    /// Columnas y filas que ocupa en la rejilla padre.
+    pub span: (usize, usize),
+}
+
+impl LayoutNode {
+    fn new(kind: LayoutKind, children: Vec<LayoutNode>) -> Self {
+        Self {
+            kind,
+            children,
+            gap: None,
+            padding: 0.0,
+            align: Align::default(),
+            justify: Justify::default(),
+            columns: Vec::new(),
+            rows: Vec::new(),
+            size: None,
+            basis: None,
+            grow: 0.0,
+            shrink: 1.0,
+            cell: None,
+            span: (1, 1),
+        }
+    }
+
+    pub fn acetate(id: &str) -> Self {
+        Self::new(LayoutKind::Acetate(id.to_string()), Vec::new())
+    }
+
+    pub fn row(children: impl IntoIterator<Item = LayoutNode>) -> Self {
+        Self::new(LayoutKind::Row, children.into_iter().collect())
+    }
+
+    pub fn column(children: impl IntoIterator<Item = LayoutNode>) -> Self {
+        Self::new(LayoutKind::Column, children.into_iter().collect())
+    }
+
+    pub fn stack(children: impl IntoIterator<Item = LayoutNode>) -> Self {
+        Self::new(LayoutKind::Stack, children.into_iter().collect())
+    }
+
+    /// Rejilla; sin filas declaradas se crean filas `1fr` según haga falta.
+    pub fn grid(
+        columns: impl IntoIterator<Item = Track>,
+        rows: impl IntoIterator<Item = Track>,
+        children: impl IntoIterator<Item = LayoutNode>,
+    ) -> Self {
+        Self {
+            columns: columns.into_iter().collect(),
+            rows: rows.into_iter().collect(),
+            ..Self::new(LayoutKind::Grid, children.into_iter().collect())
+        }
+    }
+
+    /// Ids de los acetates que coloca el árbol.
+    pub fn ids(&self) -> Vec<&str> {
+        let mut ids = match &self.kind {
+            LayoutKind::Acetate(id) => vec![id.as_str()],
+            _ => Vec::new(),
+        };
+        ids.extend(self.children.iter().flat_map(LayoutNode::ids));
+        ids
+    }
+
+    pub fn with_gap(mut self, gap: f32) -> Self {
+        self.gap = Some(gap);
+        self
+    }
+
+    pub fn with_padding(mut self, padding: f32) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    pub fn with_align(mut self, align: Align) -> Self {
+        self.align = align;
+        self
+    }
+
+    pub fn with_justify(mut self, justify: Justify) -> Self {
+        self.justify = justify;
+        self
+    }
+
+    pub fn with_size(mut self, width: f32, height: f32) -> Self {
+        self.size = Some((width, height));
+        self
+    }
+
+    pub fn with_basis(mut self, basis: f32) -> Self {
+        self.basis = Some(basis);
+        self
+    }
+
+    pub fn with_grow(mut self, grow: f32) -> Self {
+        self.grow = grow;
+        self
+    }
+
+    pub fn with_shrink(mut self, shrink: f32) -> Self {
+        self.shrink = shrink;
+        self
+    }
+
+    pub fn with_cell(mut self, column: usize, row: usize) -> Self {
+        self.cell = Some((column, row));
+        self
+    }
+
+    pub fn with_span(mut self, columns: usize, rows: usize) -> Self {
+        self.span = (columns.max(1), rows.max(1));
+        self
+    }
+}
@@ -0,0 +1,168 @@
+// length.rs for scene module
+
+use std::fmt;
+use std::ops::{Add, Neg};
+
+/// Longitud con unidades: suma de píxeles, porcentaje de una referencia (el eje de
+/// la escena para posiciones y tamaños, su alto para bordes y fuentes),
+/// unidades de ventana (`vw`/`vh`, centésimas de la escena) y píxeles independientes
+/// de la densidad (`dp`). La escena ya está en unidades lógicas y la escala de la
+/// pantalla se aplica al pintar (`Metrics::scale`), así que `dp` equivale a `px`.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Length {
+    pub px: f32,
+    pub percent: f32,
+    pub vw: f32,
+    pub vh: f32,
+    pub dp: f32,
+}
+
+impl Length {
+    pub fn px(px: f32) -> Self {
+        Self {
+            px,
+            ..Self::default()
+        }
+    }
+
+    pub fn percent(percent: f32) -> Self {
+        Self {
+            percent,
+            ..Self::default()
+        }
+    }
+
+    pub fn dp(dp: f32) -> Self {
+        Self {
+            dp,
+            ..Self::default()
+        }
+    }
+
+    /// Lee `"12"`, `"12px"`, `"50%"`, `"100vw"`, `"10vh"`, `"12dp"` o sumas y restas
+    /// de ellos dentro de `calc(...)`, como `"calc(100% - 20)"`.
+    pub fn parse(value: &str) -> Option<Self> {
+        let value = value.trim();
+        match value
+            .strip_prefix("calc(")
+            .and_then(|v| v.strip_suffix(')'))
+        {
+            Some(expression) => parse_sum(expression),
+            None => parse_term(value),
+        }
+    }
+
+    /// Solo píxeles: no depende de la escena.
+    pub fn is_px(&self) -> bool {
+        self.percent == 0.0 && self.vw == 0.0 && self.vh == 0.0 && self.dp == 0.0
+    }
+
+    /// Sin `%`, `vw` ni `vh`: vale lo mismo en cualquier escena.
+    pub fn is_fixed(&self) -> bool {
+        self.percent == 0.0 && self.vw == 0.0 && self.vh == 0.0
+    }
+
+    /// Unidades lógicas para una referencia de porcentaje y una escena.
+    pub fn resolve(&self, reference: f32, (width, height): (f32, f32)) -> f32 {
+        self.px
+            + self.percent / 100.0 * reference
+            + self.vw / 100.0 * width
+            + self.vh / 100.0 * height
+            + self.dp
+    }
+}
+
+impl fmt::Display for Length {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let terms: Vec<String> = [
+            (self.px, ""),
+            (self.percent, "%"),
+            (self.vw, "vw"),
+            (self.vh, "vh"),
+            (self.dp, "dp"),
+        ]
+        .into_iter()
+        .filter(|(value, _)| *value != 0.0)
+        .map(|(value, unit)| format!("{value}{unit}"))
+        .collect();
+        match terms.len() {
+            0 => write!(f, "0"),
+            1 => write!(f, "{}", terms[0]),
+            _ => write!(f, "calc({})", terms.join(" + ")),
+        }
+    }
+}
+
+impl Add for Length {
+    type Output = Length;
+
+    fn add(self, other: Length) -> Length {
+        Length {
+            px: self.px + other.px,
+            percent: self.percent + other.percent,
+            vw: self.vw + other.vw,
+            vh: self.vh + other.vh,
+            dp: self.dp + other.dp,
+        }
+    }
+}
+
+impl Neg for Length {
+    type Output = Length;
+
+    fn neg(self) -> Length {
+        Length {
+            px: -self.px,
+            percent: -self.percent,
+            vw: -self.vw,
+            vh: -self.vh,
+            dp: -self.dp,
+        }
+    }
+}
+
+impl From<f32> for Length {
+    fn from(px: f32) -> Self {
+        Length::px(px)
+    }
+}
+
+fn parse_term(term: &str) -> Option<Length> {
+    let term = term.trim();
+    let (number, unit) = ["%", "vw", "vh", "dp", "px"]
+        .into_iter()
+        .find_map(|unit| Some((term.strip_suffix(unit)?, unit)))
+        .unwrap_or((term, "px"));
+    let value = number
+        .trim()
+        .parse::<f32>()
+        .ok()
+        .filter(|n| n.is_finite())?;
+    let zero = Length::default();
+    Some(match unit {
+        "%" => Length::percent(value),
+        "vw" => Length { vw: value, ..zero },
+        "vh" => Length { vh: value, ..zero },
+        "dp" => Length::dp(value),
+        _ => Length::px(value),
+    })
+}
+
+/// Términos separados por `+` o `-`; un signo al inicio de un término es del número.
+fn parse_sum(expression: &str) -> Option<Length> {
+    let mut total = Length::default();
+    let mut term = String::new();
+    let mut negative = false;
+    for ch in expression.chars() {
+        if (ch == '+' || ch == '-') && !term.trim().is_empty() {
+            let value = parse_term(&term)?;
+            total = total + if negative { -value } else { value };
+            negative = ch == '-';
+            term.clear();
+        } else {
+            term.push(ch);
+        }
+    }
+    let value = parse_term(&term)?;
+    Some(total + if negative { -value } else { value })
+}
@@ -5,7 +5,22 @@
 /// Escala, margen, espaciado, etc.
 #[derive(Debug, Clone)]
 pub struct Metrics {
+    /// Píxeles físicos por unidad lógica. La escena se declara en unidades lógicas y
+    /// el `VelloAdapter` la escala al pintar; el runtime lo fija en cada frame con el
+    /// factor de escala de la ventana.
     pub scale: f32,
+    /// Separación entre el borde de la ventana y la raíz del layout.
     pub margin: u32,
+    /// Hueco por defecto entre hijos de un contenedor del layout.
     pub spacing: u32,
 }
+
+impl Default for Metrics {
+    fn default() -> Self {
+        Self {
+            scale: 1.0,
+            margin: 0,
+            spacing: 0,
+        }
+    }
+}
@@ -1,21 +1,54 @@
 mod acetate;
 mod acetate_io;
 mod acetate_stub;
+mod animation;
+mod breakpoint;
+mod constraint;
+mod decorated;
 mod design;
+mod draw;
+mod layout;
+mod length;
 mod metrics;
+mod overlay;
 mod rect;
+#[allow(clippy::module_inception)]
 mod scene;
 mod scene_info;
+mod shape;
+mod sizes;
+mod spring;
+mod text;
+mod transition;
 mod acetate_init;
+mod with_design;
+mod with_sizes;
+mod with_status;
 
 pub use acetate::Acetate;
-pub use acetate_io::{AcetateIO, AcetateStatus};
+pub use acetate_io::{AcetateIO, AcetateStatus, ClipboardRequest, EmittedAction};
 pub use acetate_stub::AcetateStub;
+pub use animation::{AnimatedProperty, Animation, AnimationValue, Easing, Keyframe, Repeat};
+pub use breakpoint::Breakpoint;
+pub use constraint::{Anchor, AnchorTarget, Constraints, Edge};
+pub use decorated::{Decorated, Decoration};
 pub use design::AcetateDesign;
+pub use draw::{CanvasImage, DrawCommand, Transform};
+pub use length::Length;
+pub use layout::{Align, Justify, LayoutKind, LayoutNode, Track};
 pub use metrics::Metrics;
+pub use overlay::{MODAL_SCRIM, MODAL_Z_INDEX, OVERLAY_Z_INDEX, Overlay, TOOLTIP_Z_INDEX};
 pub use rect::Rect;
 pub use scene::Scene;
 pub use scene_info::SceneInfo;
+pub use shape::{Shape, ShapeStroke};
+pub use sizes::Sizes;
+pub use spring::{Spring, SpringAnimation};
+pub use text::{CHAR_WIDTH, LINE_HEIGHT, TextAlign, text_width};
+pub use transition::Transition;
 pub use acetate_init::AcetateInit;
+pub use with_design::{DesignOverride, WithDesign};
+pub use with_sizes::{SizeOverride, WithSizes};
+pub use with_status::{StatusOverride, WithStatus};
 
 // Re-export conveniente
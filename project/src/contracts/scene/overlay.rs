@@ -0,0 +1,37 @@
+// overlay.rs for scene module
+
+use super::design::AcetateDesign;
+use crate::core::Color;
+
+/// Inicio del rango de z reservado para capas emergentes (menús, popups).
+/// Un overlay se dibuja en `OVERLAY_Z_INDEX + z_index` de su acetate, por encima
+/// de cualquier acetate normal de la escena.
+pub const OVERLAY_Z_INDEX: i32 = 1_000_000;
+
+/// Inicio del rango de z de los modales abiertos, por encima de los overlays.
+/// Los acetatos que deban quedar dentro de un modal usan un z aún mayor.
+pub const MODAL_Z_INDEX: i32 = 2_000_000;
+
+/// z de los tooltips que inyecta el runtime: por encima de todo lo demás.
+pub const TOOLTIP_Z_INDEX: i32 = 3_000_000;
+
+/// Velo que oscurece la escena por debajo del modal superior.
+pub const MODAL_SCRIM: Color = Color::rgba(0.0, 0.0, 0.0, 0.45);
+
+/// Capa emergente de un acetate: se pinta por encima de la escena y captura
+/// los eventos de puntero que caen dentro de su área.
+#[derive(Debug, Clone)]
+pub struct Overlay {
+    pub z_index: i32,
+    pub design: AcetateDesign,
+}
+
+impl Overlay {
+    /// Overlay en el rango reservado, relativo al z del acetate dueño.
+    pub fn above(owner_z_index: i32, design: AcetateDesign) -> Self {
+        Self {
+            z_index: OVERLAY_Z_INDEX.saturating_add(owner_z_index),
+            design,
+        }
+    }
+}
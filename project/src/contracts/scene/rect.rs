@@ -1,11 +1,133 @@
 // rect.rs for scene module
 
-// TODO: implement
-/// Área rectangular funcional utilizada en Acetate, Design y Snapshot.
-#[derive(Debug, Clone)]
+/// Área rectangular funcional utilizada en Acetate, Design, Snapshot y el layout.
+/// Coordenadas en unidades lógicas con decimales, para que animaciones y escalados
+/// fraccionarios no salten de píxel en píxel; los tamaños negativos no tienen sentido
+/// y los helpers los dejan en cero.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
 pub struct Rect {
-    pub x: i32,
-    pub y: i32,
-    pub width: u32,
-    pub height: u32,
+    pub x: f32,
+    pub y: f32,
+    pub width: f32,
+    pub height: f32,
+}
+
+impl Rect {
+    pub const fn new(x: f32, y: f32, width: f32, height: f32) -> Self {
+        Self {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    /// Área entre dos esquinas opuestas.
+    pub fn from_edges(left: f32, top: f32, right: f32, bottom: f32) -> Self {
+        Self::new(left, top, (right - left).max(0.0), (bottom - top).max(0.0))
+    }
+
+    pub fn right(&self) -> f32 {
+        self.x + self.width
+    }
+
+    pub fn bottom(&self) -> f32 {
+        self.y + self.height
+    }
+
+    pub fn center(&self) -> (f32, f32) {
+        (self.x + self.width / 2.0, self.y + self.height / 2.0)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.width <= 0.0 || self.height <= 0.0
+    }
+
+    /// Indica si el punto `(x, y)` cae dentro del área (borde derecho/inferior exclusivo).
+    /// Acepta las coordenadas enteras de los eventos de puntero o las del layout.
+    pub fn contains(&self, x: impl Into<f64>, y: impl Into<f64>) -> bool {
+        let (x, y) = (x.into(), y.into());
+        x >= self.x as f64
+            && y >= self.y as f64
+            && x < self.right() as f64
+            && y < self.bottom() as f64
+    }
+
+    /// Indica si `other` queda entero dentro del área.
+    pub fn contains_rect(&self, other: &Rect) -> bool {
+        other.x >= self.x
+            && other.y >= self.y
+            && other.right() <= self.right()
+            && other.bottom() <= self.bottom()
+    }
+
+    /// Parte común de dos áreas; sin solape queda de tamaño cero.
+    pub fn intersection(&self, other: &Rect) -> Rect {
+        let x = self.x.max(other.x);
+        let y = self.y.max(other.y);
+        Rect {
+            x,
+            y,
+            width: (self.right().min(other.right()) - x).max(0.0),
+            height: (self.bottom().min(other.bottom()) - y).max(0.0),
+        }
+    }
+
+    /// Menor área que contiene a las dos.
+    pub fn union(&self, other: &Rect) -> Rect {
+        Rect::from_edges(
+            self.x.min(other.x),
+            self.y.min(other.y),
+            self.right().max(other.right()),
+            self.bottom().max(other.bottom()),
+        )
+    }
+
+    /// Encoge el área `amount` por cada lado (crece si es negativo).
+    pub fn inset(&self, amount: f32) -> Rect {
+        Rect {
+            x: self.x + amount,
+            y: self.y + amount,
+            width: (self.width - 2.0 * amount).max(0.0),
+            height: (self.height - 2.0 * amount).max(0.0),
+        }
+    }
+
+    /// La misma área desplazada `(dx, dy)`.
+    pub fn offset(&self, dx: f32, dy: f32) -> Rect {
+        Rect {
+            x: self.x + dx,
+            y: self.y + dy,
+            ..*self
+        }
+    }
+
+    /// Bordes llevados al píxel entero más cercano, para pintar sin medios píxeles.
+    pub fn round(&self) -> Rect {
+        Rect::from_edges(
+            self.x.round(),
+            self.y.round(),
+            self.right().round(),
+            self.bottom().round(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn helpers_keep_fractional_geometry() {
+        let a = Rect::new(10.5, 20.0, 100.0, 40.25);
+        let b = Rect::new(60.0, 0.0, 100.0, 50.0);
+        assert_eq!(a.intersection(&b), Rect::new(60.0, 20.0, 50.5, 30.0));
+        assert_eq!(a.union(&b), Rect::new(10.5, 0.0, 149.5, 60.25));
+        assert_eq!(a.inset(5.0), Rect::new(15.5, 25.0, 90.0, 30.25));
+        assert_eq!(a.offset(0.25, -0.5), Rect::new(10.75, 19.5, 100.0, 40.25));
+        assert!(a.contains(10.5, 60.0) && !a.contains(110.5, 30.0));
+        assert!(a.union(&b).contains_rect(&b) && !a.contains_rect(&b));
+        assert!(a.intersection(&Rect::new(0.0, 0.0, 5.0, 5.0)).is_empty());
+        assert_eq!(a.round(), Rect::new(11.0, 20.0, 100.0, 40.0));
+    }
 }
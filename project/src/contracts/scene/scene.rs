@@ -1,9 +1,21 @@
 // scene.rs for scene module
 
+use std::collections::HashMap;
+
+use super::acetate_io::AcetateStatus;
 use super::metrics::Metrics;
 use super::scene_info::SceneInfo;
 use super::acetate::Acetate;
 use super::acetate_stub::AcetateStub;
+use super::animation::Animation;
+use super::breakpoint::Breakpoint;
+use super::constraint::Constraints;
+use super::layout::LayoutNode;
+use super::sizes::Sizes;
+use super::spring::SpringAnimation;
+use super::transition::Transition;
+
+use crate::contracts::event::Event;
 
 #[derive(Debug, Clone)]
 pub struct Scene {
@@ -11,6 +23,22 @@ pub struct Scene {
     pub height: u32,
     pub metrics: Metrics,
     pub acetates: Vec<Box<dyn Acetate>>,
+    /// Colocación declarativa de los acetates; la resuelve el `LayoutEngine`.
+    pub layout: Option<LayoutNode>,
+    /// Anclas por id de acetate; el `LayoutEngine` las resuelve tras el layout.
+    pub constraints: HashMap<String, Constraints>,
+    /// Variantes por tamaño; el `LayoutEngine` aplica las que coinciden.
+    pub breakpoints: Vec<Breakpoint>,
+    /// Bordes y fuentes relativos a la escena por id de acetate; el `LayoutEngine` los
+    /// resuelve con el tamaño de cada frame.
+    pub sizes: HashMap<String, Sizes>,
+    /// Animaciones en curso; el `Animator` las avanza y las aplica a los acetates.
+    pub animations: Vec<Animation>,
+    /// Animaciones por muelle; se aplican después de `animations`.
+    pub springs: Vec<SpringAnimation>,
+    /// Transiciones implícitas por id de acetate; las interpreta el runtime comparando
+    /// cada frame con el anterior.
+    pub transitions: HashMap<String, Transition>,
 }
 
 impl Scene {
@@ -34,6 +62,130 @@ impl Scene {
             stubs,
         }
     }
+
+    /// Reacción funcional de la escena: entrega cada evento, en orden, a los
+    /// acetatos suscritos y devuelve una escena nueva con los acetatos resultantes.
+    /// El puntero que cae sobre un overlay solo llega al acetate dueño, y con un
+    /// modal presente la entrada no llega a los acetatos por debajo de su z.
+    /// Los acetatos ocultos (`AcetateStatus::Hidden`) no reciben eventos.
+    pub fn react(&self, events: &[Event]) -> Scene {
+        let mut scene = self.clone();
+        scene.apply_status_requests();
+        for event in events {
+            let info = scene.info();
+            let floor = scene.input_floor(event);
+            let owner = scene.pointer_owner(event, floor);
+            scene.acetates = scene
+                .acetates
+                .iter()
+                .enumerate()
+                .map(|(index, acetate)| {
+                    let captured = owner.is_some_and(|owner| owner != index);
+                    let blocked = floor.is_some_and(|floor| acetate.z_index() < floor);
+                    if captured || blocked || is_hidden(acetate.as_ref()) {
+                        return acetate.clone();
+                    }
+                    if !acetate.is_subscribed_to(event) {
+                        return acetate.clone();
+                    }
+                    acetate
+                        .react(event, &acetate.perceive(&info))
+                        .unwrap_or_else(|| acetate.clone())
+                })
+                .collect();
+            scene.apply_status_requests();
+        }
+        scene
+    }
+
+    /// Aplica los `status_requests` de los acetatos visibles. Si dos piden cosas
+    /// distintas para el mismo id gana `Hidden`. Se repite hasta estabilizarse
+    /// porque mostrar un acetate puede activar sus propias peticiones (pestañas anidadas).
+    pub fn apply_status_requests(&mut self) {
+        for _ in 0..=self.acetates.len() {
+            let mut requested: HashMap<String, AcetateStatus> = HashMap::new();
+            for acetate in self.visible() {
+                for (id, status) in acetate.status_requests() {
+                    if requested.get(&id) != Some(&AcetateStatus::Hidden) {
+                        requested.insert(id, status);
+                    }
+                }
+            }
+            if requested.is_empty() {
+                return;
+            }
+
+            let mut changed = false;
+            for acetate in &mut self.acetates {
+                if let Some(status) = requested.get(&acetate.id())
+                    && acetate.output().status != *status
+                {
+                    *acetate = acetate.restatus(status.clone());
+                    changed = true;
+                }
+            }
+            if !changed {
+                return;
+            }
+        }
+    }
+
+    /// Acetatos que no están ocultos.
+    pub fn visible(&self) -> impl Iterator<Item = &Box<dyn Acetate>> + Clone {
+        self.acetates
+            .iter()
+            .filter(|acetate| !is_hidden(acetate.as_ref()))
+    }
+
+    /// z del modal superior, si lo hay.
+    pub fn modal_z_index(&self) -> Option<i32> {
+        self.visible()
+            .filter(|acetate| acetate.is_modal())
+            .map(|acetate| acetate.z_index())
+            .max()
+    }
+
+    /// z mínimo que recibe el evento: solo aplica a la entrada con un modal presente.
+    /// Soltar una tecla llega a todos para que nadie se quede con un modificador pulsado.
+    fn input_floor(&self, event: &Event) -> Option<i32> {
+        if !event.is_input() || matches!(event, Event::KeyReleased(_)) {
+            return None;
+        }
+        self.modal_z_index()
+    }
+
+    /// Índice del acetate cuyo overlay (el de mayor z) contiene la posición del evento.
+    /// Con un popup abierto, un clic fuera de todos los overlays es para el dueño del
+    /// superior: lo cierra y no llega a los acetatos de debajo.
+    fn pointer_owner(&self, event: &Event, floor: Option<i32>) -> Option<usize> {
+        let (x, y) = event.position()?;
+        let overlays: Vec<_> = self
+            .acetates
+            .iter()
+            .enumerate()
+            .filter(|(_, acetate)| !is_hidden(acetate.as_ref()))
+            .filter(|(_, acetate)| floor.is_none_or(|floor| acetate.z_index() >= floor))
+            .flat_map(|(index, acetate)| {
+                acetate
+                    .overlays()
+                    .into_iter()
+                    .map(move |overlay| (index, overlay))
+            })
+            .collect();
+        let inside = overlays
+            .iter()
+            .filter(|(_, overlay)| overlay.design.area.contains(x, y))
+            .max_by_key(|(_, overlay)| overlay.z_index);
+        let outside_click = || {
+            let topmost = overlays.iter().max_by_key(|(_, overlay)| overlay.z_index);
+            topmost.filter(|_| matches!(event, Event::MouseDown { .. }))
+        };
+        inside.or_else(outside_click).map(|(index, _)| *index)
+    }
+}
+
+fn is_hidden(acetate: &dyn Acetate) -> bool {
+    acetate.output().status == AcetateStatus::Hidden
 }
 
 impl From<Vec<Box<dyn Acetate>>> for Scene {
@@ -43,8 +195,8 @@ impl From<Vec<Box<dyn Acetate>>> for Scene {
 
         for acetate in &acetates {
             let area = acetate.area();
-            max_x = max_x.max((area.x.max(0) as u32).saturating_add(area.width));
-            max_y = max_y.max((area.y.max(0) as u32).saturating_add(area.height));
+            max_x = max_x.max(area.right().max(0.0).ceil() as u32);
+            max_y = max_y.max(area.bottom().max(0.0).ceil() as u32);
         }
 
         Scene {
@@ -56,6 +208,13 @@ impl From<Vec<Box<dyn Acetate>>> for Scene {
                 spacing: 0,
             },
             acetates,
+            layout: None,
+            constraints: HashMap::new(),
+            breakpoints: Vec::new(),
+            sizes: HashMap::new(),
+            animations: Vec::new(),
+            springs: Vec::new(),
+            transitions: HashMap::new(),
         }
     }
 }
@@ -0,0 +1,47 @@
+// shape.rs for scene module
+
+use super::rect::Rect;
+use crate::core::Color;
+
+/// Trazo de una forma: color y grosor.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ShapeStroke {
+    pub color: Color,
+    pub width: f32,
+}
+
+/// Forma adicional que un acetate pinta sobre su propio fondo.
+/// Las coordenadas son absolutas (mismo espacio que `AcetateDesign::area`).
+#[derive(Debug, Clone, PartialEq)]
+pub enum Shape {
+    /// Rectángulo relleno: selecciones, cursores, marcas.
+    Rect { area: Rect, fill: Color },
+    /// Rectángulo con esquinas redondeadas (cajas, pistas de interruptores).
+    RoundedRect {
+        area: Rect,
+        radius: f32,
+        fill: Color,
+        stroke: Option<ShapeStroke>,
+    },
+    /// Círculo por centro y radio (botones de radio, perillas).
+    Circle {
+        center: (f32, f32),
+        radius: f32,
+        fill: Color,
+        stroke: Option<ShapeStroke>,
+    },
+    /// Línea abierta por puntos (marcas de verificación, ejes).
+    Polyline {
+        points: Vec<(f32, f32)>,
+        stroke: ShapeStroke,
+    },
+    /// Arco de circunferencia trazado (spinners, anillos de progreso). Ángulos en
+    /// radianes, desde el eje x positivo y en sentido horario (y hacia abajo).
+    Arc {
+        center: (f32, f32),
+        radius: f32,
+        start_angle: f32,
+        sweep_angle: f32,
+        stroke: ShapeStroke,
+    },
+}
@@ -0,0 +1,23 @@
+// sizes.rs for scene module
+
+use super::length::Length;
+
+/// Grosor de borde y tamaño de fuente de un acetate que dependen de la escena. El
+/// `LayoutEngine` los resuelve contra su tamaño en cada frame y los impone con
+/// `Acetate::restyle`, así que siguen a la ventana como las anclas. Aquí el `%` es del
+/// alto de la escena.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Sizes {
+    pub border_thickness: Option<Length>,
+    pub font_size: Option<Length>,
+}
+
+impl Sizes {
+    /// `(borde, fuente)` en unidades lógicas para una escena de `width × height`.
+    pub fn resolve(&self, (width, height): (f32, f32)) -> (Option<f32>, Option<f32>) {
+        let resolve = |length: Option<Length>| {
+            length.map(|length| length.resolve(height, (width, height)).max(0.0))
+        };
+        (resolve(self.border_thickness), resolve(self.font_size))
+    }
+}
@@ -0,0 +1,205 @@
+// spring.rs for scene module
+
+use std::time::Duration;
+
+use super::animation::{AnimatedProperty, AnimationValue};
+use crate::actors::ticker::{FIXED_STEP, fixed_steps};
+
+/// Muelle amortiguado: rigidez, amortiguación y masa en unidades arbitrarias (las de
+/// react-spring). Con `damping² < 4·stiffness·mass` rebota antes de asentarse.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Spring {
+    pub stiffness: f32,
+    pub damping: f32,
+    pub mass: f32,
+}
+
+impl Default for Spring {
+    fn default() -> Self {
+        Self {
+            stiffness: 170.0,
+            damping: 26.0,
+            mass: 1.0,
+        }
+    }
+}
+
+/// Distancia y velocidad (por componente) por debajo de las que el muelle se da por quieto.
+const REST_DISTANCE: f32 = 1e-3;
+const REST_SPEED: f32 = 1e-3;
+/// Tras un parón largo no se simula más de un segundo de golpe.
+const MAX_STEPS: u32 = 120;
+
+/// Animación por muelle, alternativa a `Animation` cuando no hay una duración fija:
+/// soltar un arrastre con su velocidad o cambiar de destino a mitad de camino. Se
+/// integra con el paso fijo del `Ticker` (`FIXED_STEP`), así que el movimiento es el
+/// mismo a cualquier fps.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SpringAnimation {
+    pub target: String,
+    /// Como `Animation::name`: identifica sus eventos de inicio y fin.
+    pub name: String,
+    pub property: AnimatedProperty,
+    pub spring: Spring,
+    /// Destino hacia el que tira el muelle.
+    pub goal: AnimationValue,
+    /// Valor actual, por componentes (ver `AnimationValue::components`).
+    position: Vec<f32>,
+    /// Unidades por segundo de cada componente.
+    velocity: Vec<f32>,
+    /// Tiempo que no llegó a un paso fijo; se suma al siguiente tick.
+    pending: Duration,
+}
+
+impl SpringAnimation {
+    pub fn new(
+        target: &str,
+        property: AnimatedProperty,
+        from: impl Into<AnimationValue>,
+        to: impl Into<AnimationValue>,
+    ) -> Self {
+        let goal = to.into();
+        let position = from.into().components();
+        Self {
+            target: target.to_string(),
+            name: String::new(),
+            property,
+            spring: Spring::default(),
+            goal,
+            velocity: vec![0.0; position.len()],
+            position,
+            pending: Duration::ZERO,
+        }
+    }
+
+    pub fn with_name(mut self, name: &str) -> Self {
+        self.name = name.to_string();
+        self
+    }
+
+    pub fn with_spring(mut self, spring: Spring) -> Self {
+        self.spring = spring;
+        self
+    }
+
+    /// Velocidad inicial en unidades por segundo, del mismo tipo que el valor (p. ej. la
+    /// del puntero al soltar un arrastre).
+    pub fn with_velocity(mut self, velocity: impl Into<AnimationValue>) -> Self {
+        let velocity = velocity.into().components();
+        self.velocity = (0..self.position.len())
+            .map(|index| velocity.get(index).copied().unwrap_or_default())
+            .collect();
+        self
+    }
+
+    /// El mismo muelle tirando hacia `to`: conserva valor y velocidad, así que no salta.
+    pub fn retarget(&self, to: impl Into<AnimationValue>) -> Self {
+        Self {
+            goal: to.into(),
+            ..self.clone()
+        }
+    }
+
+    /// El muelle `dt` más adelante, integrado en pasos fijos.
+    pub fn advance(&self, dt: Duration) -> Self {
+        let mut next = self.clone();
+        let (steps, rest) = fixed_steps(self.pending + dt);
+        next.pending = rest;
+        if self.is_settled() {
+            return next;
+        }
+        let goal = self.goal.components_near(&self.position);
+        let Spring {
+            stiffness,
+            damping,
+            mass,
+        } = self.spring;
+        let h = FIXED_STEP.as_secs_f32();
+        // Euler semiimplícito: estable con cualquier rigidez razonable a 120 Hz.
+        for _ in 0..steps.min(MAX_STEPS) {
+            for ((position, velocity), goal) in
+                next.position.iter_mut().zip(&mut next.velocity).zip(&goal)
+            {
+                let force = -stiffness * (*position - goal) - damping * *velocity;
+                *velocity += force / mass.max(f32::EPSILON) * h;
+                *position += *velocity * h;
+            }
+            if next.is_settled() {
+                next.position = goal;
+                next.velocity
+                    .iter_mut()
+                    .for_each(|velocity| *velocity = 0.0);
+                break;
+            }
+        }
+        next
+    }
+
+    /// En el destino y sin velocidad.
+    pub fn is_settled(&self) -> bool {
+        let goal = self.goal.components_near(&self.position);
+        self.position
+            .iter()
+            .zip(&goal)
+            .all(|(position, goal)| (position - goal).abs() < REST_DISTANCE)
+            && self
+                .velocity
+                .iter()
+                .all(|velocity| velocity.abs() < REST_SPEED)
+    }
+
+    pub fn value(&self) -> AnimationValue {
+        self.goal.with_components(&self.position)
+    }
+
+    /// Velocidad actual, del mismo tipo que el valor.
+    pub fn velocity(&self) -> AnimationValue {
+        self.goal.with_components(&self.velocity)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(spring: &SpringAnimation, frame: Duration, total: Duration) -> SpringAnimation {
+        let mut spring = spring.clone();
+        let mut elapsed = Duration::ZERO;
+        while elapsed < total {
+            spring = spring.advance(frame);
+            elapsed += frame;
+        }
+        spring
+    }
+
+    #[test]
+    fn springs_settle_independently_of_frame_rate_and_retarget_smoothly() {
+        let release =
+            SpringAnimation::new("card", AnimatedProperty::X, 0.0, 100.0).with_velocity(-400.0);
+        let at_60 = run(
+            &release,
+            Duration::from_nanos(1_000_000_000 / 60),
+            Duration::from_millis(250),
+        );
+        let at_30 = run(
+            &release,
+            Duration::from_nanos(1_000_000_000 / 30),
+            Duration::from_millis(250),
+        );
+        let (AnimationValue::Number(a), AnimationValue::Number(b)) = (at_60.value(), at_30.value())
+        else {
+            panic!("numbers");
+        };
+        assert!((a - b).abs() < 1e-3, "{a} != {b}");
+        assert!(a > 0.0 && a < 100.0);
+
+        // Cambiar de destino conserva la posición y la velocidad.
+        let snapped = at_60.retarget(50.0);
+        assert_eq!(snapped.value(), at_60.value());
+        assert_eq!(snapped.velocity(), at_60.velocity());
+
+        let settled = run(&snapped, Duration::from_millis(16), Duration::from_secs(3));
+        assert!(settled.is_settled());
+        assert_eq!(settled.value(), AnimationValue::Number(50.0));
+    }
+}
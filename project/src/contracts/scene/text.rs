@@ -0,0 +1,26 @@
+// text.rs for scene module
+
+/// Ancho de cada carácter en unidades de `font_size`: la fuente incluida es
+/// monoespaciada, así que medir un texto es contar caracteres.
+pub const CHAR_WIDTH: f32 = 0.6;
+/// Alto de cada línea en unidades de `font_size`.
+pub const LINE_HEIGHT: f32 = 1.25;
+
+/// Alineación horizontal de cada línea dentro de la caja del texto.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TextAlign {
+    #[default]
+    Start,
+    Center,
+    End,
+}
+
+/// Ancho de la línea más larga de `text` con la fuente incluida.
+pub fn text_width(text: &str, font_size: f32) -> f32 {
+    let columns = text
+        .lines()
+        .map(|line| line.chars().count())
+        .max()
+        .unwrap_or(0);
+    columns as f32 * font_size * CHAR_WIDTH
+}
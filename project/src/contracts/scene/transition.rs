@@ -0,0 +1,72 @@
+// transition.rs for scene module
+
+use std::time::Duration;
+
+use super::animation::{AnimatedProperty, Animation, AnimationValue, Easing, Keyframe};
+
+/// Transición implícita de un acetate (ver `Scene::transitions`): cuando una de sus
+/// propiedades cambia de un frame al siguiente, el runtime la interpola en lugar de
+/// saltar, como `transition` en CSS.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Transition {
+    /// Propiedades que se interpolan; vacío equivale a todas.
+    pub properties: Vec<AnimatedProperty>,
+    pub duration: Duration,
+    /// Espera antes de empezar; mientras tanto se sigue viendo el valor anterior.
+    pub delay: Duration,
+    pub easing: Easing,
+}
+
+impl Transition {
+    /// Todas las propiedades, con la curva por defecto.
+    pub fn new(duration: Duration) -> Self {
+        Self {
+            properties: Vec::new(),
+            duration,
+            delay: Duration::ZERO,
+            easing: Easing::default(),
+        }
+    }
+
+    pub fn with_properties(
+        mut self,
+        properties: impl IntoIterator<Item = AnimatedProperty>,
+    ) -> Self {
+        self.properties = properties.into_iter().collect();
+        self
+    }
+
+    pub fn with_delay(mut self, delay: Duration) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    pub fn with_easing(mut self, easing: Easing) -> Self {
+        self.easing = easing;
+        self
+    }
+
+    pub fn covers(&self, property: AnimatedProperty) -> bool {
+        self.properties.is_empty() || self.properties.contains(&property)
+    }
+
+    /// Animación de `from` a `to` para `target`. El retraso se cuenta dentro de la
+    /// animación, manteniendo `from`, para que no se vea el valor nuevo antes de tiempo.
+    pub fn animate(
+        &self,
+        target: &str,
+        property: AnimatedProperty,
+        from: AnimationValue,
+        to: AnimationValue,
+    ) -> Animation {
+        let total = self.delay + self.duration;
+        let start = if total.is_zero() {
+            0.0
+        } else {
+            self.delay.as_secs_f32() / total.as_secs_f32()
+        };
+        let keyframes = [(0.0, from), (start, from), (1.0, to)]
+            .map(|(offset, value)| Keyframe { offset, value });
+        Animation::keyframes(target, property, keyframes, total).with_easing(self.easing)
+    }
+}
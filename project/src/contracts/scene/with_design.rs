@@ -0,0 +1,44 @@
+//! Diseño impuesto desde fuera a un acetate (ver `Animator::apply`).
+
+use super::acetate::Acetate;
+use super::animation::{AnimatedProperty, AnimationValue};
+use super::decorated::{Decorated, Decoration};
+use super::design::AcetateDesign;
+
+/// Acetate con las propiedades visuales animadas de `design()` impuestas: relleno,
+/// color de borde, opacidad y transformación. La geometría no pasa por aquí: se
+/// anima con `relocate`.
+pub type WithDesign = Decorated<DesignOverride>;
+
+/// En orden de aplicación: si una propiedad se repite gana la última.
+#[derive(Debug, Clone)]
+pub struct DesignOverride(pub Vec<(AnimatedProperty, AnimationValue)>);
+
+impl WithDesign {
+    pub fn new(inner: Box<dyn Acetate>, values: Vec<(AnimatedProperty, AnimationValue)>) -> Self {
+        Decorated::decorate(inner, DesignOverride(values))
+    }
+}
+
+impl Decoration for DesignOverride {
+    fn design(&self, mut design: AcetateDesign) -> AcetateDesign {
+        for (property, value) in &self.0 {
+            match (property, value) {
+                (AnimatedProperty::Fill, AnimationValue::Color(color)) => {
+                    design.background = *color
+                }
+                (AnimatedProperty::BorderColor, AnimationValue::Color(color)) => {
+                    design.border = *color
+                }
+                (AnimatedProperty::Opacity, AnimationValue::Number(opacity)) => {
+                    design.opacity = opacity.clamp(0.0, 1.0)
+                }
+                (AnimatedProperty::Transform, AnimationValue::Transform(transform)) => {
+                    design.transform = *transform
+                }
+                _ => {}
+            }
+        }
+        design
+    }
+}
@@ -0,0 +1,51 @@
+//! Borde y fuente impuestos desde fuera a un acetate (ver `Acetate::restyle`).
+
+use super::acetate::Acetate;
+use super::decorated::{Decorated, Decoration};
+use super::design::AcetateDesign;
+
+/// Acetate con `design().border_thickness` y `design().font_size` impuestos. Es la
+/// implementación por defecto de `Acetate::restyle`.
+pub type WithSizes = Decorated<SizeOverride>;
+
+#[derive(Debug, Clone)]
+pub struct SizeOverride {
+    pub border_thickness: Option<f32>,
+    pub font_size: Option<f32>,
+}
+
+impl WithSizes {
+    pub fn new(
+        inner: Box<dyn Acetate>,
+        border_thickness: Option<f32>,
+        font_size: Option<f32>,
+    ) -> Self {
+        Decorated::decorate(
+            inner,
+            SizeOverride {
+                border_thickness,
+                font_size,
+            },
+        )
+    }
+}
+
+impl Decoration for SizeOverride {
+    fn design(&self, design: AcetateDesign) -> AcetateDesign {
+        AcetateDesign {
+            border_thickness: self.border_thickness.unwrap_or(design.border_thickness),
+            font_size: self.font_size.unwrap_or(design.font_size),
+            ..design
+        }
+    }
+
+    /// Otro restyle sustituye al anterior en vez de anidarse.
+    fn restyle(
+        decorated: &WithSizes,
+        border_thickness: Option<f32>,
+        font_size: Option<f32>,
+    ) -> Box<dyn Acetate> {
+        let inner = decorated.inner().clone_box();
+        Box::new(WithSizes::new(inner, border_thickness, font_size))
+    }
+}
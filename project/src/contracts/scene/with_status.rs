@@ -0,0 +1,36 @@
+//! Estado impuesto desde fuera a un acetate (ver `Acetate::restatus`).
+
+use super::acetate::Acetate;
+use super::acetate_io::{AcetateIO, AcetateStatus};
+use super::decorated::{Decorated, Decoration};
+
+/// Acetate con `output().status` impuesto (ver `Acetate::status_requests`).
+/// Es la implementación por defecto de `Acetate::restatus`.
+pub type WithStatus = Decorated<StatusOverride>;
+
+#[derive(Debug, Clone)]
+pub struct StatusOverride(pub AcetateStatus);
+
+impl WithStatus {
+    pub fn new(inner: Box<dyn Acetate>, status: AcetateStatus) -> Self {
+        Decorated::decorate(inner, StatusOverride(status))
+    }
+}
+
+impl Decoration for StatusOverride {
+    fn output(&self, output: AcetateIO) -> AcetateIO {
+        AcetateIO {
+            status: self.0.clone(),
+            ..output
+        }
+    }
+
+    /// Volver al estado propio del acetate quita el envoltorio.
+    fn restatus(decorated: &WithStatus, status: AcetateStatus) -> Box<dyn Acetate> {
+        let inner = decorated.inner();
+        if inner.output().status == status {
+            return inner.clone_box();
+        }
+        Box::new(WithStatus::new(inner.clone_box(), status))
+    }
+}
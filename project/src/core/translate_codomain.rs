@@ -1,11 +1,16 @@
 //! codominio module for snapshot_builder
 
+use crate::contracts::scene::{DrawCommand, Shape, TextAlign, Transform};
+
 // TODO: implement
 /// Representa una proyección visual funcional de la Scene,
 /// lista para ser pasada al Renderer (y renderizada con Vello).
 #[derive(Debug, Clone)]
 pub struct Snapshot {
     pub layers: Vec<SnapshotLayer>,
+    /// Píxeles físicos por unidad lógica: las capas están en unidades lógicas y el
+    /// adaptador escala geometría, trazos y texto al pintar.
+    pub scale: f32,
 }
 
 /// Una capa visual dentro del Snapshot.
@@ -15,6 +20,16 @@ pub struct SnapshotLayer {
     pub z_index: i32,
     pub area: Rect,
     pub style: SnapshotStyle,
+    /// Formas extra del acetate, pintadas sobre el fondo de la capa.
+    pub shapes: Vec<Shape>,
+    /// Comandos de dibujo libre, en coordenadas locales al área de la capa.
+    pub commands: Vec<DrawCommand>,
+    /// Recorte de la capa (área del acetate padre); `None` pinta sin recortar.
+    pub clip: Option<Rect>,
+    /// Opacidad de la capa entera, ya multiplicada por la de sus ancestros.
+    pub opacity: f32,
+    /// Transformación absoluta de la capa (pivote y ancestros ya compuestos).
+    pub transform: Transform,
 }
 
 /// Información visual para pintar una capa.
@@ -24,22 +39,46 @@ pub struct SnapshotStyle {
     pub border_color: Color,
     pub border_thickness: f32,
     pub text: Option<String>,
+    pub text_color: Color,
+    pub font_size: f32,
+    pub text_align: TextAlign,
+    /// Caja del texto, ya resuelta (el área de la capa si el diseño no da otra).
+    pub text_box: Rect,
+    /// Alto de línea, ya resuelto.
+    pub line_height: f32,
 }
 
-/// Área rectangular (reutilizable en Scene y Snapshot).
-#[derive(Debug, Clone)]
-pub struct Rect {
-    pub x: i32,
-    pub y: i32,
-    pub width: u32,
-    pub height: u32,
-}
+/// El Snapshot comparte el área de la Scene: mismas coordenadas lógicas con decimales.
+pub use crate::contracts::scene::Rect;
 
 /// Color RGBA funcional.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Color {
     pub r: f32,
     pub g: f32,
     pub b: f32,
     pub a: f32,
 }
+
+impl Color {
+    pub const TRANSPARENT: Color = Color::rgba(0.0, 0.0, 0.0, 0.0);
+
+    pub const fn rgba(r: f32, g: f32, b: f32, a: f32) -> Self {
+        Self { r, g, b, a }
+    }
+
+    pub const fn with_alpha(self, a: f32) -> Self {
+        Self { a, ..self }
+    }
+
+    /// Interpolación lineal por canal: `t = 0` es `self`, `t = 1` es `other`.
+    pub fn mix(self, other: Color, t: f32) -> Self {
+        let lerp = |a: f32, b: f32| a + (b - a) * t;
+        Self {
+            r: lerp(self.r, other.r),
+            g: lerp(self.g, other.g),
+            b: lerp(self.b, other.b),
+            a: lerp(self.a, other.a),
+        }
+    }
+}
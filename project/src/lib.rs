@@ -1,5 +1,7 @@
+
 pub mod contracts;
 pub mod core;
 pub mod actors;
+pub mod acetates;
 pub mod ui_toml;
 pub mod runtime;
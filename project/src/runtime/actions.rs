@@ -0,0 +1,38 @@
+use std::collections::HashMap;
+
+use crate::contracts::event::Event;
+use crate::contracts::scene::Scene;
+
+/// Recoge las acciones nuevas (por `serial`) que exponen los acetatos
+/// y las convierte en `Event::Action` para el siguiente frame.
+#[derive(Debug, Clone, Default)]
+pub struct ActionCollector {
+    delivered: HashMap<String, u64>,
+}
+
+impl ActionCollector {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn collect(&mut self, scene: &Scene) -> Vec<Event> {
+        let mut events = Vec::new();
+        let mut delivered = HashMap::new();
+        for acetate in &scene.acetates {
+            let Some(emitted) = acetate.output().action else {
+                continue;
+            };
+            let id = acetate.id();
+            if self.delivered.get(&id) != Some(&emitted.serial) {
+                events.push(Event::Action {
+                    source: id.clone(),
+                    action: emitted.action,
+                });
+            }
+            delivered.insert(id, emitted.serial);
+        }
+        // Igual que en el portapapeles: un acetate reconstruido vuelve a empezar su serial.
+        self.delivered = delivered;
+        events
+    }
+}
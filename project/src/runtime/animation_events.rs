@@ -0,0 +1,167 @@
+use std::collections::HashMap;
+
+use crate::contracts::event::Event;
+use crate::contracts::scene::{AnimatedProperty, Scene};
+
+/// Estado de una animación visto en el frame anterior.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+struct Seen {
+    /// Iteración en curso; `None` durante el retraso (o muelle quieto).
+    iteration: Option<u32>,
+    finished: bool,
+}
+
+/// Compara las animaciones y muelles de la escena de la app con los del frame
+/// anterior, por `(target, name, property)`, y convierte los cambios en
+/// `Event::AnimationStarted`/`AnimationIteration`/`AnimationFinished` para el
+/// siguiente frame.
+#[derive(Debug, Clone, Default)]
+pub struct AnimationEventCollector {
+    seen: HashMap<(String, String, AnimatedProperty), Seen>,
+}
+
+impl AnimationEventCollector {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn collect(&mut self, scene: &Scene) -> Vec<Event> {
+        let mut events = Vec::new();
+        let mut seen = HashMap::new();
+        let animations = scene.animations.iter().map(|animation| {
+            let key = (
+                animation.target.clone(),
+                animation.name.clone(),
+                animation.property,
+            );
+            let now = Seen {
+                iteration: animation.iteration(),
+                finished: animation.is_finished(),
+            };
+            (key, now)
+        });
+        // Un muelle empieza cuando deja de estar quieto y termina al asentarse.
+        let springs = scene.springs.iter().map(|spring| {
+            let key = (spring.target.clone(), spring.name.clone(), spring.property);
+            let settled = spring.is_settled();
+            let now = Seen {
+                iteration: (!settled).then_some(0),
+                finished: settled,
+            };
+            (key, now)
+        });
+
+        for (key, now) in animations.chain(springs) {
+            let before = self.seen.get(&key).copied();
+            let restarted = before.is_some_and(|before| {
+                !now.finished && (now.iteration < before.iteration || before.finished)
+            });
+            // Una animación nueva o reiniciada (`elapsed` hacia atrás) cuenta desde cero;
+            // un muelle que aparece ya quieto no emite nada.
+            let before = match before {
+                Some(before) if !restarted => before,
+                _ if now.finished && now.iteration.is_none() => now,
+                _ => Seen::default(),
+            };
+            let (target, name, _) = &key;
+            if before.iteration.is_none() && now.iteration.is_some() {
+                events.push(Event::AnimationStarted {
+                    target: target.clone(),
+                    name: name.clone(),
+                });
+            }
+            if let (Some(from), Some(to)) = (before.iteration, now.iteration) {
+                // Si un frame largo se salta vueltas, se avisa de cada una.
+                for iteration in from..to {
+                    events.push(Event::AnimationIteration {
+                        target: target.clone(),
+                        name: name.clone(),
+                        iteration,
+                    });
+                }
+            }
+            if now.finished && !before.finished {
+                events.push(Event::AnimationFinished {
+                    target: target.clone(),
+                    name: name.clone(),
+                });
+            }
+            seen.insert(key, now);
+        }
+        // Las que la app quitó se olvidan: si vuelven, empiezan de nuevo.
+        self.seen = seen;
+        events
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+    use crate::contracts::scene::{Animation, Repeat, SpringAnimation};
+
+    #[test]
+    fn animations_report_start_iterations_and_finish_once() {
+        let fade = Animation::tween(
+            "card",
+            AnimatedProperty::Opacity,
+            0.0,
+            1.0,
+            Duration::from_millis(100),
+        )
+        .with_name("fade")
+        .with_delay(Duration::from_millis(50))
+        .with_repeat(Repeat::Times(3));
+        let mut scene = Scene {
+            animations: vec![fade],
+            springs: vec![SpringAnimation::new("card", AnimatedProperty::X, 0.0, 0.0)],
+            ..Scene::from(Vec::new())
+        };
+        let mut collector = AnimationEventCollector::new();
+        let started = Event::AnimationStarted {
+            target: "card".into(),
+            name: "fade".into(),
+        };
+        let iteration = |iteration| Event::AnimationIteration {
+            target: "card".into(),
+            name: "fade".into(),
+            iteration,
+        };
+        let finished = Event::AnimationFinished {
+            target: "card".into(),
+            name: "fade".into(),
+        };
+
+        let mut step = |scene: &mut Scene, ms: u64| {
+            scene.animations = scene
+                .animations
+                .iter()
+                .map(|animation| animation.advance(Duration::from_millis(ms)))
+                .collect();
+            collector.collect(scene)
+        };
+        // Durante el retraso no pasa nada; el muelle ya quieto tampoco avisa.
+        assert!(step(&mut scene, 0).is_empty());
+        assert_eq!(step(&mut scene, 60), vec![started.clone()]);
+        assert!(step(&mut scene, 40).is_empty());
+        assert_eq!(step(&mut scene, 60), vec![iteration(0)]);
+        // Un frame largo cruza la última vuelta y el final a la vez.
+        assert_eq!(step(&mut scene, 500), vec![iteration(1), finished]);
+        assert!(step(&mut scene, 16).is_empty());
+
+        // Reiniciarla (`elapsed` a cero) vuelve a contar desde el principio.
+        scene.animations[0].elapsed = Duration::ZERO;
+        assert_eq!(step(&mut scene, 60), vec![started]);
+
+        scene.springs[0] = scene.springs[0].retarget(100.0).with_name("slide");
+        let slide = collector.collect(&scene);
+        assert_eq!(
+            slide,
+            vec![Event::AnimationStarted {
+                target: "card".into(),
+                name: "slide".into(),
+            }]
+        );
+    }
+}
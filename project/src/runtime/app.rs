@@ -3,6 +3,7 @@ use std::time::{Duration, Instant};
 
 use crate::contracts::event::{Event, MouseButton};
 use crate::contracts::scene::Scene;
+use crate::runtime::tooltips::DEFAULT_TOOLTIP_DELAY;
 
 #[derive(Debug, Clone)]
 pub struct InputState {
@@ -11,8 +12,11 @@ pub struct InputState {
     pub mouse_buttons_down: HashSet<MouseButton>,
     pub keys_down: HashSet<String>,
     pub text_buffer: Option<String>,
+    /// Tamaño de la ventana en unidades lógicas (píxeles físicos / `scale_factor`).
     pub window_width: u32,
     pub window_height: u32,
+    /// Píxeles físicos por unidad lógica (2.0 en pantallas HiDPI típicas).
+    pub scale_factor: f64,
 }
 
 impl Default for InputState {
@@ -25,6 +29,7 @@ impl Default for InputState {
             text_buffer: None,
             window_width: 1,
             window_height: 1,
+            scale_factor: 1.0,
         }
     }
 }
@@ -34,8 +39,11 @@ pub struct FrameContext {
     pub tick_number: u64,
     pub dt: Duration,
     pub timestamp: Instant,
+    /// Tamaño de la ventana en unidades lógicas, las de la escena.
     pub window_width: u32,
     pub window_height: u32,
+    /// Píxeles físicos por unidad lógica; el runtime lo copia a `Metrics::scale`.
+    pub scale_factor: f64,
     pub fps: Option<f64>,
 }
 
@@ -43,6 +51,7 @@ pub struct FrameContext {
 pub struct InputWants {
     pub mouse_move: bool,
     pub mouse_buttons: bool,
+    pub scroll: bool,
     pub keyboard: bool,
     pub text_input: bool,
     pub tick: bool,
@@ -54,6 +63,7 @@ impl Default for InputWants {
         Self {
             mouse_move: false,
             mouse_buttons: false,
+            scroll: false,
             keyboard: false,
             text_input: false,
             tick: false,
@@ -67,5 +77,18 @@ pub trait App {
         InputWants::default()
     }
 
+    /// Tiempo con el puntero quieto sobre un acetate antes de mostrar su tooltip.
+    fn tooltip_delay(&self) -> Duration {
+        DEFAULT_TOOLTIP_DELAY
+    }
+
+    /// Escena del frame. No hace falta colocarla: el runtime le da el tamaño de la
+    /// ventana y aplica el `LayoutEngine` (breakpoints, `Scene::layout` y anclas) antes
+    /// de animarla y pintarla.
     fn frame(&mut self, events: &[Event], ctx: &FrameContext, input: &InputState) -> Scene;
+
+    /// La escena de `frame` ya colocada, antes de animarla. Una app que conserve sus
+    /// acetatos entre frames los guarda aquí para reaccionar al siguiente con las
+    /// mismas áreas que se pintaron (como `TomlApp`).
+    fn arranged(&mut self, _scene: &Scene) {}
 }
@@ -0,0 +1,212 @@
+use std::collections::HashMap;
+use std::fmt;
+
+use crate::contracts::event::{Event, keys};
+use crate::contracts::scene::Scene;
+
+/// Acceso al portapapeles de texto del host.
+pub trait Clipboard {
+    fn get_text(&mut self) -> Option<String>;
+    fn set_text(&mut self, text: &str) -> Result<(), String>;
+}
+
+/// Portapapeles en memoria: para tests y ejecución sin ventana.
+#[derive(Debug, Clone, Default)]
+pub struct MemoryClipboard {
+    text: Option<String>,
+}
+
+impl MemoryClipboard {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Clipboard for MemoryClipboard {
+    fn get_text(&mut self) -> Option<String> {
+        self.text.clone()
+    }
+
+    fn set_text(&mut self, text: &str) -> Result<(), String> {
+        self.text = Some(text.to_string());
+        Ok(())
+    }
+}
+
+/// Portapapeles del sistema operativo (vía `arboard`).
+/// Si la plataforma no lo ofrece, se degrada a uno en memoria.
+pub struct SystemClipboard {
+    native: Option<arboard::Clipboard>,
+    fallback: MemoryClipboard,
+}
+
+impl SystemClipboard {
+    pub fn new() -> Self {
+        let native = match arboard::Clipboard::new() {
+            Ok(clipboard) => Some(clipboard),
+            Err(err) => {
+                eprintln!("System clipboard unavailable, using in-memory clipboard: {err}");
+                None
+            }
+        };
+        Self {
+            native,
+            fallback: MemoryClipboard::new(),
+        }
+    }
+}
+
+impl Default for SystemClipboard {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for SystemClipboard {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SystemClipboard")
+            .field("native", &self.native.is_some())
+            .finish()
+    }
+}
+
+impl Clipboard for SystemClipboard {
+    fn get_text(&mut self) -> Option<String> {
+        match self.native.as_mut() {
+            Some(native) => native.get_text().ok(),
+            None => self.fallback.get_text(),
+        }
+    }
+
+    fn set_text(&mut self, text: &str) -> Result<(), String> {
+        match self.native.as_mut() {
+            Some(native) => native
+                .set_text(text.to_string())
+                .map_err(|err| format!("clipboard write error: {err}")),
+            None => self.fallback.set_text(text),
+        }
+    }
+}
+
+/// Puente entre la escena y el portapapeles:
+/// aplica las peticiones de copiar/cortar de los acetatos y genera `Event::Paste`.
+#[derive(Debug, Clone, Default)]
+pub struct ClipboardBridge {
+    applied: HashMap<String, u64>,
+}
+
+impl ClipboardBridge {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Escribe en el portapapeles las peticiones nuevas (por `serial`) de cada acetate.
+    pub fn sync(&mut self, scene: &Scene, clipboard: &mut dyn Clipboard) {
+        let mut applied = HashMap::new();
+        for acetate in &scene.acetates {
+            let Some(request) = acetate.output().clipboard else {
+                continue;
+            };
+            let id = acetate.id();
+            if self.applied.get(&id) != Some(&request.serial())
+                && let Err(err) = clipboard.set_text(request.text())
+            {
+                eprintln!("{err}");
+            }
+            applied.insert(id, request.serial());
+        }
+        // Solo se recuerdan los acetatos presentes: uno reconstruido vuelve a empezar su serial.
+        self.applied = applied;
+    }
+
+    /// `Event::Paste` si la tecla pulsada es el atajo de pegar (Ctrl/Cmd+V).
+    pub fn paste_event(
+        key: &str,
+        control_down: bool,
+        clipboard: &mut dyn Clipboard,
+    ) -> Option<Event> {
+        if !control_down || !keys::is_char_key(key, 'v') {
+            return None;
+        }
+        clipboard.get_text().map(Event::Paste)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::acetates::TextField;
+    use crate::contracts::event::{Event, MouseButton};
+    use crate::contracts::scene::{Acetate, Rect};
+
+    fn press(key: &str) -> Event {
+        Event::KeyPressed(key.to_string())
+    }
+
+    /// Escena con un campo recién creado que ya copió todo su texto.
+    fn copied(text: &str) -> Scene {
+        let field = TextField::new("name", Rect::new(0.0, 0.0, 200.0, 30.0)).with_value(text);
+        Scene::from(vec![Box::new(field) as Box<dyn Acetate>]).react(&[
+            Event::MouseDown { button: MouseButton::Left, x: 5, y: 10 },
+            Event::MouseUp { button: MouseButton::Left, x: 5, y: 10 },
+            press(keys::CONTROL),
+            press("a"),
+            press("c"),
+        ])
+    }
+
+    #[test]
+    fn copy_is_applied_once_and_pasted_into_focused_field() {
+        let field = TextField::new("name", Rect::new(0.0, 0.0, 200.0, 30.0))
+            .with_value("hola mundo");
+        let target = TextField::new("other", Rect::new(0.0, 40.0, 200.0, 30.0));
+        let scene = Scene::from(vec![
+            Box::new(field) as Box<dyn Acetate>,
+            Box::new(target) as Box<dyn Acetate>,
+        ]);
+
+        let scene = scene.react(&[
+            Event::MouseDown { button: MouseButton::Left, x: 5, y: 10 },
+            Event::MouseUp { button: MouseButton::Left, x: 5, y: 10 },
+            press(keys::CONTROL),
+            press("a"),
+            press("c"),
+        ]);
+
+        let mut clipboard = MemoryClipboard::new();
+        let mut bridge = ClipboardBridge::new();
+        bridge.sync(&scene, &mut clipboard);
+        assert_eq!(clipboard.get_text().as_deref(), Some("hola mundo"));
+
+        clipboard.set_text("otra cosa").unwrap();
+        bridge.sync(&scene, &mut clipboard);
+        assert_eq!(clipboard.get_text().as_deref(), Some("otra cosa"));
+
+        let paste = ClipboardBridge::paste_event("v", true, &mut clipboard).unwrap();
+        let scene = scene.react(&[
+            Event::KeyReleased(keys::CONTROL.to_string()),
+            Event::MouseDown { button: MouseButton::Left, x: 5, y: 50 },
+            paste,
+        ]);
+        let values: Vec<_> = scene
+            .acetates
+            .iter()
+            .map(|a| a.output().content.unwrap_or_default())
+            .collect();
+        assert_eq!(values, ["hola mundo", "otra cosa"]);
+    }
+
+    #[test]
+    fn rebuilt_acetates_restart_their_serial() {
+        let mut clipboard = MemoryClipboard::new();
+        let mut bridge = ClipboardBridge::new();
+        bridge.sync(&copied("primero"), &mut clipboard);
+        assert_eq!(clipboard.get_text().as_deref(), Some("primero"));
+
+        // Sin el campo, el puente lo olvida; el reconstruido vuelve a empezar en el
+        // mismo serial y su copia se aplica igualmente.
+        bridge.sync(&Scene::from(Vec::new()), &mut clipboard);
+        bridge.sync(&copied("segundo"), &mut clipboard);
+        assert_eq!(clipboard.get_text().as_deref(), Some("segundo"));
+    }
+}
@@ -0,0 +1,100 @@
+use crate::actors::layout_engine::{ConstraintError, LayoutEngine};
+use crate::contracts::scene::Scene;
+
+/// Paso de layout del runtime entre `App::frame` y el `SnapshotBuilder`: da a la
+/// escena el tamaño lógico de la ventana y le aplica el `LayoutEngine` (breakpoints,
+/// `Scene::layout` y anclas). Si las anclas no tienen solución se avisa una vez por
+/// error distinto y se pinta la escena sin anclar.
+#[derive(Debug, Clone, Default)]
+pub struct LayoutPass {
+    reported: Option<ConstraintError>,
+}
+
+impl LayoutPass {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn apply(&mut self, scene: Scene, window: (u32, u32)) -> Scene {
+        let scene = LayoutEngine::arrange(Scene {
+            width: window.0,
+            height: window.1,
+            ..scene
+        });
+        match LayoutEngine::anchor(&scene) {
+            Ok(anchored) => {
+                self.reported = None;
+                anchored
+            }
+            Err(err) => {
+                if self.reported.as_ref() != Some(&err) {
+                    eprintln!("❌ layout error: {err}");
+                    self.reported = Some(err);
+                }
+                scene
+            }
+        }
+    }
+
+    /// Error de anclas del último frame, si lo hubo.
+    pub fn error(&self) -> Option<&ConstraintError> {
+        self.reported.as_ref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::acetates::Button;
+    use crate::contracts::scene::{
+        Acetate, Anchor, Breakpoint, Constraints, Edge, LayoutNode, Rect,
+    };
+
+    #[test]
+    fn custom_scenes_get_layout_breakpoints_and_anchors() {
+        let button = |id: &str| {
+            Box::new(Button::new(id, Rect::new(0.0, 0.0, 10.0, 10.0))) as Box<dyn Acetate>
+        };
+        let scene = Scene {
+            layout: Some(LayoutNode::row([
+                LayoutNode::acetate("left").with_grow(1.0),
+                LayoutNode::acetate("right").with_grow(1.0),
+            ])),
+            breakpoints: vec![
+                Breakpoint::new("narrow")
+                    .with_max_width(300)
+                    .with_layout(LayoutNode::row([LayoutNode::acetate("left").with_grow(1.0)]))
+                    .with_hidden("right"),
+            ],
+            ..Scene::from(vec![button("left"), button("right")])
+        };
+        let mut pass = LayoutPass::new();
+
+        let wide = pass.apply(scene.clone(), (400, 100));
+        assert_eq!((wide.width, wide.height), (400, 100));
+        assert_eq!(wide.acetates[0].area(), Rect::new(0.0, 0.0, 200.0, 100.0));
+        assert_eq!(wide.acetates[1].area(), Rect::new(200.0, 0.0, 200.0, 100.0));
+
+        let narrow = pass.apply(scene.clone(), (200, 100));
+        assert_eq!(narrow.acetates.len(), 1);
+        assert_eq!(narrow.acetates[0].area(), Rect::new(0.0, 0.0, 200.0, 100.0));
+
+        // Sin layout, el ancla coloca; si apunta a un acetate que no existe, se pinta
+        // sin anclar y el error queda avisado.
+        let anchored = |target: &str| Scene {
+            layout: None,
+            constraints: [(
+                "left".to_string(),
+                Constraints::default().with_left(Anchor::acetate(target, Edge::Right)),
+            )]
+            .into(),
+            ..scene.clone()
+        };
+        let placed = pass.apply(anchored("right"), (400, 100));
+        assert_eq!(placed.acetates[0].area().x, 10.0);
+        assert!(pass.error().is_none());
+        let painted = pass.apply(anchored("ghost"), (400, 100));
+        assert_eq!(painted.acetates[0].area(), Rect::new(0.0, 0.0, 10.0, 10.0));
+        assert!(pass.error().is_some());
+    }
+}
@@ -1,7 +1,19 @@
+pub mod actions;
+pub mod animation_events;
 pub mod app;
+pub mod clipboard;
+pub mod layout;
 pub mod toml_app;
+pub mod tooltips;
+pub mod transitions;
 pub mod winit_runtime;
 
+pub use actions::ActionCollector;
+pub use animation_events::AnimationEventCollector;
 pub use app::{App, FrameContext, InputState, InputWants};
+pub use clipboard::{Clipboard, ClipboardBridge, MemoryClipboard, SystemClipboard};
+pub use layout::LayoutPass;
 pub use toml_app::TomlApp;
+pub use tooltips::{DEFAULT_TOOLTIP_DELAY, TooltipTracker};
+pub use transitions::TransitionTracker;
 pub use winit_runtime::*;
@@ -1,10 +1,17 @@
+use std::collections::HashMap;
 use std::path::Path;
+use std::sync::Arc;
 use std::time::{Duration, Instant, SystemTime};
 
-use crate::contracts::event::Event;
-use crate::contracts::scene::{Acetate, AcetateDesign, AcetateIO, AcetateStatus, Rect, Scene, SceneInfo};
+use crate::actors::animator::Animator;
+use crate::contracts::event::{Data, Event, Series};
+use crate::contracts::scene::{
+    Acetate, AcetateDesign, AcetateIO, AcetateStatus, Animation, Breakpoint, Constraints,
+    LayoutNode, Metrics, Rect, Scene, SceneInfo, Sizes, SpringAnimation, Transition,
+};
 use crate::core::Color;
 use crate::runtime::app::{App, FrameContext, InputState, InputWants};
+use crate::runtime::tooltips::DEFAULT_TOOLTIP_DELAY;
 use crate::ui_toml::load_scene_from_file;
 
 const BG_Z_INDEX: i32 = -100_000;
@@ -24,7 +31,7 @@ impl SimpleAcetate {
             id: id.to_string(),
             name: name.to_string(),
             z_index,
-            area: design.area.clone(),
+            area: design.area,
             design,
         }
     }
@@ -44,7 +51,17 @@ impl Acetate for SimpleAcetate {
     }
 
     fn area(&self) -> Rect {
-        self.area.clone()
+        self.area
+    }
+
+    fn relocate(&self, area: Rect) -> Box<dyn Acetate> {
+        let mut design = self.design.clone();
+        design.area = area;
+        Box::new(Self {
+            area,
+            design,
+            ..self.clone()
+        })
     }
 
     fn subscriptions(&self) -> Vec<crate::contracts::event::EventKind> {
@@ -64,6 +81,8 @@ impl Acetate for SimpleAcetate {
             content: None,
             focus: false,
             status: AcetateStatus::Ready,
+            clipboard: None,
+            action: None,
         }
     }
 
@@ -82,11 +101,22 @@ struct LayerTemplate {
     name: String,
     z_index: i32,
     design: AcetateDesign,
+    /// Acetate interactivo (botón, campo...) que conserva su estado entre frames.
+    live: Option<Box<dyn Acetate>>,
 }
 
 #[derive(Clone, Default)]
 struct SceneTemplate {
     layers: Vec<LayerTemplate>,
+    metrics: Metrics,
+    layout: Option<LayoutNode>,
+    constraints: HashMap<String, Constraints>,
+    breakpoints: Vec<Breakpoint>,
+    sizes: HashMap<String, Sizes>,
+    /// Animaciones en curso; avanzan con cada tick y sobreviven a la recarga del frame.
+    animations: Vec<Animation>,
+    springs: Vec<SpringAnimation>,
+    transitions: HashMap<String, Transition>,
 }
 
 impl SceneTemplate {
@@ -99,12 +129,44 @@ impl SceneTemplate {
                 name: a.name(),
                 z_index: a.z_index(),
                 design: a.design(),
+                live: (!a.subscriptions().is_empty()).then(|| a.clone()),
             })
             .collect();
 
-        Self { layers }
+        Self {
+            layers,
+            metrics: scene.metrics.clone(),
+            layout: scene.layout.clone(),
+            constraints: scene.constraints.clone(),
+            breakpoints: scene.breakpoints.clone(),
+            sizes: scene.sizes.clone(),
+            animations: scene.animations.clone(),
+            springs: scene.springs.clone(),
+            transitions: scene.transitions.clone(),
+        }
     }
 
+    fn is_interactive(&self) -> bool {
+        self.layers.iter().any(|layer| layer.live.is_some())
+    }
+
+    fn is_animated(&self) -> bool {
+        !self.animations.is_empty() || !self.springs.is_empty()
+    }
+
+    /// Guarda el estado resultante de los acetatos interactivos y de las animaciones.
+    fn absorb(&mut self, scene: &Scene) {
+        for layer in &mut self.layers {
+            let Some(live) = layer.live.as_mut() else {
+                continue;
+            };
+            if let Some(next) = scene.acetates.iter().find(|a| a.id() == layer.id) {
+                *live = next.clone();
+            }
+        }
+        self.animations = scene.animations.clone();
+        self.springs = scene.springs.clone();
+    }
 }
 
 pub struct TomlApp {
@@ -112,6 +174,11 @@ pub struct TomlApp {
     ui_path: String,
     last_mtime: Option<SystemTime>,
     last_reload_check: Instant,
+    tooltip_delay: Duration,
+    /// Últimos datos entregados a cada acetate, por id; se reenvían tras recargar el TOML.
+    data: HashMap<String, Data>,
+    /// Eventos de datos pendientes de entregar en el próximo frame.
+    pending: Vec<Event>,
 }
 
 impl TomlApp {
@@ -133,9 +200,47 @@ impl TomlApp {
             last_mtime: read_mtime(&ui_path),
             ui_path,
             last_reload_check: Instant::now(),
+            tooltip_delay: DEFAULT_TOOLTIP_DELAY,
+            data: HashMap::new(),
+            pending: Vec::new(),
         }
     }
 
+    pub fn with_rows(mut self, id: &str, rows: Vec<Vec<String>>) -> Self {
+        self.set_rows(id, rows);
+        self
+    }
+
+    /// Entrega `data` al acetate `id` con `Event::Data` en el siguiente frame.
+    pub fn set_data(&mut self, id: &str, data: Data) {
+        self.data.insert(id.to_string(), data.clone());
+        self.pending.push(Event::Data {
+            target: id.to_string(),
+            data,
+        });
+    }
+
+    /// Entrega filas a la tabla `id` (`kind = "table"`).
+    pub fn set_rows(&mut self, id: &str, rows: Vec<Vec<String>>) {
+        self.set_data(id, Data::Rows(Arc::new(rows)));
+    }
+
+    /// Entrega series al gráfico `id` (`kind = "chart"`).
+    pub fn set_series(&mut self, id: &str, series: Vec<Series>) {
+        self.set_data(id, Data::Series(Arc::new(series)));
+    }
+
+    /// Fija el avance (0..1) de la barra `id` (`kind = "progress"`); `None` la deja
+    /// indeterminada.
+    pub fn set_progress(&mut self, id: &str, value: Option<f32>) {
+        self.set_data(id, Data::Progress(value));
+    }
+
+    pub fn with_tooltip_delay(mut self, delay: Duration) -> Self {
+        self.tooltip_delay = delay;
+        self
+    }
+
     fn maybe_hot_reload(&mut self) {
         if self.last_reload_check.elapsed() < Duration::from_millis(200) {
             return;
@@ -158,6 +263,14 @@ impl TomlApp {
             Ok(scene) => {
                 self.scene_template = SceneTemplate::from_scene(&scene);
                 self.last_mtime = Some(mtime);
+                self.pending = self
+                    .data
+                    .iter()
+                    .map(|(id, data)| Event::Data {
+                        target: id.clone(),
+                        data: data.clone(),
+                    })
+                    .collect();
                 println!("✅ reloaded ui.toml");
             }
             Err(err) => {
@@ -167,26 +280,25 @@ impl TomlApp {
         }
     }
 
-    fn build_scene(&self, width: u32, height: u32) -> Scene {
+    fn build_scene(&mut self, ctx: &FrameContext) -> Scene {
+        let (width, height) = (ctx.window_width, ctx.window_height);
         let mut acetates: Vec<Box<dyn Acetate>> = Vec::new();
         let mut has_bg = false;
 
         for layer in &self.scene_template.layers {
-            let mut design = layer.design.clone();
-
-            if layer.id == "bg" {
-                design.area.x = 0;
-                design.area.y = 0;
-                design.area.width = width;
-                design.area.height = height;
-                has_bg = true;
+            if let Some(live) = &layer.live {
+                acetates.push(live.clone());
+                continue;
             }
 
-            if design.area.width == 0 {
-                design.area.width = 1;
+            let mut design = layer.design.clone();
+            has_bg |= layer.id == "bg";
+
+            if design.area.width == 0.0 {
+                design.area.width = 1.0;
             }
-            if design.area.height == 0 {
-                design.area.height = 1;
+            if design.area.height == 0.0 {
+                design.area.height = 1.0;
             }
 
             acetates.push(Box::new(SimpleAcetate::new(
@@ -207,12 +319,7 @@ impl TomlApp {
                 "Background",
                 BG_Z_INDEX,
                 AcetateDesign {
-                    area: Rect {
-                        x: 0,
-                        y: 0,
-                        width,
-                        height,
-                    },
+                    area: Rect::new(0.0, 0.0, width as f32, height as f32),
                     background: default_background_color(),
                     border: Color {
                         r: 0.0,
@@ -222,25 +329,80 @@ impl TomlApp {
                     },
                     border_thickness: 0.0,
                     text: None,
+                    ..AcetateDesign::default()
                 },
             )));
         }
 
-        Scene::from(acetates)
+        // El fondo llena la ventana salvo que el ui.toml lo ancle de otro modo.
+        let mut constraints = self.scene_template.constraints.clone();
+        constraints
+            .entry("bg".to_string())
+            .or_insert_with(Constraints::fill_scene);
+
+        // La escena mide lo que la ventana: los popups se colocan dentro de ella. La
+        // escala también es la de la ventana, para que `react` y `perceive` la vean. El
+        // layout lo aplica el runtime; los acetatos vivos ya traen las áreas del frame
+        // anterior (ver `arranged`).
+        Scene {
+            width,
+            height,
+            metrics: Metrics {
+                scale: ctx.scale_factor as f32,
+                ..self.scene_template.metrics.clone()
+            },
+            layout: self.scene_template.layout.clone(),
+            constraints,
+            breakpoints: self.scene_template.breakpoints.clone(),
+            sizes: self.scene_template.sizes.clone(),
+            animations: self.scene_template.animations.clone(),
+            springs: self.scene_template.springs.clone(),
+            transitions: self.scene_template.transitions.clone(),
+            ..Scene::from(acetates)
+        }
     }
 }
 
 impl App for TomlApp {
     fn input_wants(&self) -> InputWants {
+        if self.scene_template.is_interactive() {
+            return InputWants {
+                mouse_move: true,
+                mouse_buttons: true,
+                scroll: true,
+                keyboard: true,
+                text_input: true,
+                tick: true,
+                resize: true,
+            };
+        }
         InputWants {
+            tick: self.scene_template.is_animated(),
             resize: true,
             ..InputWants::default()
         }
     }
 
-    fn frame(&mut self, _events: &[Event], ctx: &FrameContext, _input: &InputState) -> Scene {
+    fn tooltip_delay(&self) -> Duration {
+        self.tooltip_delay
+    }
+
+    fn frame(&mut self, events: &[Event], ctx: &FrameContext, _input: &InputState) -> Scene {
         self.maybe_hot_reload();
-        self.build_scene(ctx.window_width, ctx.window_height)
+        let mut pending = std::mem::take(&mut self.pending);
+        pending.extend_from_slice(events);
+        let events = pending;
+        let mut scene = self
+            .build_scene(ctx)
+            .react(&events);
+        for event in events.iter().filter(|event| matches!(event, Event::Tick(_))) {
+            scene = Animator::step(scene, event);
+        }
+        scene
+    }
+
+    fn arranged(&mut self, scene: &Scene) {
+        self.scene_template.absorb(scene);
     }
 }
 
@@ -256,3 +418,35 @@ fn default_background_color() -> Color {
 fn read_mtime(path: &str) -> Option<SystemTime> {
     std::fs::metadata(path).ok()?.modified().ok()
 }
+
+#[cfg(test)]
+mod tests {
+    use std::time::UNIX_EPOCH;
+
+    use super::*;
+
+    #[test]
+    fn frame_scene_carries_the_window_scale() {
+        let unique = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("clock drift")
+            .as_nanos();
+        let path = std::env::temp_dir().join(format!("evo_ui_engine_toml_app_{unique}.toml"));
+        std::fs::write(&path, "[scene]\nwidth = 200\nheight = 100\n").expect("write ui.toml");
+        let mut app = TomlApp::new(&path);
+        let ctx = FrameContext {
+            tick_number: 0,
+            dt: Duration::ZERO,
+            timestamp: Instant::now(),
+            window_width: 640,
+            window_height: 360,
+            scale_factor: 2.0,
+            fps: None,
+        };
+
+        let scene = app.frame(&[], &ctx, &InputState::default());
+        assert_eq!((scene.width, scene.height), (640, 360));
+        assert_eq!(scene.metrics.scale, 2.0);
+        std::fs::remove_file(&path).expect("remove ui.toml");
+    }
+}
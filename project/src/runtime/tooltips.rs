@@ -0,0 +1,153 @@
+use std::time::{Duration, Instant};
+
+use crate::acetates::tooltip::{Tooltip, TooltipStyle};
+use crate::contracts::event::Event;
+use crate::contracts::scene::Scene;
+
+/// Espera por defecto antes de mostrar un tooltip.
+pub const DEFAULT_TOOLTIP_DELAY: Duration = Duration::from_millis(500);
+
+#[derive(Debug, Clone)]
+struct Hover {
+    id: String,
+    since: Instant,
+    /// Pulsar (ratón o tecla) oculta el tooltip hasta salir del acetate.
+    dismissed: bool,
+}
+
+/// Decide en cada frame si hay que mostrar el tooltip del acetate bajo el puntero.
+/// El tiempo se mide con el `timestamp` del `Ticker`.
+#[derive(Debug, Clone)]
+pub struct TooltipTracker {
+    delay: Duration,
+    style: TooltipStyle,
+    hover: Option<Hover>,
+}
+
+impl TooltipTracker {
+    pub fn new(delay: Duration) -> Self {
+        Self {
+            delay,
+            style: TooltipStyle::default(),
+            hover: None,
+        }
+    }
+
+    pub fn with_style(mut self, style: TooltipStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Tooltip a pintar este frame, dentro de `bounds` (tamaño de la ventana).
+    /// `pointer` es `None` cuando el cursor está fuera de la ventana.
+    pub fn update(
+        &mut self,
+        scene: &Scene,
+        pointer: Option<(i32, i32)>,
+        events: &[Event],
+        now: Instant,
+        bounds: (u32, u32),
+    ) -> Option<Tooltip> {
+        let target = pointer.and_then(|(x, y)| hovered(scene, x, y));
+        let pressed = events
+            .iter()
+            .any(|event| matches!(event, Event::MouseDown { .. } | Event::KeyPressed(_)));
+
+        match (&mut self.hover, &target) {
+            (Some(hover), Some((id, _))) if &hover.id == id => hover.dismissed |= pressed,
+            _ => {
+                self.hover = target.as_ref().map(|(id, _)| Hover {
+                    id: id.clone(),
+                    since: now,
+                    dismissed: pressed,
+                })
+            }
+        }
+
+        let hover = self.hover.as_ref()?;
+        let text = target?.1?;
+        if hover.dismissed || now.duration_since(hover.since) < self.delay {
+            return None;
+        }
+        Some(Tooltip::new(&text, pointer?, bounds, self.style.clone()))
+    }
+}
+
+impl Default for TooltipTracker {
+    fn default() -> Self {
+        Self::new(DEFAULT_TOOLTIP_DELAY)
+    }
+}
+
+/// Acetate visible más alto bajo el puntero y su tooltip. Respeta los modales, y
+/// sobre un overlay (menú abierto) no hay tooltip.
+fn hovered(scene: &Scene, x: i32, y: i32) -> Option<(String, Option<String>)> {
+    let floor = scene.modal_z_index().unwrap_or(i32::MIN);
+    let reachable = scene
+        .visible()
+        .enumerate()
+        .filter(|(_, acetate)| acetate.z_index() >= floor);
+    let over_overlay = reachable.clone().any(|(_, acetate)| {
+        acetate
+            .overlays()
+            .iter()
+            .any(|overlay| overlay.design.area.contains(x, y))
+    });
+    if over_overlay {
+        return None;
+    }
+    reachable
+        .filter(|(_, acetate)| acetate.area().contains(x, y))
+        .max_by_key(|(index, acetate)| (acetate.z_index(), *index))
+        .map(|(_, acetate)| (acetate.id(), acetate.design().tooltip))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::acetates::Button;
+    use crate::contracts::event::MouseButton;
+    use crate::contracts::scene::{Acetate, Rect};
+
+    #[test]
+    fn shows_after_delay_inside_window_and_hides_on_press() {
+        let button = Button::new(
+            "save",
+            Rect::new(150.0, 70.0, 50.0, 30.0),
+        )
+        .with_tooltip("Guardar");
+        let scene = Scene::from(vec![Box::new(button) as Box<dyn Acetate>]);
+        let mut tracker = TooltipTracker::new(Duration::from_millis(300));
+        let start = Instant::now();
+        let pointer = Some((190, 90));
+
+        assert!(
+            tracker
+                .update(&scene, pointer, &[], start, (200, 100))
+                .is_none()
+        );
+        let later = start + Duration::from_millis(300);
+        let tooltip = tracker
+            .update(&scene, pointer, &[], later, (200, 100))
+            .expect("tooltip after delay");
+        let area = tooltip.area();
+        assert!(area.right() <= 200.0 && area.bottom() <= 100.0);
+
+        let press = Event::MouseDown {
+            button: MouseButton::Left,
+            x: 190,
+            y: 90,
+        };
+        let later = later + Duration::from_millis(300);
+        assert!(
+            tracker
+                .update(&scene, pointer, &[press], later, (200, 100))
+                .is_none()
+        );
+        assert!(
+            tracker
+                .update(&scene, None, &[], later, (200, 100))
+                .is_none()
+        );
+    }
+}
@@ -0,0 +1,135 @@
+use std::collections::HashMap;
+use std::time::Duration;
+
+use crate::contracts::scene::{AnimatedProperty, Animation, AnimationValue, Scene};
+
+/// Convierte los cambios entre frames en animaciones: compara cada acetate con
+/// transición (`Scene::transitions`) con el del frame anterior por id y, si una
+/// propiedad cubierta cambió, la interpola desde lo que se estaba viendo.
+#[derive(Debug, Clone, Default)]
+pub struct TransitionTracker {
+    /// Último valor de destino de cada propiedad seguida.
+    targets: HashMap<(String, AnimatedProperty), AnimationValue>,
+    /// Interpolaciones en curso.
+    running: Vec<Animation>,
+}
+
+impl TransitionTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Escena con las transiciones en curso añadidas a `Scene::animations` (antes que
+    /// las de la app, que ganan si coinciden), lista para `Animator::apply`. `dt` es
+    /// el tiempo desde el frame anterior.
+    pub fn update(&mut self, scene: Scene, dt: Duration) -> Scene {
+        let mut running: Vec<Animation> = self
+            .running
+            .iter()
+            .map(|animation| animation.advance(dt))
+            .collect();
+
+        let mut targets = HashMap::new();
+        for acetate in &scene.acetates {
+            let id = acetate.id();
+            let Some(transition) = scene.transitions.get(&id) else {
+                continue;
+            };
+            for property in AnimatedProperty::ALL {
+                if !transition.covers(property) {
+                    continue;
+                }
+                let value = property.read(acetate.as_ref());
+                let key = (id.clone(), property);
+                if let Some(previous) = self.targets.get(&key)
+                    && *previous != value
+                {
+                    let current = running.iter().position(|animation| {
+                        animation.target == id && animation.property == property
+                    });
+                    // Un cambio a mitad de transición parte del valor intermedio, sin saltos.
+                    let shown = current
+                        .map(|index| running.remove(index))
+                        .and_then(|animation| animation.value())
+                        .unwrap_or(*previous);
+                    running.push(transition.animate(&id, property, shown, value));
+                }
+                targets.insert(key, value);
+            }
+        }
+
+        // Las terminadas ya muestran el valor nuevo; las de acetates que ya no están
+        // (o que perdieron la transición) sobran.
+        running.retain(|animation| {
+            !animation.is_finished()
+                && targets.contains_key(&(animation.target.clone(), animation.property))
+        });
+        self.targets = targets;
+        self.running = running;
+
+        if self.running.is_empty() {
+            return scene;
+        }
+        let animations = self
+            .running
+            .iter()
+            .cloned()
+            .chain(scene.animations.iter().cloned())
+            .collect();
+        Scene {
+            animations,
+            ..scene
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::acetates::Label;
+    use crate::actors::animator::Animator;
+    use crate::contracts::scene::{Acetate, Easing, Rect, Transition};
+
+    fn frame(x: f32) -> Scene {
+        let label = Label::new("badge", Rect::new(x, 0.0, 40.0, 20.0), "3");
+        let mut scene = Scene::from(vec![Box::new(label) as Box<dyn Acetate>]);
+        scene.transitions.insert(
+            "badge".to_string(),
+            Transition::new(Duration::from_millis(200))
+                .with_properties([AnimatedProperty::X])
+                .with_easing(Easing::Linear),
+        );
+        scene
+    }
+
+    #[test]
+    fn rebuilt_frames_interpolate_changed_properties_by_id() {
+        let mut tracker = TransitionTracker::new();
+        let dt = Duration::from_millis(50);
+        let painted_x = |scene: Scene| Animator::apply(scene).acetates[0].area().x;
+
+        let mut painted = |x: f32| painted_x(tracker.update(frame(x), dt));
+        let expected = [
+            // El primer frame solo registra el valor: no hay nada de lo que venir.
+            (0.0, 0.0),
+            // La app salta a 100; se pinta el camino.
+            (100.0, 0.0),
+            (100.0, 25.0),
+            (100.0, 50.0),
+            // Volver a 0 a mitad parte de donde iba (75 en este frame), sin saltos.
+            (0.0, 75.0),
+            (0.0, 56.25),
+        ];
+        for (target, shown) in expected {
+            let x = painted(target);
+            assert!((x - shown).abs() < 1e-3, "{x} != {shown}");
+        }
+
+        for _ in 0..4 {
+            tracker.update(frame(0.0), dt);
+        }
+        let settled = tracker.update(frame(0.0), dt);
+        assert!(settled.animations.is_empty());
+        assert_eq!(painted_x(settled), 0.0);
+    }
+}
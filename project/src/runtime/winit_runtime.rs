@@ -3,20 +3,30 @@ use std::path::Path;
 use std::sync::Arc;
 use std::time::Instant;
 
+use crate::actors::animator::Animator;
 use crate::actors::event_router::{EventRouter, SystemEvent};
 use crate::actors::input_mapper::InputMapper;
 use crate::actors::snapshot_builder::SnapshotBuilder;
 use crate::actors::ticker::Ticker;
 use crate::actors::vello_adapter::VelloAdapter;
-use crate::contracts::event::{Event, MouseButton};
+use crate::contracts::event::{keys, Event, MouseButton};
+use crate::runtime::actions::ActionCollector;
+use crate::runtime::animation_events::AnimationEventCollector;
 use crate::runtime::app::{App, FrameContext, InputState};
+use crate::runtime::clipboard::{ClipboardBridge, SystemClipboard};
+use crate::runtime::layout::LayoutPass;
 use crate::runtime::toml_app::TomlApp;
+use crate::runtime::tooltips::TooltipTracker;
+use crate::runtime::transitions::TransitionTracker;
 use crate::ui_toml::UiTomlError;
 use vello::peniko::Color as PColor;
 use vello::wgpu;
 use vello::{AaConfig, RenderParams, Renderer as VelloRenderer, RendererOptions};
 use winit::application::ApplicationHandler;
-use winit::event::{ElementState, Ime, KeyEvent, MouseButton as WinitMouseButton, WindowEvent};
+use winit::event::{
+    ElementState, Ime, KeyEvent, MouseButton as WinitMouseButton, MouseScrollDelta, WindowEvent,
+};
+use winit::dpi::PhysicalPosition;
 use winit::event_loop::{ActiveEventLoop, EventLoop};
 use winit::keyboard::Key;
 use winit::window::{Window, WindowAttributes, WindowId};
@@ -124,6 +134,14 @@ struct Runner<A: App> {
     ui_path: String,
     ticker: Ticker,
     input: InputState,
+    clipboard: SystemClipboard,
+    clipboard_bridge: ClipboardBridge,
+    actions: ActionCollector,
+    animation_events: AnimationEventCollector,
+    tooltips: TooltipTracker,
+    transitions: TransitionTracker,
+    layout: LayoutPass,
+    pointer_inside: bool,
     pending_events: Vec<Event>,
     last_frame_at: Instant,
     fps_started_at: Instant,
@@ -138,11 +156,20 @@ struct Runner<A: App> {
 
 impl<A: App> Runner<A> {
     fn new(ui_path: String, app: A) -> Self {
+        let tooltips = TooltipTracker::new(app.tooltip_delay());
         Self {
             app,
             ui_path,
             ticker: Ticker::new(Instant::now()),
             input: InputState::default(),
+            clipboard: SystemClipboard::new(),
+            clipboard_bridge: ClipboardBridge::new(),
+            actions: ActionCollector::new(),
+            animation_events: AnimationEventCollector::new(),
+            tooltips,
+            transitions: TransitionTracker::new(),
+            layout: LayoutPass::new(),
+            pointer_inside: false,
             pending_events: Vec::new(),
             last_frame_at: Instant::now(),
             fps_started_at: Instant::now(),
@@ -160,6 +187,11 @@ impl<A: App> Runner<A> {
         self.pending_events.push(event);
     }
 
+    fn control_down(&self) -> bool {
+        self.input.keys_down.contains(keys::CONTROL) || self.input.keys_down.contains(keys::SUPER)
+    }
+
+    /// Tamaño de la ventana en píxeles físicos (el de la superficie).
     fn current_window_size(&self) -> Option<(u32, u32)> {
         self.window.as_ref().map(|window| {
             let size = window.inner_size();
@@ -167,6 +199,22 @@ impl<A: App> Runner<A> {
         })
     }
 
+    fn current_scale_factor(&self) -> f64 {
+        self.window
+            .as_ref()
+            .map_or(1.0, |window| window.scale_factor())
+    }
+
+    /// Actualiza tamaño lógico y escala de `InputState`; devuelve el tamaño lógico.
+    fn sync_window_metrics(&mut self, physical: (u32, u32)) -> (u32, u32) {
+        let scale = self.current_scale_factor();
+        let (width, height) = logical_size(physical, scale);
+        self.input.window_width = width;
+        self.input.window_height = height;
+        self.input.scale_factor = scale;
+        (width, height)
+    }
+
     fn shutdown(&mut self, event_loop: &ActiveEventLoop) {
         if self.closing {
             return;
@@ -197,8 +245,8 @@ impl<A: App> Runner<A> {
         let Some((width, height)) = self.current_window_size() else {
             return RenderAction::Continue;
         };
-        self.input.window_width = width;
-        self.input.window_height = height;
+        let logical = self.sync_window_metrics((width, height));
+        let scale_factor = self.input.scale_factor;
 
         let Some(gpu) = self.gpu.as_mut() else {
             return RenderAction::Continue;
@@ -227,13 +275,37 @@ impl<A: App> Runner<A> {
             tick_number: tick.number,
             dt,
             timestamp: tick.timestamp,
-            window_width: width,
-            window_height: height,
+            window_width: logical.0,
+            window_height: logical.1,
+            scale_factor,
             fps: self.last_fps,
         };
 
-        let scene = self.app.frame(&events, &ctx, &self.input);
+        let mut scene = self.app.frame(&events, &ctx, &self.input);
+        scene.metrics.scale = scale_factor as f32;
+        let scene = self.layout.apply(scene, logical);
+        self.app.arranged(&scene);
+        // Solo las animaciones de la app: las transiciones implícitas no avisan.
+        let lifecycle = self.animation_events.collect(&scene);
+        // Las animaciones van tras el layout para que este no pise lo animado; las
+        // transiciones comparan lo ya colocado con el frame anterior.
+        let scene = self.transitions.update(scene, tick.dt);
+        let mut scene = Animator::apply(scene);
         self.input.text_buffer = None;
+        self.clipboard_bridge.sync(&scene, &mut self.clipboard);
+        let actions = self.actions.collect(&scene);
+        self.pending_events.extend(actions);
+        self.pending_events.extend(lifecycle);
+
+        let pointer = self
+            .pointer_inside
+            .then_some((self.input.mouse_x, self.input.mouse_y));
+        let tooltip = self
+            .tooltips
+            .update(&scene, pointer, &events, tick.timestamp, logical);
+        if let Some(tooltip) = tooltip {
+            scene.acetates.push(Box::new(tooltip));
+        }
 
         let snapshot = SnapshotBuilder::build(scene);
         let vello_scene = VelloAdapter::adapt(snapshot);
@@ -317,6 +389,7 @@ impl<A: App> Runner<A> {
         frame.present();
 
         self.rendered_frames += 1;
+        #[allow(clippy::manual_is_multiple_of)]
         if self.rendered_frames % 120 == 0 {
             let elapsed = self.fps_started_at.elapsed().as_secs_f64();
             if elapsed > 0.0 {
@@ -356,12 +429,11 @@ impl<A: App> ApplicationHandler for Runner<A> {
             }
         };
 
-        self.input.window_width = gpu.config.width.max(1);
-        self.input.window_height = gpu.config.height.max(1);
-
+        let physical = (gpu.config.width.max(1), gpu.config.height.max(1));
         self.window_id = Some(window.id());
         self.window = Some(window);
         self.gpu = Some(gpu);
+        self.sync_window_metrics(physical);
     }
 
     fn window_event(
@@ -380,30 +452,15 @@ impl<A: App> ApplicationHandler for Runner<A> {
                 self.queue_event(Event::Exit);
                 self.shutdown(event_loop)
             }
-            WindowEvent::Resized(_) => {
-                let Some((width, height)) = self.current_window_size() else {
-                    return;
-                };
-                self.input.window_width = width;
-                self.input.window_height = height;
-                if let Some(gpu) = self.gpu.as_mut() {
-                    gpu.resize(width, height);
-                }
-                if wants.resize {
-                    self.queue_event(Event::WindowResized { width, height });
-                }
-                if let Some(window) = &self.window {
-                    window.request_redraw();
-                }
-            }
-            WindowEvent::ScaleFactorChanged { .. } => {
-                let Some((width, height)) = self.current_window_size() else {
+            // Un cambio de escala (p. ej. al pasar a otro monitor) cambia el tamaño
+            // físico, el lógico o ambos: la superficie sigue al físico y la app al lógico.
+            WindowEvent::Resized(_) | WindowEvent::ScaleFactorChanged { .. } => {
+                let Some(physical) = self.current_window_size() else {
                     return;
                 };
-                self.input.window_width = width;
-                self.input.window_height = height;
+                let (width, height) = self.sync_window_metrics(physical);
                 if let Some(gpu) = self.gpu.as_mut() {
-                    gpu.resize(width, height);
+                    gpu.resize(physical.0, physical.1);
                 }
                 if wants.resize {
                     self.queue_event(Event::WindowResized { width, height });
@@ -412,14 +469,16 @@ impl<A: App> ApplicationHandler for Runner<A> {
                     window.request_redraw();
                 }
             }
+            WindowEvent::CursorLeft { .. } => self.pointer_inside = false,
             WindowEvent::CursorMoved { position, .. } => {
-                self.input.mouse_x = position.x.round() as i32;
-                self.input.mouse_y = position.y.round() as i32;
+                let (x, y) = logical_point(position, self.current_scale_factor());
+                self.pointer_inside = true;
+                self.input.mouse_x = x;
+                self.input.mouse_y = y;
                 if !wants.mouse_move {
                     return;
                 }
-                let system_event =
-                    SystemEvent::MouseMove(position.x.round() as i32, position.y.round() as i32);
+                let system_event = SystemEvent::MouseMove(x, y);
                 let internal_event = EventRouter::interpret(system_event);
                 let app_event = InputMapper::translate(internal_event);
                 self.queue_event(app_event);
@@ -454,21 +513,48 @@ impl<A: App> ApplicationHandler for Runner<A> {
                     }
                 }
             }
-            WindowEvent::KeyboardInput { event, .. } => {
-                if !wants.keyboard {
+            WindowEvent::MouseWheel { delta, .. } => {
+                if !wants.scroll {
                     return;
                 }
-                let key = key_event_to_string(&event);
-                match event.state {
-                    ElementState::Pressed => {
-                        self.input.keys_down.insert(key.clone());
-                        self.queue_event(Event::KeyPressed(key));
-                    }
-                    ElementState::Released => {
-                        self.input.keys_down.remove(&key);
-                        self.queue_event(Event::KeyReleased(key));
+                let (dx, dy) = scroll_pixels(delta, self.current_scale_factor());
+                self.queue_event(Event::Scroll {
+                    dx,
+                    dy,
+                    x: self.input.mouse_x,
+                    y: self.input.mouse_y,
+                });
+            }
+            WindowEvent::KeyboardInput { event, .. } => {
+                if wants.keyboard {
+                    let key = key_event_to_string(&event);
+                    match event.state {
+                        ElementState::Pressed => {
+                            self.input.keys_down.insert(key.clone());
+                            let control = self.control_down();
+                            let paste = if wants.text_input {
+                                ClipboardBridge::paste_event(&key, control, &mut self.clipboard)
+                            } else {
+                                None
+                            };
+                            self.queue_event(Event::KeyPressed(key));
+                            if let Some(paste) = paste {
+                                self.queue_event(paste);
+                            }
+                        }
+                        ElementState::Released => {
+                            self.input.keys_down.remove(&key);
+                            self.queue_event(Event::KeyReleased(key));
+                        }
                     }
                 }
+                // Sin IME activo, el texto tecleado llega junto al evento de tecla.
+                if wants.text_input
+                    && let Some(text) = key_event_text(&event)
+                {
+                    self.input.text_buffer = Some(text.clone());
+                    self.queue_event(Event::TextInput(text));
+                }
             }
             WindowEvent::Ime(Ime::Commit(text)) => {
                 if !wants.text_input {
@@ -490,6 +576,7 @@ impl<A: App> ApplicationHandler for Runner<A> {
     }
 
     fn about_to_wait(&mut self, _event_loop: &ActiveEventLoop) {
+        #[allow(clippy::collapsible_if)]
         if !self.closing {
             if let Some(window) = &self.window {
                 window.request_redraw();
@@ -523,6 +610,33 @@ fn map_mouse_button(button: WinitMouseButton) -> Option<MouseButton> {
     }
 }
 
+/// Píxeles que avanza una línea de rueda.
+const SCROLL_LINE_PX: f32 = 40.0;
+
+/// winit da la rueda positiva hacia arriba; el motor usa `dy > 0` para avanzar. Los
+/// deltas en píxeles llegan físicos y se pasan a unidades lógicas.
+fn scroll_pixels(delta: MouseScrollDelta, scale_factor: f64) -> (f32, f32) {
+    match delta {
+        MouseScrollDelta::LineDelta(x, y) => (-x * SCROLL_LINE_PX, -y * SCROLL_LINE_PX),
+        MouseScrollDelta::PixelDelta(p) => {
+            let p = p.to_logical::<f64>(scale_factor);
+            (-p.x as f32, -p.y as f32)
+        }
+    }
+}
+
+/// Posición del puntero en unidades lógicas, redondeada al píxel lógico.
+fn logical_point(position: PhysicalPosition<f64>, scale_factor: f64) -> (i32, i32) {
+    let position = position.to_logical::<f64>(scale_factor);
+    (position.x.round() as i32, position.y.round() as i32)
+}
+
+/// Tamaño lógico (el de la escena) de una ventana de `physical` píxeles.
+fn logical_size((width, height): (u32, u32), scale_factor: f64) -> (u32, u32) {
+    let logical = |physical: u32| ((physical as f64 / scale_factor).round() as u32).max(1);
+    (logical(width), logical(height))
+}
+
 fn key_event_to_string(event: &KeyEvent) -> String {
     match &event.logical_key {
         Key::Character(text) => text.to_string(),
@@ -530,6 +644,18 @@ fn key_event_to_string(event: &KeyEvent) -> String {
     }
 }
 
+/// Texto imprimible producido por una pulsación (se descartan controles como Enter o Ctrl+C).
+fn key_event_text(event: &KeyEvent) -> Option<String> {
+    if event.state != ElementState::Pressed {
+        return None;
+    }
+    let text = event.text.as_ref()?;
+    if text.is_empty() || text.chars().any(char::is_control) {
+        return None;
+    }
+    Some(text.to_string())
+}
+
 struct GpuState {
     surface: Option<wgpu::Surface<'static>>,
     device: wgpu::Device,
@@ -793,3 +919,22 @@ impl GpuState {
         }));
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn physical_input_is_converted_to_logical_units() {
+        assert_eq!(logical_size((2560, 1440), 2.0), (1280, 720));
+        assert_eq!(logical_size((1001, 1), 1.5), (667, 1));
+        assert_eq!(logical_point(PhysicalPosition::new(301.0, 99.0), 2.0), (151, 50));
+        assert_eq!(logical_point(PhysicalPosition::new(301.0, 99.0), 1.0), (301, 99));
+
+        // Las líneas no dependen de la escala; los píxeles sí, y el signo se invierte.
+        let lines = MouseScrollDelta::LineDelta(0.0, 1.0);
+        assert_eq!(scroll_pixels(lines, 2.0), (0.0, -SCROLL_LINE_PX));
+        let pixels = MouseScrollDelta::PixelDelta(PhysicalPosition::new(10.0, -30.0));
+        assert_eq!(scroll_pixels(pixels, 2.0), (-5.0, 15.0));
+    }
+}
@@ -1,17 +1,32 @@
+use std::collections::{BTreeMap, HashMap};
 use std::fmt;
 use std::fs;
 use std::path::Path;
+use std::time::Duration;
 
+use crate::acetates::{
+    Button, ButtonStateStyle, ButtonStyle, Chart, ChartStyle, Checkbox, Column, ContextMenu,
+    DataTable, Dropdown, ListStyle, MenuStyle, Modal, ModalStyle, ProgressBar, ProgressStyle,
+    RadioGroup, Slider, SliderStyle, Spinner, Switch, Tab, TabStyle, TableStyle, Tabs, ToggleStyle,
+    WithTooltip,
+};
+use crate::actors::layout_engine::{ConstraintError, LayoutEngine};
 use crate::contracts::event::{Event, EventKind};
 use crate::contracts::scene::{
-    Acetate, AcetateDesign, AcetateIO, AcetateStatus, Metrics, Rect, Scene, SceneInfo,
+    Acetate, AcetateDesign, AcetateIO, AcetateStatus, Align, AnimatedProperty, Animation,
+    AnimationValue, Anchor, Breakpoint, Constraints, Easing, Edge, Justify, Keyframe, LayoutNode,
+    Length, Metrics, Rect, Repeat, Scene, SceneInfo, Sizes, Track, Transform, Transition,
 };
 use crate::core::Color;
 
 use serde::de::Error as _;
 use serde::Deserialize;
 
-use super::schema::AcetateToml;
+use super::schema::{
+    AcetateKind, AcetateToml, AnimationToml, AnimationValueToml, ChartKind, ColumnToml,
+    LayoutToml, Orientation, RepeatToml, ScaleToml, SelectionMode, StateStyleToml, TabToml,
+    TrackToml, TransformToml, ValueToml,
+};
 
 #[derive(Debug)]
 pub enum UiTomlError {
@@ -31,6 +46,29 @@ pub enum UiTomlError {
         acetate_index: Option<usize>,
         field: &'static str,
     },
+    InvalidKind {
+        value: String,
+        acetate_index: usize,
+    },
+    UnknownOption {
+        value: String,
+        acetate_index: usize,
+    },
+    InvalidValue {
+        value: String,
+        acetate_index: usize,
+        field: &'static str,
+    },
+    InvalidLayout {
+        value: String,
+        field: &'static str,
+    },
+    InvalidAnimation {
+        value: String,
+        animation_index: usize,
+        field: &'static str,
+    },
+    Constraint(ConstraintError),
 }
 
 impl fmt::Display for UiTomlError {
@@ -78,6 +116,40 @@ impl fmt::Display for UiTomlError {
                     write!(f, "missing field {}", field)
                 }
             }
+            UiTomlError::InvalidKind {
+                value,
+                acetate_index,
+            } => write!(f, "unknown kind for acetate[{}]: {}", acetate_index, value),
+            UiTomlError::UnknownOption {
+                value,
+                acetate_index,
+            } => write!(
+                f,
+                "selected option not in acetate[{}].options: {}",
+                acetate_index, value
+            ),
+            UiTomlError::InvalidValue {
+                value,
+                acetate_index,
+                field,
+            } => write!(
+                f,
+                "invalid value for acetate[{}].{}: {}",
+                acetate_index, field, value
+            ),
+            UiTomlError::InvalidLayout { value, field } => {
+                write!(f, "invalid value for layout.{}: {}", field, value)
+            }
+            UiTomlError::InvalidAnimation {
+                value,
+                animation_index,
+                field,
+            } => write!(
+                f,
+                "invalid value for animation[{}].{}: {}",
+                animation_index, field, value
+            ),
+            UiTomlError::Constraint(err) => write!(f, "constraint error: {}", err),
         }
     }
 }
@@ -101,6 +173,12 @@ struct RawUiToml {
     scene: Option<RawSceneToml>,
     #[serde(default)]
     acetate: Vec<RawAcetateToml>,
+    #[serde(default)]
+    layout: Option<LayoutToml>,
+    #[serde(default)]
+    breakpoint: BTreeMap<String, RawBreakpointToml>,
+    #[serde(default)]
+    animation: Vec<AnimationToml>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -108,6 +186,10 @@ struct RawSceneToml {
     width: Option<i64>,
     height: Option<i64>,
     includes: Option<Vec<String>>,
+    #[serde(default)]
+    margin: Option<i64>,
+    #[serde(default)]
+    spacing: Option<i64>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -115,17 +197,227 @@ struct RawAcetateToml {
     id: Option<String>,
     #[serde(default = "default_z_i64")]
     z: i64,
-    x: Option<i64>,
-    y: Option<i64>,
-    w: Option<i64>,
-    h: Option<i64>,
+    #[serde(flatten)]
+    geometry: RawGeometryToml,
     fill: Option<String>,
     #[serde(default = "default_border")]
     border: String,
     #[serde(default = "default_border_thickness")]
-    border_thickness: f32,
+    border_thickness: ValueToml,
     #[serde(default)]
     text: Option<String>,
+    #[serde(default)]
+    text_color: Option<String>,
+    #[serde(default)]
+    font_size: Option<ValueToml>,
+    #[serde(default)]
+    kind: Option<String>,
+    #[serde(default)]
+    hover: Option<StateStyleToml>,
+    #[serde(default)]
+    pressed: Option<StateStyleToml>,
+    #[serde(default)]
+    focused: Option<StateStyleToml>,
+    #[serde(default)]
+    disabled: Option<StateStyleToml>,
+    #[serde(default)]
+    accent: Option<String>,
+    #[serde(default)]
+    checked: bool,
+    #[serde(default)]
+    options: Vec<String>,
+    #[serde(default)]
+    selected: Option<String>,
+    #[serde(default)]
+    min: Option<f32>,
+    #[serde(default)]
+    max: Option<f32>,
+    #[serde(default)]
+    step: Option<f32>,
+    #[serde(default)]
+    value: Option<f32>,
+    #[serde(default)]
+    range: Option<[f32; 2]>,
+    #[serde(default)]
+    orientation: Option<String>,
+    #[serde(default)]
+    ticks: Option<f32>,
+    #[serde(default)]
+    title: Option<String>,
+    #[serde(default)]
+    open_on: Option<String>,
+    #[serde(default)]
+    tooltip: Option<String>,
+    #[serde(default)]
+    columns: Vec<ColumnToml>,
+    #[serde(default)]
+    row_height: Option<u32>,
+    #[serde(default)]
+    header_height: Option<u32>,
+    #[serde(default)]
+    selection: Option<String>,
+    #[serde(default)]
+    stripe: Option<String>,
+    #[serde(default)]
+    tabs: Vec<TabToml>,
+    #[serde(default)]
+    chart: Option<String>,
+    #[serde(default)]
+    breakpoint: BTreeMap<String, RawOverrideToml>,
+    #[serde(default)]
+    transition: Option<RawTransitionToml>,
+}
+
+/// `transition = { property = "all", duration_ms = 200 }` de un `[[acetate]]`.
+#[derive(Debug, Deserialize)]
+struct RawTransitionToml {
+    /// `"all"` (por defecto), un nombre de propiedad o una lista de ellos.
+    #[serde(default)]
+    property: Option<RawPropertiesToml>,
+    duration_ms: u64,
+    #[serde(default)]
+    delay_ms: u64,
+    #[serde(default)]
+    easing: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum RawPropertiesToml {
+    One(String),
+    Many(Vec<String>),
+}
+
+fn transition_from_toml(raw: &RawTransitionToml, index: usize) -> Result<Transition, UiTomlError> {
+    let invalid = |value: &str, field| UiTomlError::InvalidValue {
+        value: value.to_string(),
+        acetate_index: index,
+        field,
+    };
+    let names = match &raw.property {
+        None => Vec::new(),
+        Some(RawPropertiesToml::One(name)) => vec![name.clone()],
+        Some(RawPropertiesToml::Many(names)) => names.clone(),
+    };
+    let mut properties = Vec::new();
+    for name in names.iter().filter(|name| name.trim() != "all") {
+        properties.push(
+            AnimatedProperty::parse(name).ok_or_else(|| invalid(name, "transition.property"))?,
+        );
+    }
+    // "all" junto a otras sigue siendo todas.
+    if names.iter().any(|name| name.trim() == "all") {
+        properties.clear();
+    }
+    let easing = match &raw.easing {
+        Some(easing) => Easing::parse(easing).ok_or_else(|| invalid(easing, "transition.easing"))?,
+        None => Easing::default(),
+    };
+    Ok(Transition::new(Duration::from_millis(raw.duration_ms))
+        .with_properties(properties)
+        .with_delay(Duration::from_millis(raw.delay_ms))
+        .with_easing(easing))
+}
+
+/// Posición, tamaño y anclas de un `[[acetate]]` o de uno de sus
+/// `[acetate.breakpoint.<nombre>]`.
+#[derive(Debug, Default, Deserialize)]
+struct RawGeometryToml {
+    #[serde(default)]
+    x: Option<ValueToml>,
+    #[serde(default)]
+    y: Option<ValueToml>,
+    #[serde(default)]
+    w: Option<ValueToml>,
+    #[serde(default)]
+    h: Option<ValueToml>,
+    #[serde(default)]
+    left: Option<ValueToml>,
+    #[serde(default)]
+    right: Option<ValueToml>,
+    #[serde(default)]
+    top: Option<ValueToml>,
+    #[serde(default)]
+    bottom: Option<ValueToml>,
+    #[serde(default)]
+    center_x: Option<ValueToml>,
+    #[serde(default)]
+    center_y: Option<ValueToml>,
+}
+
+impl RawGeometryToml {
+    /// Anclas declaradas, sin interpretar, con el borde que restringen.
+    fn anchors(&self) -> [(Edge, Option<&ValueToml>); 6] {
+        [
+            (Edge::Left, self.left.as_ref()),
+            (Edge::Right, self.right.as_ref()),
+            (Edge::Top, self.top.as_ref()),
+            (Edge::Bottom, self.bottom.as_ref()),
+            (Edge::CenterX, self.center_x.as_ref()),
+            (Edge::CenterY, self.center_y.as_ref()),
+        ]
+    }
+
+    fn lengths(&self) -> [(&'static str, Option<&ValueToml>); 4] {
+        [
+            ("x", self.x.as_ref()),
+            ("y", self.y.as_ref()),
+            ("w", self.w.as_ref()),
+            ("h", self.h.as_ref()),
+        ]
+    }
+}
+
+/// Lo que cambia un acetate mientras el breakpoint está activo.
+#[derive(Debug, Deserialize)]
+struct RawOverrideToml {
+    #[serde(flatten)]
+    geometry: RawGeometryToml,
+    #[serde(default)]
+    hidden: bool,
+}
+
+#[derive(Debug, Deserialize)]
+struct RawBreakpointToml {
+    #[serde(default)]
+    min_width: Option<u32>,
+    #[serde(default)]
+    max_width: Option<u32>,
+    #[serde(default)]
+    min_height: Option<u32>,
+    #[serde(default)]
+    max_height: Option<u32>,
+    #[serde(default)]
+    layout: Option<LayoutToml>,
+}
+
+fn constraints_from_toml(
+    geometry: &RawGeometryToml,
+    index: usize,
+) -> Result<Constraints, UiTomlError> {
+    let mut constraints = Constraints::default();
+    for (edge, anchor) in geometry.anchors() {
+        let anchor = match anchor {
+            None => continue,
+            Some(ValueToml::Pixels(px)) => Anchor::Fixed(Length::px(*px)),
+            Some(ValueToml::Text(text)) => {
+                Anchor::parse(text, edge).ok_or_else(|| UiTomlError::InvalidValue {
+                    value: text.clone(),
+                    acetate_index: index,
+                    field: edge.name(),
+                })?
+            }
+        };
+        constraints = match edge {
+            Edge::Left => constraints.with_left(anchor),
+            Edge::Right => constraints.with_right(anchor),
+            Edge::Top => constraints.with_top(anchor),
+            Edge::Bottom => constraints.with_bottom(anchor),
+            Edge::CenterX => constraints.with_center_x(anchor),
+            _ => constraints.with_center_y(anchor),
+        };
+    }
+    Ok(constraints)
 }
 
 #[derive(Debug, Deserialize)]
@@ -142,8 +434,12 @@ fn default_border() -> String {
     "#00000000".to_string()
 }
 
-fn default_border_thickness() -> f32 {
-    0.0
+fn default_border_thickness() -> ValueToml {
+    ValueToml::Pixels(0.0)
+}
+
+fn default_text_color() -> Color {
+    Color::rgba(1.0, 1.0, 1.0, 1.0)
 }
 
 #[derive(Debug, Clone)]
@@ -169,7 +465,17 @@ impl Acetate for TomlAcetate {
     }
 
     fn area(&self) -> Rect {
-        self.area.clone()
+        self.area
+    }
+
+    fn relocate(&self, area: Rect) -> Box<dyn Acetate> {
+        let mut design = self.design.clone();
+        design.area = area;
+        Box::new(Self {
+            area,
+            design,
+            ..self.clone()
+        })
     }
 
     fn subscriptions(&self) -> Vec<EventKind> {
@@ -189,6 +495,8 @@ impl Acetate for TomlAcetate {
             content: None,
             focus: false,
             status: AcetateStatus::Ready,
+            clipboard: None,
+            action: None,
         }
     }
 
@@ -230,28 +538,485 @@ fn parse_color(
 fn acetate_from_toml(
     input: &AcetateToml,
     acetate_index: usize,
-) -> Result<TomlAcetate, UiTomlError> {
-    let background = parse_color(&input.fill, Some(acetate_index), "fill")?;
-    let border = parse_color(&input.border, Some(acetate_index), "border")?;
-    let area = Rect {
-        x: input.x,
-        y: input.y,
-        width: input.w,
-        height: input.h,
+) -> Result<Box<dyn Acetate>, UiTomlError> {
+    let acetate = kind_from_toml(input, acetate_index)?;
+    Ok(match &input.tooltip {
+        Some(tooltip) => Box::new(WithTooltip::new(acetate, tooltip)),
+        None => acetate,
+    })
+}
+
+fn kind_from_toml(
+    input: &AcetateToml,
+    acetate_index: usize,
+) -> Result<Box<dyn Acetate>, UiTomlError> {
+    let colors = KindColors {
+        background: parse_color(&input.fill, Some(acetate_index), "fill")?,
+        border: parse_color(&input.border, Some(acetate_index), "border")?,
+        text_color: match &input.text_color {
+            Some(value) => parse_color(value, Some(acetate_index), "text_color")?,
+            None => default_text_color(),
+        },
     };
+    let area = Rect::new(input.x, input.y, input.w, input.h);
 
-    Ok(TomlAcetate {
+    Ok(match input.kind {
+        AcetateKind::Plain => Box::new(plain_from_toml(input, area, colors)),
+        AcetateKind::Button => Box::new(button_from_toml(input, acetate_index, area, colors)?),
+        AcetateKind::Checkbox | AcetateKind::Switch => {
+            toggle_from_toml(input, acetate_index, area, colors)?
+        }
+        AcetateKind::Radio => Box::new(radio_from_toml(input, acetate_index, area, colors)?),
+        AcetateKind::Dropdown | AcetateKind::ContextMenu => {
+            menu_from_toml(input, acetate_index, area, colors)?
+        }
+        AcetateKind::Modal => Box::new(modal_from_toml(input, area, colors)),
+        AcetateKind::Slider => Box::new(slider_from_toml(input, acetate_index, area, colors)?),
+        AcetateKind::Tabs => Box::new(tabs_from_toml(input, acetate_index, area, colors)?),
+        AcetateKind::Progress | AcetateKind::Spinner => {
+            progress_from_toml(input, acetate_index, area, colors)?
+        }
+        AcetateKind::Chart => Box::new(chart_from_toml(input, acetate_index, area, colors)?),
+        AcetateKind::Table => Box::new(table_from_toml(input, acetate_index, area, colors)?),
+    })
+}
+
+/// Colores comunes a todos los tipos: `fill`, `border` y `text_color` ya resueltos.
+#[derive(Debug, Clone, Copy)]
+struct KindColors {
+    background: Color,
+    border: Color,
+    text_color: Color,
+}
+
+fn plain_from_toml(input: &AcetateToml, area: Rect, colors: KindColors) -> TomlAcetate {
+    let mut design = AcetateDesign {
+        area,
+        background: colors.background,
+        border: colors.border,
+        border_thickness: input.border_thickness,
+        text: input.text.clone(),
+        text_color: colors.text_color,
+        ..AcetateDesign::default()
+    };
+    if let Some(size) = input.font_size {
+        design.font_size = size;
+    }
+    TomlAcetate {
         id: input.id.clone(),
         name: input.id.clone(),
         z_index: input.z,
-        area: area.clone(),
-        design: AcetateDesign {
-            area,
-            background,
-            border,
-            border_thickness: input.border_thickness,
-            text: input.text.clone(),
-        },
+        area,
+        design,
+    }
+}
+
+/// `fill`, `border` y `text_color` son el estado normal; `hover`, `pressed`, `focused`
+/// y `disabled` sobrescriben lo que declaren.
+fn button_from_toml(
+    input: &AcetateToml,
+    acetate_index: usize,
+    area: Rect,
+    colors: KindColors,
+) -> Result<Button, UiTomlError> {
+    let normal = ButtonStateStyle {
+        fill: colors.background,
+        border: colors.border,
+        text_color: colors.text_color,
+    };
+    let mut style = ButtonStyle::from_normal(normal);
+    style.border_thickness = input.border_thickness;
+    if let Some(size) = input.font_size {
+        style.font_size = size;
+    }
+    let states = [
+        (&mut style.hover, &input.hover),
+        (&mut style.pressed, &input.pressed),
+        (&mut style.focused, &input.focused),
+        (&mut style.disabled, &input.disabled),
+    ];
+    for ((state, declared), fields) in states.into_iter().zip(STATE_FIELDS) {
+        *state = state_style(state, declared.as_ref(), acetate_index, fields)?;
+    }
+
+    let mut button = Button::new(&input.id, area)
+        .with_z_index(input.z)
+        .with_style(style);
+    if let Some(label) = &input.text {
+        button = button.with_label(label);
+    }
+    Ok(button)
+}
+
+fn toggle_from_toml(
+    input: &AcetateToml,
+    acetate_index: usize,
+    area: Rect,
+    colors: KindColors,
+) -> Result<Box<dyn Acetate>, UiTomlError> {
+    let style = toggle_style(input, acetate_index, colors)?;
+    Ok(if input.kind == AcetateKind::Checkbox {
+        let mut checkbox = Checkbox::new(&input.id, area)
+            .with_z_index(input.z)
+            .with_style(style)
+            .with_checked(input.checked);
+        if let Some(label) = &input.text {
+            checkbox = checkbox.with_label(label);
+        }
+        Box::new(checkbox)
+    } else {
+        let mut switch = Switch::new(&input.id, area)
+            .with_z_index(input.z)
+            .with_style(style)
+            .with_checked(input.checked);
+        if let Some(label) = &input.text {
+            switch = switch.with_label(label);
+        }
+        Box::new(switch)
+    })
+}
+
+fn radio_from_toml(
+    input: &AcetateToml,
+    acetate_index: usize,
+    area: Rect,
+    colors: KindColors,
+) -> Result<RadioGroup, UiTomlError> {
+    let style = toggle_style(input, acetate_index, colors)?;
+    let selected = selected_option(input, acetate_index)?;
+    Ok(RadioGroup::new(&input.id, area)
+        .with_z_index(input.z)
+        .with_style(style)
+        .with_options(input.options.iter().cloned())
+        .with_selected(selected))
+}
+
+/// Desplegable o menú contextual: `accent` es el resaltado de la opción activa.
+fn menu_from_toml(
+    input: &AcetateToml,
+    acetate_index: usize,
+    area: Rect,
+    colors: KindColors,
+) -> Result<Box<dyn Acetate>, UiTomlError> {
+    let mut style = MenuStyle {
+        background: colors.background,
+        border: colors.border,
+        text_color: colors.text_color,
+        ..MenuStyle::default()
+    };
+    if let Some(size) = input.font_size {
+        style.font_size = size;
+    }
+    if let Some(accent) = &input.accent {
+        style.highlight = parse_color(accent, Some(acetate_index), "accent")?;
+    }
+    if input.kind == AcetateKind::ContextMenu {
+        return Ok(Box::new(
+            ContextMenu::new(&input.id, area)
+                .with_z_index(input.z)
+                .with_style(style)
+                .with_items(input.options.iter().cloned()),
+        ));
+    }
+    let mut dropdown = Dropdown::new(&input.id, area)
+        .with_z_index(input.z)
+        .with_style(style)
+        .with_options(input.options.iter().cloned())
+        .with_selected(selected_option(input, acetate_index)?);
+    if let Some(placeholder) = &input.text {
+        dropdown = dropdown.with_placeholder(placeholder);
+    }
+    Ok(Box::new(dropdown))
+}
+
+fn modal_from_toml(input: &AcetateToml, area: Rect, colors: KindColors) -> Modal {
+    let mut style = ModalStyle {
+        panel: colors.background,
+        border: colors.border,
+        text_color: colors.text_color,
+        ..ModalStyle::default()
+    };
+    if let Some(size) = input.font_size {
+        style.font_size = size;
+    }
+    let mut modal = Modal::new(&input.id, area)
+        .with_z_index(input.z)
+        .with_style(style)
+        .with_buttons(input.options.iter().cloned());
+    if let Some(title) = &input.title {
+        modal = modal.with_title(title);
+    }
+    if let Some(message) = &input.text {
+        modal = modal.with_message(message);
+    }
+    if let Some(trigger) = &input.open_on {
+        modal = modal.with_trigger(trigger).with_open(false);
+    }
+    modal
+}
+
+/// `accent` es el indicador de la pestaña activa y `selected` su etiqueta.
+fn tabs_from_toml(
+    input: &AcetateToml,
+    acetate_index: usize,
+    area: Rect,
+    colors: KindColors,
+) -> Result<Tabs, UiTomlError> {
+    let mut style = TabStyle {
+        background: colors.background,
+        focus_border: colors.border,
+        active_text: colors.text_color,
+        ..TabStyle::default()
+    };
+    if let Some(size) = input.font_size {
+        style.font_size = size;
+    }
+    if let Some(accent) = &input.accent {
+        style.indicator = parse_color(accent, Some(acetate_index), "accent")?;
+    }
+    let active = match &input.selected {
+        Some(label) => input
+            .tabs
+            .iter()
+            .position(|tab| &tab.label == label)
+            .ok_or_else(|| UiTomlError::UnknownOption {
+                value: label.clone(),
+                acetate_index,
+            })?,
+        None => 0,
+    };
+    let tabs = input
+        .tabs
+        .iter()
+        .map(|tab| Tab::new(&tab.label).with_group(tab.group.iter().cloned()));
+    Ok(Tabs::new(&input.id, area)
+        .with_z_index(input.z)
+        .with_orientation(input.orientation)
+        .with_style(style)
+        .with_tabs(tabs)
+        .with_active(active))
+}
+
+/// `fill` es la pista y `accent` el relleno o el arco.
+fn progress_from_toml(
+    input: &AcetateToml,
+    acetate_index: usize,
+    area: Rect,
+    colors: KindColors,
+) -> Result<Box<dyn Acetate>, UiTomlError> {
+    let mut style = ProgressStyle {
+        track: colors.background,
+        text_color: colors.text_color,
+        ..ProgressStyle::default()
+    };
+    if let Some(size) = input.font_size {
+        style.font_size = size;
+    }
+    if let Some(accent) = &input.accent {
+        style.fill = parse_color(accent, Some(acetate_index), "accent")?;
+    }
+    if input.kind == AcetateKind::Spinner {
+        return Ok(Box::new(
+            Spinner::new(&input.id, area)
+                .with_z_index(input.z)
+                .with_style(style),
+        ));
+    }
+    let mut bar = ProgressBar::new(&input.id, area)
+        .with_z_index(input.z)
+        .with_style(style);
+    if let Some(value) = input.value {
+        bar = bar.with_value(value);
+    }
+    if let Some(label) = &input.text {
+        bar = bar.with_label(label);
+    }
+    Ok(Box::new(bar))
+}
+
+/// `fill` es el fondo, `border` los ejes y `accent` la primera serie.
+fn chart_from_toml(
+    input: &AcetateToml,
+    acetate_index: usize,
+    area: Rect,
+    colors: KindColors,
+) -> Result<Chart, UiTomlError> {
+    let mut style = ChartStyle {
+        background: colors.background,
+        text_color: colors.text_color,
+        ..ChartStyle::default()
+    };
+    if let Some(size) = input.font_size {
+        style.font_size = size;
+    }
+    if colors.border.a > 0.0 {
+        style.axis = colors.border;
+    }
+    if let Some(accent) = &input.accent {
+        style.palette[0] = parse_color(accent, Some(acetate_index), "accent")?;
+    }
+    let mut chart = Chart::new(&input.id, area)
+        .with_z_index(input.z)
+        .with_kind(input.chart)
+        .with_style(style);
+    if let Some(title) = &input.title {
+        chart = chart.with_title(title);
+    }
+    Ok(chart)
+}
+
+/// `fill` es el fondo del cuerpo, `border` el contorno y los separadores, `accent` la
+/// selección y `stripe` la cebra. Las filas llegan después desde la app (`Data::Rows`).
+fn table_from_toml(
+    input: &AcetateToml,
+    acetate_index: usize,
+    area: Rect,
+    colors: KindColors,
+) -> Result<DataTable, UiTomlError> {
+    let defaults = TableStyle::default();
+    let mut body = ListStyle {
+        background: colors.background,
+        border: colors.border,
+        text_color: colors.text_color,
+        ..defaults.body
+    };
+    if let Some(size) = input.font_size {
+        body.font_size = size;
+    }
+    if let Some(accent) = &input.accent {
+        body.selection = parse_color(accent, Some(acetate_index), "accent")?;
+    }
+    if let Some(stripe) = &input.stripe {
+        body.stripe = Some(parse_color(stripe, Some(acetate_index), "stripe")?);
+    }
+    let style = TableStyle {
+        header_text: colors.text_color,
+        divider: colors.border,
+        body,
+        ..defaults
+    };
+
+    let columns = input
+        .columns
+        .iter()
+        .map(|column| Column::new(&column.title, column.width).with_sortable(column.sortable));
+    let mut table = DataTable::new(&input.id, area)
+        .with_z_index(input.z)
+        .with_style(style)
+        .with_columns(columns)
+        .with_selection_mode(input.selection);
+    if let Some(row_height) = input.row_height {
+        table = table.with_row_height(row_height);
+    }
+    if let Some(header_height) = input.header_height {
+        table = table.with_header_height(header_height);
+    }
+    Ok(table)
+}
+
+/// Índice de `selected` dentro de `options` (radio, desplegable).
+fn selected_option(
+    input: &AcetateToml,
+    acetate_index: usize,
+) -> Result<Option<usize>, UiTomlError> {
+    let Some(value) = &input.selected else {
+        return Ok(None);
+    };
+    input
+        .options
+        .iter()
+        .position(|option| option == value)
+        .map(Some)
+        .ok_or_else(|| UiTomlError::UnknownOption {
+            value: value.clone(),
+            acetate_index,
+        })
+}
+
+/// `fill` es la pista, `accent` el tramo activo y `border` el contorno de los pulgares.
+fn slider_from_toml(
+    input: &AcetateToml,
+    acetate_index: usize,
+    area: Rect,
+    colors: KindColors,
+) -> Result<Slider, UiTomlError> {
+    let min = input.min.unwrap_or(0.0);
+    let max = input.max.unwrap_or(1.0);
+    if min > max {
+        return Err(UiTomlError::InvalidValue {
+            value: format!("{}..{}", min, max),
+            acetate_index,
+            field: "max",
+        });
+    }
+    let mut style = SliderStyle {
+        track: colors.background,
+        thumb_border: colors.border,
+        ..SliderStyle::default()
+    };
+    if let Some(accent) = &input.accent {
+        style.fill = parse_color(accent, Some(acetate_index), "accent")?;
+    }
+
+    let slider = Slider::new(&input.id, area)
+        .with_z_index(input.z)
+        .with_orientation(input.orientation)
+        .with_bounds(min, max, input.step.unwrap_or((max - min) / 100.0))
+        .with_ticks(input.ticks)
+        .with_style(style);
+    Ok(match input.range {
+        Some([low, high]) => slider.with_range(low, high),
+        None => slider.with_value(input.value.unwrap_or(min)),
+    })
+}
+
+/// Estilo de checkbox/switch/radio: `fill` es el estado apagado y `accent` el encendido.
+fn toggle_style(
+    input: &AcetateToml,
+    acetate_index: usize,
+    colors: KindColors,
+) -> Result<ToggleStyle, UiTomlError> {
+    let mut style = ToggleStyle {
+        off_fill: colors.background,
+        border: colors.border,
+        text_color: colors.text_color,
+        ..ToggleStyle::default()
+    };
+    if let Some(size) = input.font_size {
+        style.font_size = size;
+    }
+    if let Some(accent) = &input.accent {
+        style.on_fill = parse_color(accent, Some(acetate_index), "accent")?;
+    }
+    Ok(style)
+}
+
+/// Nombres de campo (para errores) de cada tabla de estado: fill, border, text_color.
+const STATE_FIELDS: [[&str; 3]; 4] = [
+    ["hover.fill", "hover.border", "hover.text_color"],
+    ["pressed.fill", "pressed.border", "pressed.text_color"],
+    ["focused.fill", "focused.border", "focused.text_color"],
+    ["disabled.fill", "disabled.border", "disabled.text_color"],
+];
+
+/// Aplica sobre el estilo derivado los colores declarados en la tabla del estado.
+fn state_style(
+    derived: &ButtonStateStyle,
+    input: Option<&StateStyleToml>,
+    acetate_index: usize,
+    fields: [&'static str; 3],
+) -> Result<ButtonStateStyle, UiTomlError> {
+    let Some(input) = input else {
+        return Ok(derived.clone());
+    };
+    let color = |value: &Option<String>, fallback: Color, field| match value {
+        Some(value) => parse_color(value, Some(acetate_index), field),
+        None => Ok(fallback),
+    };
+    Ok(ButtonStateStyle {
+        fill: color(&input.fill, derived.fill, fields[0])?,
+        border: color(&input.border, derived.border, fields[1])?,
+        text_color: color(&input.text_color, derived.text_color, fields[2])?,
     })
 }
 
@@ -281,6 +1046,22 @@ fn parse_u32_dimensions(
     Ok(value as u32)
 }
 
+/// Ancho o alto de un acetate: admite decimales, pero no valores negativos.
+fn parse_dimension(
+    value: f32,
+    field: &'static str,
+    acetate_index: usize,
+) -> Result<f32, UiTomlError> {
+    if value < 0.0 {
+        return Err(UiTomlError::InvalidDimensions {
+            value: value.to_string(),
+            acetate_index: Some(acetate_index),
+            field,
+        });
+    }
+    Ok(value)
+}
+
 fn parse_i32(
     value: i64,
     field: &'static str,
@@ -300,6 +1081,190 @@ fn parse_i32(
     Ok(value as i32)
 }
 
+fn layout_error(value: impl ToString, field: &'static str) -> UiTomlError {
+    UiTomlError::InvalidLayout {
+        value: value.to_string(),
+        field,
+    }
+}
+
+fn parse_tracks(tracks: &[TrackToml], field: &'static str) -> Result<Vec<Track>, UiTomlError> {
+    tracks
+        .iter()
+        .map(|track| match track {
+            TrackToml::Pixels(px) if *px >= 0.0 => Ok(Track::Fixed(*px)),
+            TrackToml::Pixels(px) => Err(layout_error(px, field)),
+            TrackToml::Text(text) => Track::parse(text).ok_or_else(|| layout_error(text, field)),
+        })
+        .collect()
+}
+
+/// Traduce `[layout]` a `LayoutNode`. Las hojas sin `width`/`height` toman el tamaño
+/// declarado del acetate (`sizes`), y un id que no existe es un error.
+fn layout_from_toml(
+    raw: &LayoutToml,
+    sizes: &[(String, f32, f32)],
+) -> Result<LayoutNode, UiTomlError> {
+    let mut node = match (&raw.id, raw.kind.as_deref()) {
+        (Some(id), None) => {
+            let (_, w, h) = sizes
+                .iter()
+                .find(|(known, _, _)| known == id)
+                .ok_or_else(|| layout_error(id, "id"))?;
+            LayoutNode::acetate(id).with_size(*w, *h)
+        }
+        (Some(_), Some(kind)) => return Err(layout_error(kind, "kind")),
+        (None, kind) => {
+            let children = raw
+                .children
+                .iter()
+                .map(|child| layout_from_toml(child, sizes))
+                .collect::<Result<Vec<_>, _>>()?;
+            match kind.unwrap_or("column") {
+                "row" => LayoutNode::row(children),
+                "column" => LayoutNode::column(children),
+                "stack" => LayoutNode::stack(children),
+                "grid" => LayoutNode::grid(
+                    parse_tracks(&raw.columns, "columns")?,
+                    parse_tracks(&raw.rows, "rows")?,
+                    children,
+                ),
+                other => return Err(layout_error(other, "kind")),
+            }
+        }
+    };
+    if raw.width.is_some() || raw.height.is_some() {
+        let (width, height) = node.size.unwrap_or((0.0, 0.0));
+        node = node.with_size(raw.width.unwrap_or(width), raw.height.unwrap_or(height));
+    }
+    if let Some(gap) = raw.gap {
+        node = node.with_gap(gap);
+    }
+    if let Some(padding) = raw.padding {
+        node = node.with_padding(padding);
+    }
+    if let Some(align) = &raw.align {
+        node = node.with_align(Align::parse(align).ok_or_else(|| layout_error(align, "align"))?);
+    }
+    if let Some(justify) = &raw.justify {
+        node = node.with_justify(
+            Justify::parse(justify).ok_or_else(|| layout_error(justify, "justify"))?,
+        );
+    }
+    if let Some(basis) = raw.basis {
+        node = node.with_basis(basis);
+    }
+    if let Some(grow) = raw.grow {
+        node = node.with_grow(grow);
+    }
+    if let Some(shrink) = raw.shrink {
+        node = node.with_shrink(shrink);
+    }
+    if let Some([column, row]) = raw.cell {
+        node = node.with_cell(column, row);
+    }
+    if let Some([columns, rows]) = raw.span {
+        node = node.with_span(columns, rows);
+    }
+    Ok(node)
+}
+
+fn parse_length(
+    value: &ValueToml,
+    field: &'static str,
+    acetate_index: usize,
+) -> Result<Length, UiTomlError> {
+    match value {
+        ValueToml::Pixels(px) => Ok(Length::px(*px)),
+        ValueToml::Text(text) => Length::parse(text).ok_or_else(|| UiTomlError::InvalidValue {
+            value: text.clone(),
+            acetate_index,
+            field,
+        }),
+    }
+}
+
+/// Ancla el borde o tamaño que corresponde a una longitud de `x`/`y`/`w`/`h`; si ese
+/// borde ya está anclado, es un conflicto.
+fn length_constraint(
+    constraints: &mut Constraints,
+    length: Length,
+    field: &'static str,
+    acetate_index: usize,
+) -> Result<(), UiTomlError> {
+    match field {
+        "x" if constraints.left.is_none() => constraints.left = Some(Anchor::Fixed(length)),
+        "y" if constraints.top.is_none() => constraints.top = Some(Anchor::Fixed(length)),
+        "w" => constraints.width = Some(length),
+        "h" => constraints.height = Some(length),
+        _ => {
+            return Err(UiTomlError::InvalidValue {
+                value: length.to_string(),
+                acetate_index,
+                field,
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Completa los ejes indicados para que no dependan del área actual: sin posición se
+/// ancla a la declarada y, si las anclas no deducen el tamaño, se fija el declarado.
+fn pin(
+    mut constraints: Constraints,
+    declared: &Rect,
+    horizontal: bool,
+    vertical: bool,
+) -> Constraints {
+    let count = |anchors: [&Option<Anchor>; 3]| anchors.iter().filter(|a| a.is_some()).count();
+    if horizontal {
+        let positions = count([&constraints.left, &constraints.right, &constraints.center_x]);
+        if positions == 0 {
+            constraints.left = Some(Anchor::Fixed(Length::px(declared.x)));
+        }
+        if positions < 2 && constraints.width.is_none() {
+            constraints.width = Some(Length::px(declared.width));
+        }
+    }
+    if vertical {
+        let positions = count([&constraints.top, &constraints.bottom, &constraints.center_y]);
+        if positions == 0 {
+            constraints.top = Some(Anchor::Fixed(Length::px(declared.y)));
+        }
+        if positions < 2 && constraints.height.is_none() {
+            constraints.height = Some(Length::px(declared.height));
+        }
+    }
+    constraints
+}
+
+/// Anclas de un acetate bajo un breakpoint: cada eje que el breakpoint toca sustituye
+/// entero al de la base.
+fn override_constraints(base: &Constraints, moved: Constraints, declared: &Rect) -> Constraints {
+    let horizontal = moved.left.is_some()
+        || moved.right.is_some()
+        || moved.center_x.is_some()
+        || moved.width.is_some();
+    let vertical = moved.top.is_some()
+        || moved.bottom.is_some()
+        || moved.center_y.is_some()
+        || moved.height.is_some();
+    let mut constraints = base.clone();
+    if horizontal {
+        constraints.left = moved.left;
+        constraints.right = moved.right;
+        constraints.center_x = moved.center_x;
+        constraints.width = moved.width;
+    }
+    if vertical {
+        constraints.top = moved.top;
+        constraints.bottom = moved.bottom;
+        constraints.center_y = moved.center_y;
+        constraints.height = moved.height;
+    }
+    pin(constraints, declared, horizontal, vertical)
+}
+
 fn build_scene_from_raw(
     raw: RawUiToml,
     include_acetates: Vec<RawAcetateToml>,
@@ -309,25 +1274,175 @@ fn build_scene_from_raw(
     let scene_height = require_field(scene.height, "scene.height", None)?;
     let width = parse_u32_dimensions(scene_width, "scene.width", None)?;
     let height = parse_u32_dimensions(scene_height, "scene.height", None)?;
+    let metrics = Metrics {
+        margin: parse_u32_dimensions(scene.margin.unwrap_or(0), "scene.margin", None)?,
+        spacing: parse_u32_dimensions(scene.spacing.unwrap_or(0), "scene.spacing", None)?,
+        ..Metrics::default()
+    };
+    let viewport = (width as f32, height as f32);
+    let mut breakpoints: BTreeMap<String, Breakpoint> = raw
+        .breakpoint
+        .iter()
+        .map(|(name, raw)| {
+            let breakpoint = Breakpoint {
+                min_width: raw.min_width,
+                max_width: raw.max_width,
+                min_height: raw.min_height,
+                max_height: raw.max_height,
+                ..Breakpoint::new(name)
+            };
+            (name.clone(), breakpoint)
+        })
+        .collect();
+    // Los acetates colocados por `[layout]` (el de la escena o el de algún breakpoint)
+    // pueden omitir su posición y tamaño.
+    let base_layout: Vec<&str> = raw.layout.as_ref().map(LayoutToml::ids).unwrap_or_default();
+    let breakpoint_layouts: Vec<&str> = raw
+        .breakpoint
+        .values()
+        .filter_map(|breakpoint| breakpoint.layout.as_ref())
+        .flat_map(LayoutToml::ids)
+        .collect();
+    let laid_out: Vec<String> = base_layout
+        .iter()
+        .chain(&breakpoint_layouts)
+        .map(|id| id.to_string())
+        .collect();
+    // Acetates que se mueven con algún breakpoint: fuera de él deben volver a su sitio.
+    let mut responsive: Vec<String> = breakpoint_layouts.iter().map(|id| id.to_string()).collect();
 
     let mut raw_acetates = raw.acetate;
     raw_acetates.extend(include_acetates);
 
     let mut acetates: Vec<Box<dyn Acetate>> = Vec::with_capacity(raw_acetates.len());
     let mut parsed_acetate = Vec::with_capacity(raw_acetates.len());
+    let mut constraints = HashMap::new();
+    let mut transitions = HashMap::new();
+    let mut relative_sizes = HashMap::new();
 
     for (index, acetate) in raw_acetates.into_iter().enumerate() {
-        let id = require_field(acetate.id, "id", Some(index))?;
+        let id = require_field(acetate.id.clone(), "id", Some(index))?;
         let z = parse_i32(acetate.z, "z", Some(index))?;
-        let x = parse_i32(require_field(acetate.x, "x", Some(index))?, "x", Some(index))?;
-        let y = parse_i32(require_field(acetate.y, "y", Some(index))?, "y", Some(index))?;
-        let w =
-            parse_u32_dimensions(require_field(acetate.w, "w", Some(index))?, "w", Some(index))?;
-        let h =
-            parse_u32_dimensions(require_field(acetate.h, "h", Some(index))?, "h", Some(index))?;
+        let mut anchored = constraints_from_toml(&acetate.geometry, index)?;
+        // Las longitudes se resuelven contra el tamaño declarado de la escena; las que
+        // no son solo píxeles quedan además como anclas para seguir a la ventana.
+        let mut resolved = [None; 4];
+        for (slot, (field, value)) in resolved.iter_mut().zip(acetate.geometry.lengths()) {
+            let Some(value) = value else {
+                continue;
+            };
+            let length = parse_length(value, field, index)?;
+            if !length.is_px() {
+                length_constraint(&mut anchored, length, field, index)?;
+            }
+            let reference = if matches!(field, "x" | "w") { width } else { height };
+            let px = length.resolve(reference as f32, viewport);
+            *slot = Some(px);
+        }
+        let [x, y, w, h] = resolved;
+        // Lo que fijan el layout o las anclas (la posición con una en el eje, el
+        // tamaño con dos) puede omitirse.
+        let count = |anchors: [&Option<Anchor>; 3]| anchors.iter().filter(|a| a.is_some()).count();
+        let horizontal = count([&anchored.left, &anchored.right, &anchored.center_x]);
+        let vertical = count([&anchored.top, &anchored.bottom, &anchored.center_y]);
+        let in_layout = laid_out.contains(&id);
+        let placed = |value: Option<f32>, field, derived: bool| match value {
+            None if in_layout || derived => Ok(0.0),
+            value => require_field(value, field, Some(index)),
+        };
+        let x = placed(x, "x", horizontal > 0)?;
+        let y = placed(y, "y", vertical > 0)?;
+        let w = parse_dimension(placed(w, "w", horizontal > 1)?, "w", index)?;
+        let h = parse_dimension(placed(h, "h", vertical > 1)?, "h", index)?;
+        let declared = Rect::new(x, y, w, h);
+        for (name, moved) in &acetate.breakpoint {
+            let breakpoint = breakpoints.get_mut(name).ok_or(UiTomlError::InvalidValue {
+                value: name.clone(),
+                acetate_index: index,
+                field: "breakpoint",
+            })?;
+            if moved.hidden {
+                breakpoint.hidden.push(id.clone());
+            }
+            let mut geometry = constraints_from_toml(&moved.geometry, index)?;
+            for (field, value) in moved.geometry.lengths() {
+                if let Some(value) = value {
+                    let length = parse_length(value, field, index)?;
+                    length_constraint(&mut geometry, length, field, index)?;
+                }
+            }
+            if !geometry.is_empty() {
+                let constraints = override_constraints(&anchored, geometry, &declared);
+                breakpoint.constraints.insert(id.clone(), constraints);
+                responsive.push(id.clone());
+            }
+        }
+        if responsive.contains(&id) && !base_layout.contains(&id.as_str()) {
+            anchored = pin(anchored, &declared, true, true);
+        }
+        if !anchored.is_empty() {
+            constraints.insert(id.clone(), anchored);
+        }
+        if let Some(transition) = &acetate.transition {
+            transitions.insert(id.clone(), transition_from_toml(transition, index)?);
+        }
+        // Bordes y fuentes se resuelven contra la escena declarada (`%` es de su alto);
+        // los que dependen de ella quedan además en `Scene::sizes` para seguir a la
+        // ventana.
+        let declared_sizes = Sizes {
+            border_thickness: Some(parse_length(
+                &acetate.border_thickness,
+                "border_thickness",
+                index,
+            )?),
+            font_size: acetate
+                .font_size
+                .as_ref()
+                .map(|value| parse_length(value, "font_size", index))
+                .transpose()?,
+        };
+        let relative = Sizes {
+            border_thickness: declared_sizes.border_thickness.filter(|l| !l.is_fixed()),
+            font_size: declared_sizes.font_size.filter(|l| !l.is_fixed()),
+        };
+        if relative != Sizes::default() {
+            relative_sizes.insert(id.clone(), relative);
+        }
+        let (border_thickness, font_size) = declared_sizes.resolve(viewport);
+        let border_thickness = border_thickness.unwrap_or_default();
         let fill = require_field(acetate.fill, "fill", Some(index))?;
         let border = acetate.border;
-        let border_thickness = acetate.border_thickness;
+        let kind = match acetate.kind {
+            Some(value) => AcetateKind::parse(&value).ok_or(UiTomlError::InvalidKind {
+                value,
+                acetate_index: index,
+            })?,
+            None => AcetateKind::Plain,
+        };
+        let orientation = match acetate.orientation {
+            Some(value) => Orientation::parse(&value).ok_or(UiTomlError::InvalidValue {
+                value,
+                acetate_index: index,
+                field: "orientation",
+            })?,
+            None => Orientation::default(),
+        };
+        let selection = match acetate.selection {
+            Some(value) => SelectionMode::parse(&value).ok_or(UiTomlError::InvalidValue {
+                value,
+                acetate_index: index,
+                field: "selection",
+            })?,
+            None => SelectionMode::default(),
+        };
+        let chart = match acetate.chart {
+            Some(value) => ChartKind::parse(&value).ok_or(UiTomlError::InvalidValue {
+                value,
+                acetate_index: index,
+                field: "chart",
+            })?,
+            None => ChartKind::default(),
+        };
 
         parsed_acetate.push(AcetateToml {
             id,
@@ -340,24 +1455,217 @@ fn build_scene_from_raw(
             border,
             border_thickness,
             text: acetate.text,
+            text_color: acetate.text_color,
+            font_size,
+            kind,
+            hover: acetate.hover,
+            pressed: acetate.pressed,
+            focused: acetate.focused,
+            disabled: acetate.disabled,
+            accent: acetate.accent,
+            checked: acetate.checked,
+            options: acetate.options,
+            selected: acetate.selected,
+            min: acetate.min,
+            max: acetate.max,
+            step: acetate.step,
+            value: acetate.value,
+            range: acetate.range,
+            orientation,
+            ticks: acetate.ticks,
+            title: acetate.title,
+            open_on: acetate.open_on,
+            tooltip: acetate.tooltip,
+            columns: acetate.columns,
+            row_height: acetate.row_height,
+            header_height: acetate.header_height,
+            selection,
+            stripe: acetate.stripe,
+            tabs: acetate.tabs,
+            chart,
         });
     }
 
     for (index, acetate) in parsed_acetate.iter().enumerate() {
-        let instance = acetate_from_toml(acetate, index)?;
-        acetates.push(Box::new(instance) as Box<dyn Acetate>);
+        acetates.push(acetate_from_toml(acetate, index)?);
+    }
+
+    let sizes: Vec<(String, f32, f32)> = parsed_acetate
+        .iter()
+        .map(|acetate| (acetate.id.clone(), acetate.w, acetate.h))
+        .collect();
+    let layout = raw
+        .layout
+        .as_ref()
+        .map(|layout| layout_from_toml(layout, &sizes))
+        .transpose()?;
+    for (name, raw) in &raw.breakpoint {
+        if let Some(layout) = &raw.layout {
+            breakpoints.get_mut(name).expect("declared breakpoint").layout =
+                Some(layout_from_toml(layout, &sizes)?);
+        }
     }
 
-    Ok(Scene {
+    let animations = raw
+        .animation
+        .iter()
+        .enumerate()
+        .map(|(index, animation)| animation_from_toml(animation, index, &acetates))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let scene = Scene {
         width,
         height,
-        metrics: Metrics {
-            scale: 1.0,
-            margin: 0,
-            spacing: 0,
-        },
+        metrics,
         acetates,
-    })
+        layout,
+        constraints,
+        breakpoints: breakpoints.into_values().collect(),
+        sizes: relative_sizes,
+        animations,
+        springs: Vec::new(),
+        transitions,
+    };
+    // Ids desconocidos, ciclos y ejes sobrerrestringidos se detectan al cargar, con y
+    // sin cada breakpoint.
+    LayoutEngine::constrain(&scene).map_err(UiTomlError::Constraint)?;
+    for breakpoint in &scene.breakpoints {
+        LayoutEngine::constrain(&breakpoint.apply(scene.clone())).map_err(UiTomlError::Constraint)?;
+    }
+    Ok(scene)
+}
+
+/// `[[animation]]` -> `Animation`. `from` y `to` son los fotogramas 0 y 1 y se
+/// combinan con `[[animation.keyframes]]`; sin `from` se parte del valor declarado.
+fn animation_from_toml(
+    input: &AnimationToml,
+    index: usize,
+    acetates: &[Box<dyn Acetate>],
+) -> Result<Animation, UiTomlError> {
+    let invalid = |value: String, field: &'static str| UiTomlError::InvalidAnimation {
+        value,
+        animation_index: index,
+        field,
+    };
+    let target = acetates
+        .iter()
+        .find(|acetate| acetate.id() == input.target)
+        .ok_or_else(|| invalid(input.target.clone(), "target"))?;
+    let property = AnimatedProperty::parse(&input.property)
+        .ok_or_else(|| invalid(input.property.clone(), "property"))?;
+    let value = |value: &AnimationValueToml, field: &'static str| {
+        animation_value(value, property).ok_or_else(|| invalid(describe_value(value), field))
+    };
+
+    let from = match &input.from {
+        Some(from) => value(from, "from")?,
+        None => property.read(target.as_ref()),
+    };
+    let mut keyframes = vec![Keyframe {
+        offset: 0.0,
+        value: from,
+    }];
+    // Los offsets van en orden estricto entre `from` (0) y `to` (1); sin `to`, el último
+    // fotograma puede ocupar el 1.
+    let mut previous = 0.0;
+    for (i, keyframe) in input.keyframes.iter().enumerate() {
+        let last = i + 1 == input.keyframes.len();
+        let within = if last && input.to.is_none() {
+            keyframe.offset <= 1.0
+        } else {
+            keyframe.offset < 1.0
+        };
+        if keyframe.offset <= previous || !within {
+            return Err(invalid(keyframe.offset.to_string(), "keyframes.offset"));
+        }
+        previous = keyframe.offset;
+        keyframes.push(Keyframe {
+            offset: keyframe.offset,
+            value: value(&keyframe.value, "keyframes.value")?,
+        });
+    }
+    match &input.to {
+        Some(to) => keyframes.push(Keyframe {
+            offset: 1.0,
+            value: value(to, "to")?,
+        }),
+        None if input.keyframes.is_empty() => return Err(invalid("missing".to_string(), "to")),
+        None => {}
+    }
+
+    let easing = match &input.easing {
+        Some(easing) => Easing::parse(easing).ok_or_else(|| invalid(easing.clone(), "easing"))?,
+        None => Easing::default(),
+    };
+    let repeat = match &input.repeat {
+        None => Repeat::default(),
+        Some(RepeatToml::Times(times)) => Repeat::Times(*times),
+        Some(RepeatToml::Text(text)) if text.trim() == "infinite" => Repeat::Forever,
+        Some(RepeatToml::Text(text)) => return Err(invalid(text.clone(), "repeat")),
+    };
+
+    Ok(Animation::keyframes(
+        &input.target,
+        property,
+        keyframes,
+        Duration::from_millis(input.duration_ms),
+    )
+    .with_delay(Duration::from_millis(input.delay_ms))
+    .with_easing(easing)
+    .with_repeat(repeat)
+    .with_alternate(input.alternate)
+    .with_name(input.name.as_deref().unwrap_or_default()))
+}
+
+/// Valor del TOML para `property`: números en la geometría y la opacidad (0..1), colores
+/// en `fill`/`border` y tablas en `transform`.
+fn animation_value(
+    value: &AnimationValueToml,
+    property: AnimatedProperty,
+) -> Option<AnimationValue> {
+    match (property, value) {
+        (AnimatedProperty::X | AnimatedProperty::Y, AnimationValueToml::Number(number)) => {
+            Some(AnimationValue::Number(*number))
+        }
+        (AnimatedProperty::Width | AnimatedProperty::Height, AnimationValueToml::Number(number))
+            if *number >= 0.0 =>
+        {
+            Some(AnimationValue::Number(*number))
+        }
+        (AnimatedProperty::Opacity, AnimationValueToml::Number(number))
+            if (0.0..=1.0).contains(number) =>
+        {
+            Some(AnimationValue::Number(*number))
+        }
+        (
+            AnimatedProperty::Fill | AnimatedProperty::BorderColor,
+            AnimationValueToml::Text(text),
+        ) => parse_color(text, None, "color").ok().map(AnimationValue::Color),
+        (AnimatedProperty::Transform, AnimationValueToml::Transform(transform)) => {
+            Some(AnimationValue::Transform(transform_from_toml(transform)))
+        }
+        _ => None,
+    }
+}
+
+fn transform_from_toml(transform: &TransformToml) -> Transform {
+    let (scale_x, scale_y) = match transform.scale {
+        Some(ScaleToml::Uniform(scale)) => (scale, scale),
+        Some(ScaleToml::Axes([scale_x, scale_y])) => (scale_x, scale_y),
+        None => (1.0, 1.0),
+    };
+    let [x, y] = transform.translate.unwrap_or([0.0, 0.0]);
+    Transform::scale(scale_x, scale_y)
+        .then(Transform::rotate(transform.rotate.unwrap_or(0.0).to_radians()))
+        .then(Transform::translate(x, y))
+}
+
+fn describe_value(value: &AnimationValueToml) -> String {
+    match value {
+        AnimationValueToml::Number(number) => number.to_string(),
+        AnimationValueToml::Text(text) => text.clone(),
+        AnimationValueToml::Transform(transform) => format!("{transform:?}"),
+    }
 }
 
 pub fn load_scene_from_str(toml_str: &str) -> Result<Scene, UiTomlError> {
@@ -402,6 +1710,8 @@ pub fn load_scene_from_file(path: impl AsRef<Path>) -> Result<Scene, UiTomlError
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::actors::layout_engine::LayoutEngine;
+    use crate::contracts::event::Data;
     use std::time::{SystemTime, UNIX_EPOCH};
 
     #[test]
@@ -458,4 +1768,467 @@ fill = "#112233"
         let _ = fs::remove_file(&include_path);
         let _ = fs::remove_dir_all(&root);
     }
+
+    #[test]
+    fn button_kind_uses_declared_hover_style() {
+        let scene = load_scene_from_str(
+            r##"
+[scene]
+width = 200
+height = 100
+
+[[acetate]]
+id = "ok"
+kind = "button"
+x = 10
+y = 10
+w = 80
+h = 30
+fill = "#224488"
+text = "OK"
+
+[acetate.hover]
+fill = "#336699"
+"##,
+        )
+        .expect("load scene");
+
+        let button = &scene.acetates[0];
+        assert!(!button.subscriptions().is_empty());
+        assert_eq!(button.design().background, parse_color("#224488", None, "fill").unwrap());
+
+        let hovered = button
+            .react(&Event::MouseMoved(20, 20), &scene.info())
+            .expect("hover changes state");
+        assert_eq!(hovered.design().background, parse_color("#336699", None, "fill").unwrap());
+        assert_eq!(hovered.design().text.as_deref(), Some("OK"));
+
+        let err = load_scene_from_str(
+            r##"
+[scene]
+width = 10
+height = 10
+
+[[acetate]]
+id = "x"
+kind = "slidr"
+x = 0
+y = 0
+w = 1
+h = 1
+fill = "#000000"
+"##,
+        )
+        .expect_err("unknown kind");
+        assert!(matches!(err, UiTomlError::InvalidKind { acetate_index: 0, .. }));
+    }
+
+    #[test]
+    fn radio_kind_selects_declared_option() {
+        let scene = load_scene_from_str(
+            r##"
+[scene]
+width = 200
+height = 100
+
+[[acetate]]
+id = "size"
+kind = "radio"
+x = 0
+y = 0
+w = 100
+h = 72
+fill = "#202020"
+options = ["S", "M", "L"]
+selected = "M"
+"##,
+        )
+        .expect("load scene");
+        assert_eq!(scene.acetates[0].output().content.as_deref(), Some("M"));
+
+        let err = load_scene_from_str(
+            r##"
+[scene]
+width = 10
+height = 10
+
+[[acetate]]
+id = "size"
+kind = "radio"
+x = 0
+y = 0
+w = 1
+h = 1
+fill = "#000000"
+options = ["S"]
+selected = "XL"
+"##,
+        )
+        .expect_err("unknown option");
+        assert!(matches!(err, UiTomlError::UnknownOption { acetate_index: 0, .. }));
+    }
+
+    #[test]
+    fn slider_kind_reads_range_and_rejects_bad_orientation() {
+        let slider = r##"
+[scene]
+width = 200
+height = 100
+
+[[acetate]]
+id = "price"
+kind = "slider"
+x = 0
+y = 0
+w = 200
+h = 20
+fill = "#202020"
+min = 0
+max = 500
+step = 10
+range = [100, 250]
+"##;
+        let scene = load_scene_from_str(slider).expect("load scene");
+        assert_eq!(scene.acetates[0].output().content.as_deref(), Some("100..250"));
+
+        let err = load_scene_from_str(&format!("{slider}orientation = \"diagonal\"\n"))
+            .expect_err("bad orientation");
+        assert!(matches!(
+            err,
+            UiTomlError::InvalidValue {
+                field: "orientation",
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn table_kind_reads_columns_and_takes_rows_from_the_app() {
+        let table = r##"
+[scene]
+width = 400
+height = 200
+
+[[acetate]]
+id = "users"
+kind = "table"
+x = 0
+y = 0
+w = 300
+h = 124
+fill = "#202020"
+row_height = 20
+selection = "multi"
+
+[[acetate.columns]]
+title = "Name"
+width = 160
+
+[[acetate.columns]]
+title = "Id"
+width = 60
+sortable = false
+"##;
+        let scene = load_scene_from_str(table).expect("load scene");
+        let header = scene.acetates[0].children();
+        assert_eq!(header[0].output().content.as_deref(), Some("Name"));
+        assert_eq!(header[1].area().width, 60.0);
+
+        let rows = vec![vec!["Ana".to_string(), "7".to_string()]];
+        let scene = scene.react(&[Event::Data {
+            target: "users".to_string(),
+            data: Data::Rows(std::sync::Arc::new(rows)),
+        }]);
+        assert_eq!(scene.acetates[0].children()[2].children().len(), 1);
+
+        let err = load_scene_from_str(&table.replace("\"multi\"", "\"many\""))
+            .expect_err("bad selection");
+        assert!(matches!(
+            err,
+            UiTomlError::InvalidValue {
+                field: "selection",
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn layout_table_places_acetates_without_coordinates() {
+        let ui = r##"
+[scene]
+width = 400
+height = 300
+margin = 10
+spacing = 10
+
+[[acetate]]
+id = "title"
+h = 40
+fill = "#202020"
+
+[[acetate]]
+id = "body"
+fill = "#303030"
+
+[layout]
+kind = "column"
+
+[[layout.children]]
+id = "title"
+
+[[layout.children]]
+id = "body"
+grow = 1
+"##;
+        let scene = load_scene_from_str(ui).expect("load scene");
+        assert!(scene.layout.is_some());
+        let scene = LayoutEngine::apply(scene).expect("layout");
+        assert_eq!(
+            scene.acetates[1].area(),
+            Rect::new(10.0, 60.0, 380.0, 230.0)
+        );
+
+        let err = load_scene_from_str(&format!("{ui}\n[[layout.children]]\nid = \"footer\"\n"))
+            .expect_err("unknown id");
+        assert!(matches!(err, UiTomlError::InvalidLayout { field: "id", .. }));
+    }
+
+    #[test]
+    fn relative_units_follow_the_scene_size() {
+        let ui = r##"
+[scene]
+width = 400
+height = 200
+
+[[acetate]]
+id = "panel"
+x = "25%"
+y = 10
+w = "calc(50% - 20)"
+h = "10vh"
+fill = "#202020"
+border_thickness = "0.5vw"
+font_size = "5%"
+"##;
+        let scene = load_scene_from_str(ui).expect("load scene");
+        let design = scene.acetates[0].design();
+        assert_eq!(design.area, Rect::new(100.0, 10.0, 180.0, 20.0));
+        // El `%` de bordes y fuentes es del alto de la escena.
+        assert_eq!(design.border_thickness, 2.0);
+        assert_eq!(design.font_size, 10.0);
+
+        // Al cambiar la ventana se recalculan como la geometría.
+        let wide = LayoutEngine::apply(Scene {
+            width: 800,
+            height: 400,
+            ..scene
+        })
+        .expect("layout");
+        let design = wide.acetates[0].design();
+        assert_eq!(design.area, Rect::new(200.0, 10.0, 380.0, 40.0));
+        assert_eq!(design.border_thickness, 4.0);
+        assert_eq!(design.font_size, 20.0);
+
+        let fixed = ui.replace("\"0.5vw\"", "\"2dp\"").replace("\"5%\"", "12");
+        let fixed = load_scene_from_str(&fixed).expect("fixed sizes");
+        assert!(fixed.sizes.is_empty());
+        assert_eq!(fixed.acetates[0].design().font_size, 12.0);
+
+        let err = load_scene_from_str(&ui.replace("\"25%\"", "\"25 apples\"")).expect_err("bad x");
+        assert!(matches!(err, UiTomlError::InvalidValue { field: "x", .. }));
+    }
+
+    #[test]
+    fn breakpoints_switch_sidebar_to_stacked_layout() {
+        let ui = r##"
+[scene]
+width = 800
+height = 400
+
+[breakpoint.narrow]
+max_width = 600
+
+[[acetate]]
+id = "sidebar"
+x = 0
+y = 0
+w = 200
+h = "100%"
+fill = "#202020"
+
+[acetate.breakpoint.narrow]
+w = "100%"
+h = 80
+
+[[acetate]]
+id = "content"
+left = "sidebar.right"
+right = "scene.right"
+y = 0
+h = "100%"
+fill = "#303030"
+
+[acetate.breakpoint.narrow]
+x = 0
+top = "sidebar.bottom"
+bottom = "scene.bottom"
+w = "100%"
+
+[[acetate]]
+id = "hint"
+x = 600
+y = 10
+w = 100
+h = 20
+fill = "#404040"
+
+[acetate.breakpoint.narrow]
+hidden = true
+"##;
+        let scene = load_scene_from_str(ui).expect("load scene");
+        let at = |scene: &Scene, width: u32| {
+            LayoutEngine::apply(Scene {
+                width,
+                ..scene.clone()
+            })
+            .expect("layout")
+        };
+        let areas = |scene: &Scene| scene.acetates.iter().map(|a| a.area()).collect::<Vec<_>>();
+
+        let desktop = areas(&at(&scene, 800));
+        assert_eq!(desktop[0], Rect::new(0.0, 0.0, 200.0, 400.0));
+        assert_eq!(desktop[1], Rect::new(200.0, 0.0, 600.0, 400.0));
+        assert_eq!(desktop.len(), 3);
+
+        let narrow = at(&scene, 500);
+        // Al volver a ensancharse, lo movido regresa a su sitio.
+        let mut back = scene.clone();
+        back.acetates = narrow.acetates.clone();
+        back.acetates.push(scene.acetates[2].clone());
+        assert_eq!(areas(&at(&back, 800)), desktop);
+
+        let narrow = areas(&narrow);
+        assert_eq!(narrow[0], Rect::new(0.0, 0.0, 500.0, 80.0));
+        assert_eq!(narrow[1], Rect::new(0.0, 80.0, 500.0, 320.0));
+        assert_eq!(narrow.len(), 2);
+
+        let tiny = ui.replace("breakpoint.narrow]\nhidden", "breakpoint.tiny]\nhidden");
+        let err = load_scene_from_str(&tiny).expect_err("unknown breakpoint");
+        assert!(matches!(err, UiTomlError::InvalidValue { field: "breakpoint", .. }));
+    }
+
+    #[test]
+    fn animations_and_transitions_compile_from_toml() {
+        let ui = r##"
+[scene]
+width = 800
+height = 600
+
+[[acetate]]
+id = "panel"
+x = 80
+y = 0
+w = 200
+h = 100
+fill = "#000000"
+transition = { property = ["x", "opacity"], duration_ms = 150, easing = "ease-in" }
+
+[[animation]]
+target = "panel"
+name = "slide"
+property = "x"
+from = 80
+to = 400
+duration_ms = 600
+easing = "ease-out"
+repeat = "infinite"
+
+[[animation]]
+target = "panel"
+property = "fill"
+to = "#ffffff"
+duration_ms = 1000
+delay_ms = 200
+easing = "linear"
+repeat = 2
+alternate = true
+
+[[animation.keyframes]]
+offset = 0.5
+value = "#ff0000"
+
+[[animation]]
+target = "panel"
+property = "transform"
+to = { rotate = 90, scale = 2 }
+duration_ms = 100
+"##;
+        let scene = load_scene_from_str(ui).expect("load scene");
+        let [slide, fill, spin] = &scene.animations[..] else {
+            panic!("three animations");
+        };
+        assert_eq!(
+            scene.transitions["panel"],
+            Transition::new(Duration::from_millis(150))
+                .with_properties([AnimatedProperty::X, AnimatedProperty::Opacity])
+                .with_easing(Easing::EaseIn)
+        );
+        assert_eq!((slide.name.as_str(), fill.name.as_str()), ("slide", ""));
+        assert_eq!(slide.easing, Easing::EaseOut);
+        assert_eq!(slide.repeat, Repeat::Forever);
+        assert_eq!(slide.duration, Duration::from_millis(600));
+
+        // Sin `from` parte del relleno declarado.
+        assert_eq!(fill.keyframes.len(), 3);
+        assert_eq!(
+            fill.keyframes[0].value,
+            AnimationValue::Color(Color::rgba(0.0, 0.0, 0.0, 1.0))
+        );
+        assert_eq!(fill.delay, Duration::from_millis(200));
+        assert_eq!((fill.repeat, fill.alternate), (Repeat::Times(2), true));
+        let midway = fill.advance(Duration::from_millis(700)).value();
+        assert_eq!(midway, Some(AnimationValue::Color(Color::rgba(1.0, 0.0, 0.0, 1.0))));
+
+        let Some(AnimationValue::Transform(end)) = spin.advance(Duration::from_millis(100)).value()
+        else {
+            panic!("transform");
+        };
+        let (x, y) = end.apply((1.0, 0.0));
+        assert!(x.abs() < 1e-5 && (y - 2.0).abs() < 1e-5);
+
+        for (from, to, field) in [
+            ("target = \"panel\"", "target = \"ghost\"", "target"),
+            ("property = \"x\"", "property = \"depth\"", "property"),
+            ("\"ease-out\"", "\"bouncy\"", "easing"),
+            ("\"infinite\"", "\"forever\"", "repeat"),
+            ("\"#ff0000\"", "\"red\"", "keyframes.value"),
+        ] {
+            let err = load_scene_from_str(&ui.replacen(from, to, 1)).expect_err(field);
+            assert!(
+                matches!(err, UiTomlError::InvalidAnimation { field: f, .. } if f == field),
+                "{field}: {err}"
+            );
+        }
+        for (from, to) in [("offset = 0.5", "offset = 0"), ("offset = 0.5", "offset = 1")] {
+            let err = load_scene_from_str(&ui.replacen(from, to, 1)).expect_err("offset");
+            assert!(matches!(err, UiTomlError::InvalidAnimation { field: "keyframes.offset", .. }));
+        }
+        let keyframe = "value = \"#ff0000\"\n";
+        let unsorted = ui.replacen(
+            keyframe,
+            &format!("{keyframe}\n[[animation.keyframes]]\noffset = 0.25\n{keyframe}"),
+            1,
+        );
+        let err = load_scene_from_str(&unsorted).expect_err("unsorted");
+        assert!(matches!(err, UiTomlError::InvalidAnimation { field: "keyframes.offset", .. }));
+        let err = load_scene_from_str(&ui.replacen("to = 400\n", "", 1)).expect_err("to");
+        assert!(matches!(
+            err,
+            UiTomlError::InvalidAnimation { animation_index: 0, field: "to", .. }
+        ));
+
+        let depth = ui.replace("\"opacity\"]", "\"depth\"]");
+        let err = load_scene_from_str(&depth).expect_err("property");
+        assert!(matches!(err, UiTomlError::InvalidValue { field: "transition.property", .. }));
+    }
 }
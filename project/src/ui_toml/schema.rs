@@ -1,46 +1,297 @@
 use serde::Deserialize;
 
-#[derive(Debug, Deserialize)]
+pub use crate::acetates::{ChartKind, Orientation, SelectionMode};
+
+/// Documento ya validado: `compile.rs` lo construye a partir de `RawUiToml`.
+#[derive(Debug)]
 pub struct UiToml {
     pub scene: SceneToml,
-    #[serde(default)]
     pub acetate: Vec<AcetateToml>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug)]
 pub struct SceneToml {
     pub width: u32,
     pub height: u32,
-    #[serde(default)]
     pub includes: Option<Vec<String>>,
+    /// `Metrics::margin`: separación de la raíz del layout respecto a la ventana.
+    pub margin: u32,
+    /// `Metrics::spacing`: hueco por defecto entre hijos del layout.
+    pub spacing: u32,
 }
 
-#[derive(Debug, Deserialize)]
+/// `[[acetate]]` ya validado: `compile.rs` lo construye a partir de `RawAcetateToml`.
+#[derive(Debug)]
 pub struct AcetateToml {
     pub id: String,
-    #[serde(default = "default_z")]
     pub z: i32,
-    pub x: i32,
-    pub y: i32,
-    pub w: u32,
-    pub h: u32,
+    pub x: f32,
+    pub y: f32,
+    pub w: f32,
+    pub h: f32,
     pub fill: String,
-    #[serde(default = "default_border")]
     pub border: String,
-    #[serde(default = "default_border_thickness")]
     pub border_thickness: f32,
-    #[serde(default)]
     pub text: Option<String>,
+    pub text_color: Option<String>,
+    /// Tamaño de letra; sin él, el del estilo por defecto de cada tipo.
+    pub font_size: Option<f32>,
+    pub kind: AcetateKind,
+    pub hover: Option<StateStyleToml>,
+    pub pressed: Option<StateStyleToml>,
+    pub focused: Option<StateStyleToml>,
+    pub disabled: Option<StateStyleToml>,
+    /// Color de acento (estado encendido) de checkbox/switch/radio.
+    pub accent: Option<String>,
+    pub checked: bool,
+    pub options: Vec<String>,
+    /// Etiqueta de la opción seleccionada inicialmente (radio).
+    pub selected: Option<String>,
+    pub min: Option<f32>,
+    pub max: Option<f32>,
+    pub step: Option<f32>,
+    pub value: Option<f32>,
+    /// `[bajo, alto]`: slider de rango con dos pulgares.
+    pub range: Option<[f32; 2]>,
+    pub orientation: Orientation,
+    /// Intervalo entre marcas del slider.
+    pub ticks: Option<f32>,
+    pub title: Option<String>,
+    /// Id del botón que abre el modal; sin él, el modal empieza abierto.
+    pub open_on: Option<String>,
+    /// Ayuda que el runtime muestra al dejar el puntero encima (cualquier `kind`).
+    pub tooltip: Option<String>,
+    /// Columnas de la tabla (`[[acetate.columns]]`); las filas las entrega la app.
+    pub columns: Vec<ColumnToml>,
+    pub row_height: Option<u32>,
+    pub header_height: Option<u32>,
+    pub selection: SelectionMode,
+    /// Fondo de las filas impares de la tabla.
+    pub stripe: Option<String>,
+    /// Pestañas (`[[acetate.tabs]]`) con el grupo de ids que muestra cada una.
+    pub tabs: Vec<TabToml>,
+    /// Forma del gráfico: `"line"`, `"bar"` o `"scatter"`.
+    pub chart: ChartKind,
+}
+
+/// Tipo de acetate declarado con `kind = "..."`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum AcetateKind {
+    /// Rectángulo estático (sin `kind`).
+    #[default]
+    Plain,
+    Button,
+    Checkbox,
+    Switch,
+    Radio,
+    Slider,
+    Dropdown,
+    ContextMenu,
+    Modal,
+    Table,
+    Tabs,
+    Progress,
+    Spinner,
+    Chart,
+}
+
+impl AcetateKind {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim() {
+            "plain" => Some(AcetateKind::Plain),
+            "button" => Some(AcetateKind::Button),
+            "checkbox" => Some(AcetateKind::Checkbox),
+            "switch" => Some(AcetateKind::Switch),
+            "radio" => Some(AcetateKind::Radio),
+            "slider" => Some(AcetateKind::Slider),
+            "dropdown" => Some(AcetateKind::Dropdown),
+            "context_menu" => Some(AcetateKind::ContextMenu),
+            "modal" => Some(AcetateKind::Modal),
+            "table" => Some(AcetateKind::Table),
+            "tabs" => Some(AcetateKind::Tabs),
+            "progress" => Some(AcetateKind::Progress),
+            "spinner" => Some(AcetateKind::Spinner),
+            "chart" => Some(AcetateKind::Chart),
+            _ => None,
+        }
+    }
+}
+
+/// Colores de un estado interactivo (`[acetate.hover]`, `[acetate.pressed]`, ...).
+/// Los campos ausentes se derivan del estilo normal.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct StateStyleToml {
+    #[serde(default)]
+    pub fill: Option<String>,
+    #[serde(default)]
+    pub border: Option<String>,
+    #[serde(default)]
+    pub text_color: Option<String>,
+}
+
+/// Nodo de `[layout]`: una hoja con `id` (el de un `[[acetate]]`) o un contenedor
+/// con `kind = "row" | "column" | "stack" | "grid"` e hijos en `[[layout.children]]`.
+/// Sin `kind` ni `id` es una columna.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct LayoutToml {
+    #[serde(default)]
+    pub id: Option<String>,
+    #[serde(default)]
+    pub kind: Option<String>,
+    #[serde(default)]
+    pub children: Vec<LayoutToml>,
+    #[serde(default)]
+    pub gap: Option<f32>,
+    #[serde(default)]
+    pub padding: Option<f32>,
+    #[serde(default)]
+    pub align: Option<String>,
+    #[serde(default)]
+    pub justify: Option<String>,
+    /// Pistas de la rejilla: píxeles (`120`, `"120px"`) o fracciones (`"1fr"`).
+    #[serde(default)]
+    pub columns: Vec<TrackToml>,
+    #[serde(default)]
+    pub rows: Vec<TrackToml>,
+    /// Tamaño natural; en hojas, por defecto el `w`/`h` del acetate.
+    #[serde(default)]
+    pub width: Option<f32>,
+    #[serde(default)]
+    pub height: Option<f32>,
+    #[serde(default)]
+    pub basis: Option<f32>,
+    #[serde(default)]
+    pub grow: Option<f32>,
+    #[serde(default)]
+    pub shrink: Option<f32>,
+    /// `[columna, fila]` dentro de la rejilla padre.
+    #[serde(default)]
+    pub cell: Option<[usize; 2]>,
+    /// `[columnas, filas]` que ocupa en la rejilla padre.
+    #[serde(default)]
+    pub span: Option<[usize; 2]>,
+}
+
+impl LayoutToml {
+    /// Ids de acetate que aparecen en el árbol.
+    pub fn ids(&self) -> Vec<&str> {
+        let mut ids: Vec<&str> = self.id.as_deref().into_iter().collect();
+        ids.extend(self.children.iter().flat_map(|child| child.ids()));
+        ids
+    }
+}
+
+/// Valor de un `[[acetate]]` que admite un número en píxeles (`24`) o texto: una
+/// longitud con unidades (`"50%"`, `"100vw"`, `"12dp"`, `"calc(100% - 20)"`) en
+/// `x`/`y`/`w`/`h`, `border_thickness` y `font_size`, o un ancla (`"sidebar.right + 8"`,
+/// `"scene"`) en `left`/`right`/`top`/`bottom`/`center_x`/`center_y`.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+pub enum ValueToml {
+    Pixels(f32),
+    Text(String),
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+pub enum TrackToml {
+    Pixels(f32),
+    Text(String),
+}
+
+/// Columna de una tabla (`[[acetate.columns]]`).
+#[derive(Debug, Clone, Deserialize)]
+pub struct ColumnToml {
+    pub title: String,
+    #[serde(default = "default_column_width")]
+    pub width: u32,
+    #[serde(default = "default_sortable")]
+    pub sortable: bool,
+}
+
+/// Pestaña (`[[acetate.tabs]]`): `group` son los ids visibles mientras está activa.
+#[derive(Debug, Clone, Deserialize)]
+pub struct TabToml {
+    pub label: String,
+    #[serde(default)]
+    pub group: Vec<String>,
+}
+
+fn default_column_width() -> u32 {
+    100
+}
+
+fn default_sortable() -> bool {
+    true
+}
+
+/// Animación (`[[animation]]`): lleva `property` del acetate `target` de `from` a `to`,
+/// o por `[[animation.keyframes]]`. Sin `from` parte del valor declarado del acetate.
+#[derive(Debug, Clone, Deserialize)]
+pub struct AnimationToml {
+    pub target: String,
+    /// Nombre que llevan sus eventos (`Event::AnimationStarted` y demás).
+    #[serde(default)]
+    pub name: Option<String>,
+    /// `x`, `y`, `w`, `h`, `fill`, `border`, `opacity` o `transform`.
+    pub property: String,
+    #[serde(default)]
+    pub from: Option<AnimationValueToml>,
+    #[serde(default)]
+    pub to: Option<AnimationValueToml>,
+    #[serde(default)]
+    pub keyframes: Vec<KeyframeToml>,
+    pub duration_ms: u64,
+    #[serde(default)]
+    pub delay_ms: u64,
+    /// Nombre de CSS (`"ease-out"`, `"cubic-bezier(0.2, 0, 0, 1)"`); por defecto `"ease"`.
+    #[serde(default)]
+    pub easing: Option<String>,
+    /// Número de iteraciones o `"infinite"`; por defecto una.
+    #[serde(default)]
+    pub repeat: Option<RepeatToml>,
+    #[serde(default)]
+    pub alternate: bool,
+}
+
+/// Fotograma clave: `offset` va de 0 a 1 dentro de cada iteración.
+#[derive(Debug, Clone, Deserialize)]
+pub struct KeyframeToml {
+    pub offset: f32,
+    pub value: AnimationValueToml,
+}
+
+/// Valor animado: un número (geometría y opacidad), un color `"#rrggbb[aa]"` o una
+/// transformación `{ translate = [x, y], scale = 1.2, rotate = 90 }` (grados).
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+pub enum AnimationValueToml {
+    Number(f32),
+    Text(String),
+    Transform(TransformToml),
 }
 
-fn default_z() -> i32 {
-    0
+/// Escala, luego giro alrededor del centro del acetate y por último traslación.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct TransformToml {
+    #[serde(default)]
+    pub translate: Option<[f32; 2]>,
+    #[serde(default)]
+    pub scale: Option<ScaleToml>,
+    #[serde(default)]
+    pub rotate: Option<f32>,
 }
 
-fn default_border() -> String {
-    "#00000000".to_string()
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+pub enum ScaleToml {
+    Uniform(f32),
+    Axes([f32; 2]),
 }
 
-fn default_border_thickness() -> f32 {
-    0.0
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+pub enum RepeatToml {
+    Times(u32),
+    Text(String),
 }